@@ -33,6 +33,7 @@ use databend_common_storage::DataOperator;
 use databend_common_tracing::set_panic_hook;
 use databend_enterprise_background_service::get_background_service_handler;
 use databend_query::clusters::ClusterDiscovery;
+use databend_query::interpreters::MetricsHistory;
 use databend_query::local;
 use databend_query::servers::admin::AdminService;
 use databend_query::servers::flight::FlightService;
@@ -411,6 +412,8 @@ pub async fn start_services(conf: &InnerConfig) -> Result<(), MainError> {
         start_time.elapsed().as_secs_f32()
     );
 
+    MetricsHistory::start(conf);
+
     if conf.background.enable {
         println!("Start background service");
         get_background_service_handler()
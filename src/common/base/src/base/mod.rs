@@ -17,6 +17,7 @@ mod net;
 mod ordered_float;
 mod profiling;
 mod progress;
+mod retry;
 mod select;
 mod semaphore;
 mod shutdown_signal;
@@ -41,6 +42,7 @@ pub use ordered_float::OrderedFloat;
 pub use profiling::Profiling;
 pub use progress::Progress;
 pub use progress::ProgressValues;
+pub use retry::RetryPolicy;
 pub use select::select3;
 pub use select::Select3Output;
 pub use semaphore::Semaphore;
@@ -0,0 +1,136 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::future::Future;
+use std::time::Duration;
+use std::time::Instant;
+
+use rand::Rng;
+
+/// Retry policy shared by internal RPCs.
+///
+/// Delays grow exponentially from `min_delay` up to `max_delay`, optionally with jitter.
+/// Retrying stops once `max_attempts` is reached, or once the next attempt would start
+/// after the deadline, so that retries never outlive the statement that issued them.
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    min_delay: Duration,
+    max_delay: Duration,
+    factor: f64,
+    max_attempts: usize,
+    jitter: bool,
+    deadline: Option<Instant>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            min_delay: Duration::from_millis(50),
+            max_delay: Duration::from_secs(30),
+            factor: 2.0,
+            max_attempts: 3,
+            jitter: true,
+            deadline: None,
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn with_min_delay(mut self, min_delay: Duration) -> Self {
+        self.min_delay = min_delay;
+        self
+    }
+
+    pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    pub fn with_factor(mut self, factor: f64) -> Self {
+        self.factor = factor;
+        self
+    }
+
+    /// Max number of retries after the first attempt.
+    pub fn with_max_attempts(mut self, max_attempts: usize) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    pub fn with_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    pub fn with_deadline(mut self, deadline: Option<Instant>) -> Self {
+        self.deadline = deadline;
+        self
+    }
+
+    /// Returns the delay before the `attempt`-th retry (starting from 0),
+    /// or None if the retry budget or the deadline is exhausted.
+    pub fn next_delay(&self, attempt: usize) -> Option<Duration> {
+        if attempt >= self.max_attempts {
+            return None;
+        }
+
+        let exp = self.factor.powi(attempt.min(i32::MAX as usize) as i32);
+        let mut delay = self.min_delay.mul_f64(exp).min(self.max_delay);
+        if self.jitter && !delay.is_zero() {
+            // Equal jitter: keep half of the delay and randomize the other half.
+            let half = delay / 2;
+            delay = half + half.mul_f64(rand::thread_rng().gen_range(0.0..=1.0));
+        }
+
+        match self.deadline {
+            Some(deadline) if Instant::now() + delay >= deadline => None,
+            _ => Some(delay),
+        }
+    }
+
+    /// Runs `f` until it succeeds, `retryable` rejects the error, or the policy is exhausted.
+    ///
+    /// `notify` is called with the error and the delay before each retry.
+    pub async fn retry<T, E, F, Fut>(
+        &self,
+        mut f: F,
+        retryable: impl Fn(&E) -> bool,
+        mut notify: impl FnMut(&E, Duration),
+    ) -> Result<T, E>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        let mut attempt = 0;
+        loop {
+            let err = match f().await {
+                Ok(v) => return Ok(v),
+                Err(err) => err,
+            };
+
+            if !retryable(&err) {
+                return Err(err);
+            }
+
+            match self.next_delay(attempt) {
+                None => return Err(err),
+                Some(delay) => {
+                    notify(&err, delay);
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+            }
+        }
+    }
+}
@@ -22,6 +22,7 @@ mod pool;
 mod pool_retry;
 mod progress;
 mod range_merger;
+mod retry;
 mod runtime;
 mod stoppable;
 mod string;
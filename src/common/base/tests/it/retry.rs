@@ -0,0 +1,111 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+use std::time::Instant;
+
+use databend_common_base::base::tokio;
+use databend_common_base::base::RetryPolicy;
+
+#[test]
+fn test_retry_policy_delays() {
+    let policy = RetryPolicy::default()
+        .with_min_delay(Duration::from_millis(10))
+        .with_max_delay(Duration::from_millis(50))
+        .with_factor(2.0)
+        .with_max_attempts(4)
+        .with_jitter(false);
+
+    assert_eq!(policy.next_delay(0), Some(Duration::from_millis(10)));
+    assert_eq!(policy.next_delay(1), Some(Duration::from_millis(20)));
+    assert_eq!(policy.next_delay(2), Some(Duration::from_millis(40)));
+    assert_eq!(policy.next_delay(3), Some(Duration::from_millis(50)));
+    assert_eq!(policy.next_delay(4), None);
+
+    let policy = policy.with_jitter(true);
+    for attempt in 0..4 {
+        let delay = policy.next_delay(attempt).unwrap();
+        assert!(delay >= Duration::from_millis(5));
+        assert!(delay <= Duration::from_millis(50));
+    }
+}
+
+#[test]
+fn test_retry_policy_deadline() {
+    let policy = RetryPolicy::default()
+        .with_min_delay(Duration::from_secs(1))
+        .with_jitter(false)
+        .with_deadline(Some(Instant::now() + Duration::from_millis(100)));
+
+    assert_eq!(policy.next_delay(0), None);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_retry_policy_retry() {
+    let policy = RetryPolicy::default()
+        .with_min_delay(Duration::from_millis(1))
+        .with_max_attempts(3);
+
+    // Succeeds on the third attempt.
+    let calls = AtomicUsize::new(0);
+    let notified = AtomicUsize::new(0);
+    let res: Result<usize, String> = policy
+        .retry(
+            || async {
+                match calls.fetch_add(1, Ordering::SeqCst) {
+                    n if n < 2 => Err("retry".to_string()),
+                    n => Ok(n),
+                }
+            },
+            |_| true,
+            |_, _| {
+                notified.fetch_add(1, Ordering::SeqCst);
+            },
+        )
+        .await;
+    assert_eq!(res, Ok(2));
+    assert_eq!(notified.load(Ordering::SeqCst), 2);
+
+    // Gives up once the attempt budget is exhausted.
+    let calls = AtomicUsize::new(0);
+    let res: Result<(), String> = policy
+        .retry(
+            || async {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Err("retry".to_string())
+            },
+            |_| true,
+            |_, _| {},
+        )
+        .await;
+    assert!(res.is_err());
+    assert_eq!(calls.load(Ordering::SeqCst), 4);
+
+    // Non-retryable errors are returned immediately.
+    let calls = AtomicUsize::new(0);
+    let res: Result<(), String> = policy
+        .retry(
+            || async {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Err("fatal".to_string())
+            },
+            |e| e != "fatal",
+            |_, _| {},
+        )
+        .await;
+    assert!(res.is_err());
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
@@ -36,6 +36,8 @@ pub enum CompressAlgorithm {
     Lzma,
     /// [Xz](https://tukaani.org/xz/) compress format, the successor of [`CompressAlgorithm::Lzma`].
     Xz,
+    /// [Snappy](https://github.com/google/snappy/blob/main/framing_format.txt) framing format.
+    Snappy,
     /// [Zlib](https://datatracker.ietf.org/doc/html/rfc1950) compress format.
     ///
     /// Similar to [`CompressAlgorithm::Deflate`] and [`CompressAlgorithm::Gzip`]
@@ -54,6 +56,7 @@ impl CompressAlgorithm {
             CompressAlgorithm::Gzip => "gz",
             CompressAlgorithm::Lzma => "lzma",
             CompressAlgorithm::Xz => "xz",
+            CompressAlgorithm::Snappy => "sz",
             CompressAlgorithm::Zlib => "zl",
             CompressAlgorithm::Zstd => "zstd",
         }
@@ -70,6 +73,7 @@ impl CompressAlgorithm {
             "gz" => Some(CompressAlgorithm::Gzip),
             "lzma" => Some(CompressAlgorithm::Lzma),
             "xz" => Some(CompressAlgorithm::Xz),
+            "sz" => Some(CompressAlgorithm::Snappy),
             "zl" => Some(CompressAlgorithm::Zlib),
             "zstd" | "zst" => Some(CompressAlgorithm::Zstd),
             _ => None,
@@ -87,4 +91,72 @@ impl CompressAlgorithm {
 
         CompressAlgorithm::from_extension(&ext)
     }
+
+    /// Detect CompressAlgorithm from the magic bytes at the beginning of the data.
+    ///
+    /// Only formats with a reliable magic number are detected, `None` will be
+    /// return for other data. Raw deflate and brotli streams have no header, so
+    /// they are never detected.
+    pub fn from_magic(data: &[u8]) -> Option<CompressAlgorithm> {
+        match data {
+            [0x1f, 0x8b, ..] => Some(CompressAlgorithm::Gzip),
+            [0x28, 0xb5, 0x2f, 0xfd, ..] => Some(CompressAlgorithm::Zstd),
+            // "BZh" followed by the block size, which is '1'..='9' (100k..900k).
+            [b'B', b'Z', b'h', b'1'..=b'9', ..] => Some(CompressAlgorithm::Bz2),
+            [0xfd, b'7', b'z', b'X', b'Z', 0x00, ..] => Some(CompressAlgorithm::Xz),
+            // The stream identifier chunk: type 0xff, length 6, "sNaPpY".
+            [
+                0xff,
+                0x06,
+                0x00,
+                0x00,
+                b's',
+                b'N',
+                b'a',
+                b'P',
+                b'p',
+                b'Y',
+                ..,
+            ] => Some(CompressAlgorithm::Snappy),
+            // Zlib header with a 32K window and no preset dictionary, only the
+            // compression levels whose second byte is not printable are accepted,
+            // "x^" may well start a text file.
+            [0x78, 0x01 | 0x9c | 0xda, ..] => Some(CompressAlgorithm::Zlib),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_magic() {
+        let cases: [(&[u8], Option<CompressAlgorithm>); 12] = [
+            (&[0x1f, 0x8b, 0x08, 0x00], Some(CompressAlgorithm::Gzip)),
+            (
+                &[0x28, 0xb5, 0x2f, 0xfd, 0x04],
+                Some(CompressAlgorithm::Zstd),
+            ),
+            (b"BZh91AY&SY", Some(CompressAlgorithm::Bz2)),
+            (b"BZh", None),
+            (b"BZhang,1\n", None),
+            (
+                &[0xfd, 0x37, 0x7a, 0x58, 0x5a, 0x00, 0x00],
+                Some(CompressAlgorithm::Xz),
+            ),
+            (
+                b"\xff\x06\x00\x00sNaPpY\x01",
+                Some(CompressAlgorithm::Snappy),
+            ),
+            (&[0x78, 0x9c, 0x4b, 0x4c], Some(CompressAlgorithm::Zlib)),
+            (b"x^2,1\n", None),
+            (b"a,b,c\n1,2,3\n", None),
+            (&[0x1f], None),
+        ];
+        for (data, expected) in cases {
+            assert_eq!(CompressAlgorithm::from_magic(data), expected);
+        }
+    }
 }
@@ -37,6 +37,7 @@ use futures::AsyncRead;
 use log::trace;
 use pin_project::pin_project;
 
+use crate::snappy::SnappyFramedDecoder;
 use crate::CompressAlgorithm;
 
 #[derive(Debug)]
@@ -56,6 +57,8 @@ pub enum DecompressCodec {
     Lzma(LzmaDecoder),
     /// Decoder for [`CompressAlgorithm::Xz`]
     Xz(XzDecoder),
+    /// Decoder for [`CompressAlgorithm::Snappy`]
+    Snappy(SnappyFramedDecoder),
     /// Decoder for [`CompressAlgorithm::Zlib`]
     Zlib(ZlibDecoder),
     /// Decoder for [`CompressAlgorithm::Zstd`]
@@ -71,6 +74,7 @@ impl From<CompressAlgorithm> for DecompressCodec {
             CompressAlgorithm::Gzip => DecompressCodec::Gzip(GzipDecoder::new()),
             CompressAlgorithm::Lzma => DecompressCodec::Lzma(LzmaDecoder::new()),
             CompressAlgorithm::Xz => DecompressCodec::Xz(XzDecoder::new()),
+            CompressAlgorithm::Snappy => DecompressCodec::Snappy(SnappyFramedDecoder::new()),
             CompressAlgorithm::Zlib => DecompressCodec::Zlib(ZlibDecoder::new()),
             CompressAlgorithm::Zstd => DecompressCodec::Zstd(ZstdDecoder::new()),
         }
@@ -86,6 +90,7 @@ impl Decode for DecompressCodec {
             DecompressCodec::Gzip(v) => v.reinit(),
             DecompressCodec::Lzma(v) => v.reinit(),
             DecompressCodec::Xz(v) => v.reinit(),
+            DecompressCodec::Snappy(v) => v.reinit(),
             DecompressCodec::Zlib(v) => v.reinit(),
             DecompressCodec::Zstd(v) => v.reinit(),
         }
@@ -103,6 +108,7 @@ impl Decode for DecompressCodec {
             DecompressCodec::Gzip(v) => v.decode(input, output),
             DecompressCodec::Lzma(v) => v.decode(input, output),
             DecompressCodec::Xz(v) => v.decode(input, output),
+            DecompressCodec::Snappy(v) => v.decode(input, output),
             DecompressCodec::Zlib(v) => v.decode(input, output),
             DecompressCodec::Zstd(v) => v.decode(input, output),
         }
@@ -119,6 +125,7 @@ impl Decode for DecompressCodec {
             DecompressCodec::Gzip(v) => v.flush(output),
             DecompressCodec::Lzma(v) => v.flush(output),
             DecompressCodec::Xz(v) => v.flush(output),
+            DecompressCodec::Snappy(v) => v.flush(output),
             DecompressCodec::Zlib(v) => v.flush(output),
             DecompressCodec::Zstd(v) => v.flush(output),
         }
@@ -135,6 +142,7 @@ impl Decode for DecompressCodec {
             DecompressCodec::Gzip(v) => v.finish(output),
             DecompressCodec::Lzma(v) => v.finish(output),
             DecompressCodec::Xz(v) => v.finish(output),
+            DecompressCodec::Snappy(v) => v.finish(output),
             DecompressCodec::Zlib(v) => v.finish(output),
             DecompressCodec::Zstd(v) => v.finish(output),
         }
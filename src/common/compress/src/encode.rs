@@ -28,6 +28,7 @@ use async_compression::Level;
 use brotli::enc::backward_references::BrotliEncoderParams;
 use databend_common_exception::ErrorCode;
 
+use crate::snappy::SnappyFramedEncoder;
 use crate::CompressAlgorithm;
 
 #[derive(Debug)]
@@ -47,6 +48,8 @@ pub enum CompressCodec {
     Lzma(LzmaEncoder),
     /// Encoder for [`CompressAlgorithm::Xz`]
     Xz(XzEncoder),
+    /// Encoder for [`CompressAlgorithm::Snappy`]
+    Snappy(SnappyFramedEncoder),
     /// Encoder for [`CompressAlgorithm::Zlib`]
     Zlib(ZlibEncoder),
     /// Encoder for [`CompressAlgorithm::Zstd`]
@@ -72,6 +75,7 @@ impl From<CompressAlgorithm> for CompressCodec {
                 CompressCodec::Lzma(LzmaEncoder::new(Level::Default.into_xz2()))
             }
             CompressAlgorithm::Xz => CompressCodec::Xz(XzEncoder::new(Level::Default.into_xz2())),
+            CompressAlgorithm::Snappy => CompressCodec::Snappy(SnappyFramedEncoder::new()),
             CompressAlgorithm::Zlib => {
                 CompressCodec::Zlib(ZlibEncoder::new(Level::Default.into_flate2()))
             }
@@ -95,6 +99,7 @@ impl Encode for CompressCodec {
             CompressCodec::Gzip(v) => v.encode(input, output),
             CompressCodec::Lzma(v) => v.encode(input, output),
             CompressCodec::Xz(v) => v.encode(input, output),
+            CompressCodec::Snappy(v) => v.encode(input, output),
             CompressCodec::Zlib(v) => v.encode(input, output),
             CompressCodec::Zstd(v) => v.encode(input, output),
         }
@@ -111,6 +116,7 @@ impl Encode for CompressCodec {
             CompressCodec::Gzip(v) => v.flush(output),
             CompressCodec::Lzma(v) => v.flush(output),
             CompressCodec::Xz(v) => v.flush(output),
+            CompressCodec::Snappy(v) => v.flush(output),
             CompressCodec::Zlib(v) => v.flush(output),
             CompressCodec::Zstd(v) => v.flush(output),
         }
@@ -127,6 +133,7 @@ impl Encode for CompressCodec {
             CompressCodec::Gzip(v) => v.finish(output),
             CompressCodec::Lzma(v) => v.finish(output),
             CompressCodec::Xz(v) => v.finish(output),
+            CompressCodec::Snappy(v) => v.finish(output),
             CompressCodec::Zlib(v) => v.finish(output),
             CompressCodec::Zstd(v) => v.finish(output),
         }
@@ -200,6 +207,7 @@ mod tests {
             CompressAlgorithm::Deflate,
             CompressAlgorithm::Xz,
             CompressAlgorithm::Lzma,
+            CompressAlgorithm::Snappy,
         ] {
             let mut encoder = CompressCodec::from(algo);
             let compressed = encoder.compress_all(&content)?;
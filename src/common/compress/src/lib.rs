@@ -17,6 +17,7 @@
 mod compress_algorithms;
 mod decode;
 mod encode;
+mod snappy;
 
 pub use compress_algorithms::CompressAlgorithm;
 pub use decode::DecompressCodec;
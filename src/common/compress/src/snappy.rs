@@ -0,0 +1,358 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Codecs of the [snappy framing format](https://github.com/google/snappy/blob/main/framing_format.txt).
+//!
+//! A framed stream is a sequence of chunks of at most 64KiB of uncompressed data, so
+//! both the encoder and the decoder only buffer one chunk at a time.
+
+use std::io::Error;
+use std::io::ErrorKind;
+use std::io::Result;
+
+use async_compression::codec::Decode;
+use async_compression::codec::Encode;
+use async_compression::util::PartialBuffer;
+
+pub const SNAPPY_STREAM_IDENTIFIER: &[u8] = b"\xff\x06\x00\x00sNaPpY";
+
+const CHUNK_HEADER_SIZE: usize = 4;
+const CHUNK_CRC_SIZE: usize = 4;
+const MAX_UNCOMPRESSED_CHUNK_SIZE: usize = 65536;
+
+const CHUNK_TYPE_COMPRESSED: u8 = 0x00;
+const CHUNK_TYPE_UNCOMPRESSED: u8 = 0x01;
+const CHUNK_TYPE_STREAM_IDENTIFIER: u8 = 0xff;
+
+fn masked_crc(data: &[u8]) -> u32 {
+    let crc = crc32c::crc32c(data);
+    ((crc >> 15) | (crc << 17)).wrapping_add(0xa282ead8)
+}
+
+fn invalid_data(msg: impl Into<String>) -> Error {
+    Error::new(ErrorKind::InvalidData, msg.into())
+}
+
+/// Copy the data in `pending` after `pos` into the output, returns whether all of it is written.
+fn write_pending(
+    pending: &mut Vec<u8>,
+    pos: &mut usize,
+    output: &mut PartialBuffer<impl AsRef<[u8]> + AsMut<[u8]>>,
+) -> bool {
+    let n = (pending.len() - *pos).min(output.unwritten().len());
+    output.unwritten_mut()[..n].copy_from_slice(&pending[*pos..*pos + n]);
+    output.advance(n);
+    *pos += n;
+    if *pos == pending.len() {
+        pending.clear();
+        *pos = 0;
+        true
+    } else {
+        false
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct SnappyFramedEncoder {
+    started: bool,
+    /// Uncompressed data of the chunk being filled.
+    input: Vec<u8>,
+    /// Encoded chunks not yet written to the output.
+    output: Vec<u8>,
+    output_pos: usize,
+}
+
+impl SnappyFramedEncoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn encode_chunk(&mut self) -> Result<()> {
+        if !self.started {
+            self.output.extend_from_slice(SNAPPY_STREAM_IDENTIFIER);
+            self.started = true;
+        }
+        if self.input.is_empty() {
+            return Ok(());
+        }
+
+        let crc = masked_crc(&self.input);
+        let compressed = snap::raw::Encoder::new()
+            .compress_vec(&self.input)
+            .map_err(|e| invalid_data(e.to_string()))?;
+        let (chunk_type, data) = if compressed.len() < self.input.len() {
+            (CHUNK_TYPE_COMPRESSED, compressed.as_slice())
+        } else {
+            (CHUNK_TYPE_UNCOMPRESSED, self.input.as_slice())
+        };
+        let len = (CHUNK_CRC_SIZE + data.len()) as u32;
+        self.output.push(chunk_type);
+        self.output.extend_from_slice(&len.to_le_bytes()[..3]);
+        self.output.extend_from_slice(&crc.to_le_bytes());
+        self.output.extend_from_slice(data);
+        self.input.clear();
+        Ok(())
+    }
+}
+
+impl Encode for SnappyFramedEncoder {
+    fn encode(
+        &mut self,
+        input: &mut PartialBuffer<impl AsRef<[u8]>>,
+        output: &mut PartialBuffer<impl AsRef<[u8]> + AsMut<[u8]>>,
+    ) -> Result<()> {
+        loop {
+            if !write_pending(&mut self.output, &mut self.output_pos, output) {
+                return Ok(());
+            }
+            if input.unwritten().is_empty() {
+                return Ok(());
+            }
+            let n = (MAX_UNCOMPRESSED_CHUNK_SIZE - self.input.len()).min(input.unwritten().len());
+            self.input.extend_from_slice(&input.unwritten()[..n]);
+            input.advance(n);
+            if self.input.len() == MAX_UNCOMPRESSED_CHUNK_SIZE {
+                self.encode_chunk()?;
+            }
+        }
+    }
+
+    fn flush(
+        &mut self,
+        output: &mut PartialBuffer<impl AsRef<[u8]> + AsMut<[u8]>>,
+    ) -> Result<bool> {
+        self.encode_chunk()?;
+        Ok(write_pending(
+            &mut self.output,
+            &mut self.output_pos,
+            output,
+        ))
+    }
+
+    fn finish(
+        &mut self,
+        output: &mut PartialBuffer<impl AsRef<[u8]> + AsMut<[u8]>>,
+    ) -> Result<bool> {
+        self.flush(output)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct SnappyFramedDecoder {
+    /// Header and body of the chunk being read.
+    chunk: Vec<u8>,
+    /// Bytes of a skippable chunk still to be skipped.
+    skip: usize,
+    /// Decompressed data not yet written to the output.
+    output: Vec<u8>,
+    output_pos: usize,
+}
+
+impl SnappyFramedDecoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Decode the chunk in `self.chunk`, whose body is fully read.
+    fn decode_chunk(&mut self, chunk_type: u8) -> Result<()> {
+        let body = &self.chunk[CHUNK_HEADER_SIZE..];
+        match chunk_type {
+            CHUNK_TYPE_STREAM_IDENTIFIER => {
+                if self.chunk != SNAPPY_STREAM_IDENTIFIER {
+                    return Err(invalid_data("invalid snappy stream identifier"));
+                }
+            }
+            CHUNK_TYPE_COMPRESSED | CHUNK_TYPE_UNCOMPRESSED => {
+                if body.len() < CHUNK_CRC_SIZE {
+                    return Err(invalid_data("snappy chunk is too short"));
+                }
+                let crc = u32::from_le_bytes(body[..CHUNK_CRC_SIZE].try_into().unwrap());
+                let data = &body[CHUNK_CRC_SIZE..];
+                let start = self.output.len();
+                if chunk_type == CHUNK_TYPE_COMPRESSED {
+                    let len =
+                        snap::raw::decompress_len(data).map_err(|e| invalid_data(e.to_string()))?;
+                    if len > MAX_UNCOMPRESSED_CHUNK_SIZE {
+                        return Err(invalid_data(format!(
+                            "snappy chunk of {len} bytes is larger than {MAX_UNCOMPRESSED_CHUNK_SIZE}"
+                        )));
+                    }
+                    self.output.resize(start + len, 0);
+                    snap::raw::Decoder::new()
+                        .decompress(data, &mut self.output[start..])
+                        .map_err(|e| invalid_data(e.to_string()))?;
+                } else {
+                    self.output.extend_from_slice(data);
+                }
+                if masked_crc(&self.output[start..]) != crc {
+                    return Err(invalid_data("snappy chunk checksum mismatch"));
+                }
+            }
+            0x02..=0x7f => {
+                return Err(invalid_data(format!(
+                    "unsupported unskippable snappy chunk type {chunk_type:#04x}"
+                )));
+            }
+            // skippable chunks, including padding
+            _ => {}
+        }
+        self.chunk.clear();
+        Ok(())
+    }
+}
+
+impl Decode for SnappyFramedDecoder {
+    fn reinit(&mut self) -> Result<()> {
+        *self = Self::default();
+        Ok(())
+    }
+
+    fn decode(
+        &mut self,
+        input: &mut PartialBuffer<impl AsRef<[u8]>>,
+        output: &mut PartialBuffer<impl AsRef<[u8]> + AsMut<[u8]>>,
+    ) -> Result<bool> {
+        loop {
+            if !write_pending(&mut self.output, &mut self.output_pos, output) {
+                return Ok(false);
+            }
+
+            let unwritten = input.unwritten();
+            if unwritten.is_empty() {
+                return Ok(false);
+            }
+
+            if self.skip > 0 {
+                let n = self.skip.min(unwritten.len());
+                input.advance(n);
+                self.skip -= n;
+                continue;
+            }
+
+            if self.chunk.len() < CHUNK_HEADER_SIZE {
+                let n = (CHUNK_HEADER_SIZE - self.chunk.len()).min(unwritten.len());
+                self.chunk.extend_from_slice(&unwritten[..n]);
+                input.advance(n);
+                continue;
+            }
+
+            let chunk_type = self.chunk[0];
+            let len = u32::from_le_bytes([self.chunk[1], self.chunk[2], self.chunk[3], 0]) as usize;
+            if (0x80..=0xfe).contains(&chunk_type) {
+                // skippable chunk, drop it without buffering
+                self.chunk.clear();
+                self.skip = len;
+                continue;
+            }
+            if len > CHUNK_CRC_SIZE + MAX_UNCOMPRESSED_CHUNK_SIZE {
+                return Err(invalid_data(format!(
+                    "snappy chunk of {len} bytes is too large"
+                )));
+            }
+
+            let n = (CHUNK_HEADER_SIZE + len - self.chunk.len()).min(unwritten.len());
+            self.chunk.extend_from_slice(&unwritten[..n]);
+            input.advance(n);
+            if self.chunk.len() == CHUNK_HEADER_SIZE + len {
+                self.decode_chunk(chunk_type)?;
+            }
+        }
+    }
+
+    fn flush(
+        &mut self,
+        output: &mut PartialBuffer<impl AsRef<[u8]> + AsMut<[u8]>>,
+    ) -> Result<bool> {
+        Ok(write_pending(
+            &mut self.output,
+            &mut self.output_pos,
+            output,
+        ))
+    }
+
+    fn finish(
+        &mut self,
+        output: &mut PartialBuffer<impl AsRef<[u8]> + AsMut<[u8]>>,
+    ) -> Result<bool> {
+        if !self.chunk.is_empty() || self.skip > 0 {
+            return Err(invalid_data("unexpected end of snappy stream"));
+        }
+        self.flush(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+    use std::io::Write;
+
+    use super::*;
+    use crate::CompressAlgorithm;
+    use crate::CompressCodec;
+    use crate::DecompressDecoder;
+
+    #[test]
+    fn test_snappy_framed_decode_snap_stream() -> databend_common_exception::Result<()> {
+        let content = (0..200_000u32)
+            .flat_map(|i| format!("{},{}\n", i, i % 7).into_bytes())
+            .collect::<Vec<_>>();
+
+        // a stream written by the reference implementation of the snap crate
+        let mut compressed = vec![];
+        {
+            let mut encoder = snap::write::FrameEncoder::new(&mut compressed);
+            encoder.write_all(&content)?;
+            encoder.flush()?;
+        }
+        assert!(compressed.starts_with(SNAPPY_STREAM_IDENTIFIER));
+
+        // decode in small batches, as the stage reader does
+        let mut decoder = DecompressDecoder::new(CompressAlgorithm::Snappy);
+        let mut decompressed = vec![];
+        for batch in compressed.chunks(1000) {
+            decompressed.extend(decoder.decompress_batch(batch)?);
+        }
+        decompressed.extend(decoder.decompress_batch(&[])?);
+        assert_eq!(decompressed, content);
+
+        // and our own encoder is readable by the reference implementation
+        let encoded = CompressCodec::from(CompressAlgorithm::Snappy).compress_all(&content)?;
+        let mut decoded = vec![];
+        snap::read::FrameDecoder::new(encoded.as_slice()).read_to_end(&mut decoded)?;
+        assert_eq!(decoded, content);
+        Ok(())
+    }
+
+    #[test]
+    fn test_snappy_framed_decode_corrupted() {
+        let content = b"a,b,c\n1,2,3\n".repeat(100);
+        let mut compressed = CompressCodec::from(CompressAlgorithm::Snappy)
+            .compress_all(&content)
+            .unwrap();
+
+        // truncated
+        let mut decoder = DecompressDecoder::new(CompressAlgorithm::Snappy);
+        assert!(
+            decoder
+                .decompress_all(&compressed[..compressed.len() - 1])
+                .is_err()
+        );
+
+        // checksum mismatch
+        let last = compressed.len() - 1;
+        compressed[last] ^= 0xff;
+        let mut decoder = DecompressDecoder::new(CompressAlgorithm::Snappy);
+        assert!(decoder.decompress_all(&compressed).is_err());
+    }
+}
@@ -104,7 +104,9 @@ build_exceptions! {
     CannotListenerPort(1045),
     BadBytes(1046),
     InitPrometheusFailure(1047),
+    TooManyQueuedQueries(1048),
     Overflow(1049),
+    SpillBytesLimitExceeded(1050),
     TLSConfigurationFailure(1052),
     UnknownSession(1053),
     SHA1CheckFailed(1057),
@@ -229,6 +231,8 @@ build_exceptions! {
     InvalidArgument(2004),
     // Meta service replied with invalid data
     InvalidReply(2005),
+    // Meta service is unreachable, only read-only queries on cached metadata are served.
+    MetaServiceUnavailable(2006),
 
     TableVersionMismatched(2009),
     OCCRetryFailure(2011),
@@ -45,13 +45,30 @@ impl CopyStatus {
     }
 }
 
+/// At most this many rejected rows are kept for each file, the errors beyond are still
+/// counted.
+pub const MAX_REJECTED_ROWS_PER_FILE: usize = 10000;
+
 #[derive(Default, Clone, Serialize, Deserialize)]
 pub struct FileStatus {
     pub num_rows_loaded: usize,
     pub error: Option<FileErrorsInfo>,
+    /// Only kept if `REJECTED_ROWS_PATH` is set.
+    #[serde(default)]
+    pub rejected_rows: Vec<RejectedRow>,
 }
 
 impl FileStatus {
+    pub fn add_rejected_row(&mut self, error: &FileParseError, line: usize, row: &[u8]) {
+        if self.rejected_rows.len() < MAX_REJECTED_ROWS_PER_FILE {
+            self.rejected_rows.push(RejectedRow {
+                line,
+                error: error.to_string(),
+                row: String::from_utf8_lossy(row).into_owned(),
+            });
+        }
+    }
+
     pub fn add_error(&mut self, error: FileParseError, line: usize) {
         match &mut self.error {
             None => {
@@ -71,6 +88,9 @@ impl FileStatus {
 
     fn merge(&mut self, other: FileStatus) {
         self.num_rows_loaded += other.num_rows_loaded;
+        let remain = MAX_REJECTED_ROWS_PER_FILE.saturating_sub(self.rejected_rows.len());
+        self.rejected_rows
+            .extend(other.rejected_rows.into_iter().take(remain));
         match (&mut self.error, other.error) {
             (None, Some(e)) => self.error = Some(e),
             (Some(e1), Some(e2)) => e1.merge(e2),
@@ -79,6 +99,14 @@ impl FileStatus {
     }
 }
 
+#[derive(Clone, Serialize, Deserialize)]
+pub struct RejectedRow {
+    /// 0-based, like [`FileErrorInfo::line`].
+    pub line: usize,
+    pub error: String,
+    pub row: String,
+}
+
 #[derive(Clone, Serialize, Deserialize)]
 pub struct FileErrorsInfo {
     pub num_errors: usize,
@@ -194,11 +194,9 @@ impl FromStr for StageFileFormatType {
             "XML" => Ok(StageFileFormatType::Xml),
             "JSON" => Ok(StageFileFormatType::Json),
             "ORC" => Ok(StageFileFormatType::Orc),
-            "AVRO" => Err(format!(
-                "File format type '{s}' not implemented yet', must be one of ( CSV | TSV | NDJSON | PARQUET | ORC)"
-            )),
+            "AVRO" => Ok(StageFileFormatType::Avro),
             _ => Err(format!(
-                "Unknown file format type '{s}', must be one of ( CSV | TSV | NDJSON | PARQUET | ORC)"
+                "Unknown file format type '{s}', must be one of ( CSV | TSV | NDJSON | PARQUET | ORC | AVRO)"
             )),
         }
     }
@@ -507,6 +505,9 @@ pub struct CopyOptions {
     pub max_file_size: usize,
     pub single: bool,
     pub detailed_output: bool,
+
+    // load only, set by the statement and not stored with the stage
+    pub rejected_rows_path: Option<String>,
 }
 
 impl CopyOptions {
@@ -591,7 +592,11 @@ impl Display for CopyOptions {
         write!(f, "ReturnFailedOnly {}", self.return_failed_only)?;
         write!(f, "MaxFileSize {}", self.max_file_size)?;
         write!(f, "Single {}", self.single)?;
-        write!(f, "DetailedOutput {}", self.detailed_output)
+        write!(f, "DetailedOutput {}", self.detailed_output)?;
+        if let Some(path) = &self.rejected_rows_path {
+            write!(f, "RejectedRowsPath {}", path)?;
+        }
+        Ok(())
     }
 }
 
@@ -31,6 +31,7 @@ use databend_common_base::base::tokio::sync::mpsc::UnboundedSender;
 use databend_common_base::base::tokio::sync::oneshot;
 use databend_common_base::base::tokio::sync::oneshot::Sender as OneSend;
 use databend_common_base::base::tokio::time::sleep;
+use databend_common_base::base::RetryPolicy;
 use databend_common_base::containers::ItemManager;
 use databend_common_base::containers::Pool;
 use databend_common_base::future::TimedFutureExt;
@@ -105,6 +106,14 @@ use crate::METACLI_COMMIT_SEMVER;
 use crate::MIN_METASRV_SEMVER;
 
 const RPC_RETRIES: usize = 4;
+
+/// Backoff between the attempts of a meta-service RPC, the endpoint is switched before each retry.
+fn rpc_retry_policy() -> RetryPolicy {
+    RetryPolicy::default()
+        .with_min_delay(Duration::from_millis(20))
+        .with_max_delay(Duration::from_secs(1))
+        .with_max_attempts(RPC_RETRIES - 1)
+}
 const AUTH_TOKEN_KEY: &str = "auth-token-bin";
 
 pub(crate) type RealClient = MetaServiceClient<InterceptedService<Channel, AuthInterceptor>>;
@@ -1086,7 +1095,13 @@ impl MetaGrpcClient {
 
                     self.choose_next_endpoint();
                     failures.push(e.clone());
-                    continue;
+                    match rpc_retry_policy().next_delay(i) {
+                        Some(delay) => {
+                            sleep(delay).await;
+                            continue;
+                        }
+                        None => break,
+                    }
                 }
             }
 
@@ -1152,7 +1167,13 @@ impl MetaGrpcClient {
                 if is_status_retryable(e) {
                     self.choose_next_endpoint();
                     failures.push(e.clone());
-                    continue;
+                    match rpc_retry_policy().next_delay(i) {
+                        Some(delay) => {
+                            sleep(delay).await;
+                            continue;
+                        }
+                        None => break,
+                    }
                 }
             }
 
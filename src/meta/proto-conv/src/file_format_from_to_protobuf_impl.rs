@@ -219,6 +219,11 @@ impl FromToProto for mt::principal::FileFormatParams {
                     mt::principal::OrcFileFormatParams::from_pb(p)?,
                 ))
             }
+            Some(pb::file_format_params::Format::Avro(p)) => {
+                Ok(mt::principal::FileFormatParams::Avro(
+                    mt::principal::AvroFileFormatParams::from_pb(p)?,
+                ))
+            }
             Some(pb::file_format_params::Format::Parquet(p)) => {
                 Ok(mt::principal::FileFormatParams::Parquet(
                     mt::principal::ParquetFileFormatParams::from_pb(p)?,
@@ -292,6 +297,11 @@ impl FromToProto for mt::principal::FileFormatParams {
                     mt::principal::OrcFileFormatParams::to_pb(p)?,
                 )),
             }),
+            Self::Avro(p) => Ok(Self::PB {
+                format: Some(pb::file_format_params::Format::Avro(
+                    mt::principal::AvroFileFormatParams::to_pb(p)?,
+                )),
+            }),
         }
     }
 }
@@ -321,6 +331,31 @@ impl FromToProto for mt::principal::OrcFileFormatParams {
     }
 }
 
+impl FromToProto for mt::principal::AvroFileFormatParams {
+    type PB = pb::AvroFileFormatParams;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+
+    fn from_pb(p: pb::AvroFileFormatParams) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+        mt::principal::AvroFileFormatParams::try_create(p.missing_field_as.as_deref(), p.null_if)
+            .map_err(|e| Incompatible {
+                reason: format!("{e}"),
+            })
+    }
+
+    fn to_pb(&self) -> Result<pb::AvroFileFormatParams, Incompatible> {
+        Ok(pb::AvroFileFormatParams {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            missing_field_as: Some(self.missing_field_as.to_string()),
+            null_if: self.null_if.clone(),
+        })
+    }
+}
+
 impl FromToProto for mt::principal::ParquetFileFormatParams {
     type PB = pb::ParquetFileFormatParams;
     fn get_pb_ver(p: &Self::PB) -> u64 {
@@ -69,6 +69,7 @@ fn test_decode_v31_copy_max_file() -> anyhow::Result<()> {
             disable_variant_check: false,
             return_failed_only: false,
             detailed_output: false,
+            rejected_rows_path: None,
         },
         comment: "test".to_string(),
         ..Default::default()
@@ -69,6 +69,7 @@ fn test_decode_v42_s3_stage_new_field() -> anyhow::Result<()> {
             disable_variant_check: true,
             return_failed_only: false,
             detailed_output: false,
+            rejected_rows_path: None,
         },
         comment: "test".to_string(),
         number_of_files: 100,
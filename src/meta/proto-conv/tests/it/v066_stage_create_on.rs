@@ -70,6 +70,7 @@ fn test_decode_v66_stage() -> anyhow::Result<()> {
             disable_variant_check: true,
             return_failed_only: false,
             detailed_output: false,
+            rejected_rows_path: None,
         },
         comment: "ccc".to_string(),
         number_of_files: 100,
@@ -248,6 +248,10 @@ pub enum Expr {
         span: Span,
         name: String,
     },
+    /// `?` standing in for a redacted literal, only produced by `Statement::to_redacted_sql`
+    Placeholder {
+        span: Span,
+    },
 }
 
 impl Expr {
@@ -287,7 +291,8 @@ impl Expr {
             | Expr::LastDay { span, .. }
             | Expr::PreviousDay { span, .. }
             | Expr::NextDay { span, .. }
-            | Expr::Hole { span, .. } => *span,
+            | Expr::Hole { span, .. }
+            | Expr::Placeholder { span } => *span,
         }
     }
 
@@ -433,6 +438,7 @@ impl Expr {
             Expr::PreviousDay { span, date, .. } => merge_span(*span, date.whole_span()),
             Expr::NextDay { span, date, .. } => merge_span(*span, date.whole_span()),
             Expr::Hole { span, .. } => *span,
+            Expr::Placeholder { span } => *span,
         }
     }
 
@@ -767,6 +773,9 @@ impl Display for Expr {
                 Expr::Hole { name, .. } => {
                     write!(f, ":{name}")?;
                 }
+                Expr::Placeholder { .. } => {
+                    write!(f, "?")?;
+                }
             }
 
             if need_paren {
@@ -160,6 +160,12 @@ fn pretty_table_source(source: CreateTableSource) -> RcDoc<'static> {
                 RcDoc::nil()
             })
             .append(RcDoc::text(table.to_string())),
+        CreateTableSource::Template(query) => RcDoc::space()
+            .append(RcDoc::text("USING TEMPLATE"))
+            .append(RcDoc::space())
+            .append(parenthesized(
+                pretty_query(*query).nest(NEST_FACTOR).group(),
+            )),
     }
 }
 
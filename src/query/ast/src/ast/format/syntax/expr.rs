@@ -459,5 +459,6 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc<'static> {
             .append(RcDoc::text(unit.to_string()))
             .append(RcDoc::text(")")),
         Expr::Hole { name, .. } => RcDoc::text(":").append(RcDoc::text(name.to_string())),
+        Expr::Placeholder { .. } => RcDoc::text("?"),
     }
 }
@@ -141,6 +141,9 @@ pub struct SelectStmt {
     pub span: Span,
     pub hints: Option<Hint>,
     pub distinct: bool,
+    // `DISTINCT ON (expr, ...)`, keeps the first row of each group
+    // in the order given by the `ORDER BY` clause.
+    pub distinct_on: Option<Vec<Expr>>,
     pub top_n: Option<u64>,
     // Result set of current subquery
     pub select_list: Vec<SelectTarget>,
@@ -170,6 +173,11 @@ impl Display for SelectStmt {
         if self.distinct {
             write!(f, "DISTINCT ")?;
         }
+        if let Some(distinct_on) = &self.distinct_on {
+            write!(f, "DISTINCT ON (")?;
+            write_comma_separated_list(f, distinct_on)?;
+            write!(f, ") ")?;
+        }
         if let Some(topn) = &self.top_n {
             write!(f, "TOP {} ", topn)?;
         }
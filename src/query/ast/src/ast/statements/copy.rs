@@ -26,6 +26,7 @@ use crate::ast::quote::QuotedString;
 use crate::ast::write_comma_separated_map;
 use crate::ast::write_comma_separated_string_list;
 use crate::ast::write_comma_separated_string_map;
+use crate::ast::Expr;
 use crate::ast::Hint;
 use crate::ast::Identifier;
 use crate::ast::Query;
@@ -67,6 +68,9 @@ pub struct CopyIntoTableStmt {
     pub disable_variant_check: bool,
     pub return_failed_only: bool,
     pub on_error: String,
+    /// Where the rows rejected by `ON_ERROR = CONTINUE` are written, relative to the
+    /// location of the source stage.
+    pub rejected_rows_path: Option<String>,
 }
 
 impl CopyIntoTableStmt {
@@ -84,6 +88,7 @@ impl CopyIntoTableStmt {
             CopyIntoTableOption::DisableVariantCheck(v) => self.disable_variant_check = v,
             CopyIntoTableOption::ReturnFailedOnly(v) => self.return_failed_only = v,
             CopyIntoTableOption::OnError(v) => self.on_error = v,
+            CopyIntoTableOption::RejectedRowsPath(v) => self.rejected_rows_path = Some(v),
         }
     }
 }
@@ -138,6 +143,9 @@ impl Display for CopyIntoTableStmt {
         write!(f, " DISABLE_VARIANT_CHECK = {}", self.disable_variant_check)?;
         write!(f, " ON_ERROR = {}", self.on_error)?;
         write!(f, " RETURN_FAILED_ONLY = {}", self.return_failed_only)?;
+        if let Some(path) = &self.rejected_rows_path {
+            write!(f, " REJECTED_ROWS_PATH = {}", QuotedString(path, '\''))?;
+        }
 
         Ok(())
     }
@@ -151,6 +159,9 @@ pub struct CopyIntoLocationOptions {
     pub use_raw_path: bool,
     pub include_query_id: bool,
     pub overwrite: bool,
+    /// Write Iceberg table metadata next to the unloaded parquet files.
+    #[serde(default)]
+    pub iceberg_metadata: bool,
 }
 
 impl Default for CopyIntoLocationOptions {
@@ -162,6 +173,7 @@ impl Default for CopyIntoLocationOptions {
             use_raw_path: false,
             include_query_id: true,
             overwrite: false,
+            iceberg_metadata: false,
         }
     }
 }
@@ -173,6 +185,7 @@ pub struct CopyIntoLocationStmt {
     pub hints: Option<Hint>,
     pub src: CopyIntoLocationSource,
     pub dst: FileLocation,
+    pub partition_by: Option<Expr>,
     pub file_format: FileFormatOptions,
     pub options: CopyIntoLocationOptions,
 }
@@ -188,6 +201,9 @@ impl Display for CopyIntoLocationStmt {
         }
         write!(f, " INTO {}", self.dst)?;
         write!(f, " FROM {}", self.src)?;
+        if let Some(partition_by) = &self.partition_by {
+            write!(f, " PARTITION BY ({partition_by})")?;
+        }
 
         if !self.file_format.is_empty() {
             write!(f, " FILE_FORMAT = ({})", self.file_format)?;
@@ -198,6 +214,7 @@ impl Display for CopyIntoLocationStmt {
         write!(f, " INCLUDE_QUERY_ID = {}", self.options.include_query_id)?;
         write!(f, " USE_RAW_PATH = {}", self.options.use_raw_path)?;
         write!(f, " OVERWRITE = {}", self.options.overwrite)?;
+        write!(f, " ICEBERG_METADATA = {}", self.options.iceberg_metadata)?;
 
         Ok(())
     }
@@ -213,6 +230,7 @@ impl CopyIntoLocationStmt {
             CopyIntoLocationOption::IncludeQueryID(v) => self.options.include_query_id = v,
             CopyIntoLocationOption::UseRawPath(v) => self.options.use_raw_path = v,
             CopyIntoLocationOption::OverWrite(v) => self.options.overwrite = v,
+            CopyIntoLocationOption::IcebergMetadata(v) => self.options.iceberg_metadata = v,
         }
     }
 }
@@ -491,6 +509,7 @@ pub enum CopyIntoTableOption {
     DisableVariantCheck(bool),
     ReturnFailedOnly(bool),
     OnError(String),
+    RejectedRowsPath(String),
 }
 
 pub enum CopyIntoLocationOption {
@@ -501,6 +520,7 @@ pub enum CopyIntoLocationOption {
     UseRawPath(bool),
     DetailedOutput(bool),
     OverWrite(bool),
+    IcebergMetadata(bool),
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Default, Drive, DriveMut)]
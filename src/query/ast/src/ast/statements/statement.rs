@@ -17,6 +17,7 @@ use std::fmt::Formatter;
 
 use derive_visitor::Drive;
 use derive_visitor::DriveMut;
+use derive_visitor::VisitorMut;
 use dictionary::CreateDictionaryStmt;
 use dictionary::DropDictionaryStmt;
 use dictionary::ShowCreateDictionaryStmt;
@@ -31,7 +32,9 @@ use crate::ast::statements::settings::Settings;
 use crate::ast::statements::task::CreateTaskStmt;
 use crate::ast::write_comma_separated_list;
 use crate::ast::CreateOption;
+use crate::ast::Expr;
 use crate::ast::Identifier;
+use crate::ast::InsertSource;
 use crate::ast::Query;
 
 // SQL statement
@@ -376,6 +379,33 @@ impl Statement {
             _ => format!("{}", self),
         }
     }
+
+    /// Like `to_mask_sql`, but also replaces every literal with `?`,
+    /// so that the values embedded in the statement are not recorded.
+    pub fn to_redacted_sql(&self) -> String {
+        #[derive(VisitorMut)]
+        #[visitor(Expr(enter), InsertSource(enter))]
+        struct LiteralRedactor;
+
+        impl LiteralRedactor {
+            fn enter_expr(&mut self, expr: &mut Expr) {
+                if let Expr::Literal { span, .. } = expr {
+                    *expr = Expr::Placeholder { span: *span };
+                }
+            }
+
+            // the values of a large INSERT are kept unparsed, redact them as a whole
+            fn enter_insert_source(&mut self, source: &mut InsertSource) {
+                if let InsertSource::RawValues { rest_str, .. } = source {
+                    *rest_str = "?".to_string();
+                }
+            }
+        }
+
+        let mut stmt = self.clone();
+        stmt.drive_mut(&mut LiteralRedactor);
+        stmt.to_mask_sql()
+    }
 }
 
 impl Display for Statement {
@@ -276,6 +276,9 @@ pub enum CreateTableSource {
         database: Option<Identifier>,
         table: Identifier,
     },
+    /// `USING TEMPLATE (<query>)`, the query returns a `(column_name, type, nullable)` row
+    /// per column, e.g. the output of `infer_schema`.
+    Template(Box<Query>),
 }
 
 impl Display for CreateTableSource {
@@ -298,6 +301,7 @@ impl Display for CreateTableSource {
                 write!(f, "LIKE ")?;
                 write_dot_separated_list(f, catalog.iter().chain(database).chain(Some(table)))
             }
+            CreateTableSource::Template(query) => write!(f, "USING TEMPLATE ({query})"),
         }
     }
 }
@@ -829,6 +833,7 @@ pub enum ColumnExpr {
     Default(Box<Expr>),
     Virtual(Box<Expr>),
     Stored(Box<Expr>),
+    AutoIncrement,
 }
 
 impl Display for ColumnExpr {
@@ -843,6 +848,9 @@ impl Display for ColumnExpr {
             ColumnExpr::Stored(expr) => {
                 write!(f, " AS ({expr}) STORED")?;
             }
+            ColumnExpr::AutoIncrement => {
+                write!(f, " AUTOINCREMENT")?;
+            }
         }
         Ok(())
     }
@@ -32,6 +32,7 @@ use crate::parser::common::ident;
 use crate::parser::common::table_ref;
 use crate::parser::common::IResult;
 use crate::parser::common::*;
+use crate::parser::expr::expr;
 use crate::parser::expr::literal_bool;
 use crate::parser::expr::literal_string;
 use crate::parser::expr::literal_u64;
@@ -78,6 +79,7 @@ pub fn copy_into_table(i: Input) -> IResult<Statement> {
                 disable_variant_check: Default::default(),
                 on_error: "abort".to_string(),
                 return_failed_only: Default::default(),
+                rejected_rows_path: Default::default(),
             };
             for opt in opts {
                 copy_stmt.apply_option(opt);
@@ -101,14 +103,16 @@ fn copy_into_location(i: Input) -> IResult<Statement> {
             ~ #hint?
             ~ INTO ~ #file_location
             ~ ^FROM ~ ^#copy_into_location_source
+            ~ ( PARTITION ~ ^BY ~ ^#expr )?
             ~ #copy_into_location_option*
         },
-        |(with, _copy, opt_hints, _into, dst, _from, src, opts)| {
+        |(with, _copy, opt_hints, _into, dst, _from, src, partition_by, opts)| {
             let mut copy_stmt = CopyIntoLocationStmt {
                 with,
                 hints: opt_hints,
                 src,
                 dst,
+                partition_by: partition_by.map(|(_, _, expr)| expr),
                 file_format: Default::default(),
                 options: Default::default(),
             };
@@ -124,6 +128,7 @@ pub fn copy_into(i: Input) -> IResult<Statement> {
          #copy_into_location:"`COPY
                 INTO { internalStage | externalStage | externalLocation }
                 FROM { [<database_name>.]<table_name> | ( <query> ) }
+                [ PARTITION BY ( <expr> ) ]
                 [ FILE_FORMAT = ( { TYPE = { CSV | JSON | PARQUET | TSV } [ formatTypeOptions ] } ) ]
                 [ copyOptions ]`"
          | #copy_into_table: "`COPY
@@ -192,6 +197,10 @@ fn copy_into_table_option(i: Input) -> IResult<CopyIntoTableOption> {
             rule! { RETURN_FAILED_ONLY ~ "=" ~ #literal_bool },
             |(_, _, return_failed_only)| CopyIntoTableOption::ReturnFailedOnly(return_failed_only),
         ),
+        map(
+            rule! { REJECTED_ROWS_PATH ~ "=" ~ #literal_string },
+            |(_, _, path)| CopyIntoTableOption::RejectedRowsPath(path),
+        ),
     ))(i)
 }
 
@@ -220,6 +229,10 @@ fn copy_into_location_option(i: Input) -> IResult<CopyIntoLocationOption> {
             rule! {  OVERWRITE ~ "=" ~ #literal_bool },
             |(_, _, include_query_id)| CopyIntoLocationOption::OverWrite(include_query_id),
         ),
+        map(
+            rule! {  ICEBERG_METADATA ~ "=" ~ #literal_bool },
+            |(_, _, iceberg_metadata)| CopyIntoLocationOption::IcebergMetadata(iceberg_metadata),
+        ),
         map(rule! { #file_format_clause }, |options| {
             CopyIntoLocationOption::FileFormat(options)
         }),
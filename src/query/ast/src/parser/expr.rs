@@ -478,6 +478,7 @@ impl Expr {
             Expr::PreviousDay { .. } => Affix::Nilfix,
             Expr::NextDay { .. } => Affix::Nilfix,
             Expr::Hole { .. } => Affix::Nilfix,
+            Expr::Placeholder { .. } => Affix::Nilfix,
         }
     }
 }
@@ -1031,20 +1032,28 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
         },
     );
 
-    let function_call = map(
+    let function_call = map_res(
         rule! {
             #function_name
-            ~ "(" ~ DISTINCT? ~ #comma_separated_list0(subexpr(0))? ~ ")"
-        },
-        |(name, _, opt_distinct, opt_args, _)| ExprElement::FunctionCall {
-            func: FunctionCall {
-                distinct: opt_distinct.is_some(),
-                name,
-                args: opt_args.unwrap_or_default(),
-                params: vec![],
-                window: None,
-                lambda: None,
-            },
+            ~ "(" ~ DISTINCT? ~ #comma_separated_list0(subexpr(0))?
+            ~ ( ORDER ~ ^BY ~ ^#order_by_expr )? ~ ")"
+        },
+        |(name, _, opt_distinct, opt_args, opt_order_by, _)| {
+            let args = opt_args.unwrap_or_default();
+            let func = match opt_order_by {
+                Some((_, _, order_by)) => {
+                    any_value_order_by(name, opt_distinct.is_some(), args, order_by)?
+                }
+                None => FunctionCall {
+                    distinct: opt_distinct.is_some(),
+                    name,
+                    args,
+                    params: vec![],
+                    window: None,
+                    lambda: None,
+                },
+            };
+            Ok(ExprElement::FunctionCall { func })
         },
     );
     let function_call_with_lambda = map(
@@ -1100,7 +1109,6 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             },
         },
     );
-
     let case = map(
         rule! {
             CASE ~ #subexpr(0)?
@@ -1375,7 +1383,7 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
                 | #function_call_with_lambda : "`function(..., x -> ...)`"
                 | #function_call_with_window : "`function(...) OVER ([ PARTITION BY <expr>, ... ] [ ORDER BY <expr>, ... ] [ <window frame> ])`"
                 | #function_call_with_params : "`function(...)(...)`"
-                | #function_call : "`function(...)`"
+                | #function_call : "`function(... [ORDER BY ...])`"
                 | #case : "`CASE ... END`"
                 | #tuple : "`(<expr> [, ...])`"
                 | #subquery : "`(SELECT ...)`"
@@ -1392,6 +1400,75 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
     )(i)
 }
 
+/// `ANY_VALUE(<expr> ORDER BY <key> [ASC | DESC])` picks the value of the first row in the
+/// given order, it is rewritten into `ARG_MIN` or `ARG_MAX` keyed on `(<key>, <expr>)`, so that
+/// the rows with an equal key are ordered by their value instead of by their arrival order.
+/// Rows whose key is NULL are still ignored, the same as `ARG_MIN(<expr>, <key>)`.
+fn any_value_order_by(
+    name: Identifier,
+    distinct: bool,
+    mut args: Vec<Expr>,
+    order_by: OrderByExpr,
+) -> Result<FunctionCall, nom::Err<ErrorKind>> {
+    if !name.name.eq_ignore_ascii_case("any_value") {
+        return Err(nom::Err::Failure(ErrorKind::Other(
+            "ORDER BY inside function call is only supported by ANY_VALUE",
+        )));
+    }
+    if distinct || args.len() != 1 {
+        return Err(nom::Err::Failure(ErrorKind::Other(
+            "ANY_VALUE ... ORDER BY takes exactly one argument",
+        )));
+    }
+    if order_by.nulls_first.is_some() {
+        return Err(nom::Err::Failure(ErrorKind::Other(
+            "NULLS FIRST | LAST is not supported by ANY_VALUE ... ORDER BY",
+        )));
+    }
+
+    let arg = args.remove(0);
+    let span = name.span;
+    let key = order_by.expr;
+    let key = Expr::FunctionCall {
+        span,
+        func: FunctionCall {
+            distinct: false,
+            name: Identifier::from_name(span, "if"),
+            args: vec![
+                Expr::IsNull {
+                    span,
+                    expr: Box::new(key.clone()),
+                    not: false,
+                },
+                Expr::Literal {
+                    span,
+                    value: Literal::Null,
+                },
+                Expr::Tuple {
+                    span,
+                    exprs: vec![key, arg.clone()],
+                },
+            ],
+            params: vec![],
+            window: None,
+            lambda: None,
+        },
+    };
+    let func_name = if order_by.asc.unwrap_or(true) {
+        "arg_min"
+    } else {
+        "arg_max"
+    };
+    Ok(FunctionCall {
+        distinct: false,
+        name: Identifier::from_name(span, func_name),
+        args: vec![arg, key],
+        params: vec![],
+        window: None,
+        lambda: None,
+    })
+}
+
 pub fn unary_op(i: Input) -> IResult<UnaryOperator> {
     // Plus and Minus are parsed as binary op at first.
     alt((
@@ -57,6 +57,7 @@ pub enum SetOperationElement {
     SelectStmt {
         hints: Option<Hint>,
         distinct: bool,
+        distinct_on: Option<Vec<Expr>>,
         top_n: Option<u64>,
         select_list: Vec<SelectTarget>,
         from: Vec<TableReference>,
@@ -103,10 +104,19 @@ pub fn set_operation_element(i: Input) -> IResult<WithSpan<SetOperationElement>>
             }
         },
     );
+    let distinct = alt((
+        map(
+            rule! {
+                DISTINCT ~ ON ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")"
+            },
+            |(_, _, _, exprs, _)| Some(exprs),
+        ),
+        map(rule! { DISTINCT }, |_| None),
+    ));
     let select_stmt = map_res(
         rule! {
             ( FROM ~ ^#comma_separated_list1(table_reference) )?
-            ~ SELECT ~ #hint? ~ DISTINCT? ~ #top_n? ~ ^#comma_separated_list1(select_target)
+            ~ SELECT ~ #hint? ~ #distinct? ~ #top_n? ~ ^#comma_separated_list1(select_target)
             ~ ( FROM ~ ^#comma_separated_list1(table_reference) )?
             ~ ( WHERE ~ ^#expr )?
             ~ ( GROUP ~ ^BY ~ ^#group_by_items )?
@@ -136,7 +146,8 @@ pub fn set_operation_element(i: Input) -> IResult<WithSpan<SetOperationElement>>
 
             Ok(SetOperationElement::SelectStmt {
                 hints: opt_hints,
-                distinct: opt_distinct.is_some(),
+                distinct: matches!(opt_distinct, Some(None)),
+                distinct_on: opt_distinct.flatten(),
                 top_n: opt_top_n,
                 select_list,
                 from: opt_from_block_first
@@ -238,6 +249,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, SetOperationElement>>> PrattParser<I>
             SetOperationElement::SelectStmt {
                 hints,
                 distinct,
+                distinct_on,
                 top_n,
                 select_list,
                 from,
@@ -251,6 +263,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, SetOperationElement>>> PrattParser<I>
                 hints,
                 top_n,
                 distinct,
+                distinct_on,
                 select_list,
                 from,
                 selection,
@@ -553,6 +566,14 @@ pub fn temporal_clause(i: Input) -> IResult<TemporalClause> {
         |(_, travel_point)| TemporalClause::TimeTravel(travel_point),
     );
 
+    // SQL:2011 `FOR SYSTEM_TIME AS OF <timestamp>`, same as `AT (TIMESTAMP => <timestamp>)`.
+    let system_time = map(
+        rule! {
+            FOR ~ SYSTEM_TIME ~ ^AS ~ ^OF ~ ^#expr
+        },
+        |(_, _, _, _, ts)| TemporalClause::TimeTravel(TimeTravelPoint::Timestamp(Box::new(ts))),
+    );
+
     let changes = map(
         rule! {
             CHANGES ~ "(" ~ INFORMATION ~ "=>" ~ ( DEFAULT | APPEND_ONLY ) ~ ")" ~ AT ~ ^#travel_point ~ (END ~ ^#at_snapshot_or_ts)?
@@ -569,6 +590,7 @@ pub fn temporal_clause(i: Input) -> IResult<TemporalClause> {
 
     rule!(
         #time_travel
+        | #system_time
         | #changes
     )(i)
 }
@@ -832,6 +832,18 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             })
         },
     );
+    let flashback_table = map(
+        rule! {
+            FLASHBACK ~ TABLE ~ #table_reference_only ~ TO ~ #travel_point
+        },
+        |(_, _, table_reference, _, point)| {
+            Statement::AlterTable(AlterTableStmt {
+                if_exists: false,
+                table_reference,
+                action: AlterTableAction::FlashbackTo { point },
+            })
+        },
+    );
     let rename_table = map(
         rule! {
             RENAME ~ TABLE ~ ( IF ~ ^EXISTS )? ~ #dot_separated_idents_1_to_3 ~ TO ~ #dot_separated_idents_1_to_3
@@ -2324,6 +2336,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #merge : "`MERGE INTO <target_table> USING <source> ON <join_expr> { matchedClause | notMatchedClause } [ ... ]`"
             | #delete : "`DELETE FROM <table> [WHERE ...]`"
             | #update : "`UPDATE <table> SET <column> = <expr> [, <column> = <expr> , ... ] [WHERE ...]`"
+            | #flashback_table : "`FLASHBACK TABLE [<database>.]<table> TO <travel_point>`"
             | #begin
             | #commit
             | #abort
@@ -2817,12 +2830,31 @@ pub fn set_var_hints(i: Input) -> IResult<HintItem> {
     )(i)
 }
 
+/// `NO_CACHE` is a shortcut of disabling the query result cache and the table data cache.
+pub fn no_cache_hints(i: Input) -> IResult<Vec<HintItem>> {
+    map(rule! { NO_CACHE }, |token| {
+        ["enable_query_result_cache", "enable_table_data_cache"]
+            .into_iter()
+            .map(|name| HintItem {
+                name: Identifier::from_name(Some(token.span), name),
+                expr: Expr::Literal {
+                    span: Some(token.span),
+                    value: Literal::UInt64(0),
+                },
+            })
+            .collect()
+    })(i)
+}
+
 pub fn hint(i: Input) -> IResult<Hint> {
+    let set_var_hints = map(set_var_hints, |item| vec![item]);
     let hint = map(
         rule! {
-            "/*+" ~ #set_var_hints+ ~ "*/"
+            "/*+" ~ ( #no_cache_hints | #set_var_hints )+ ~ "*/"
+        },
+        |(_, hints, _)| Hint {
+            hints_list: hints.into_iter().flatten().collect(),
         },
-        |(_, hints_list, _)| Hint { hints_list },
     );
     let invalid_hint = map(
         rule! {
@@ -2899,6 +2931,7 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
         DefaultExpr(Box<Expr>),
         VirtualExpr(Box<Expr>),
         StoredExpr(Box<Expr>),
+        AutoIncrement,
     }
 
     let nullable = alt((
@@ -2924,6 +2957,7 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
             },
             |(_, _, _, stored_expr, _, _)| ColumnConstraint::StoredExpr(Box::new(stored_expr)),
         ),
+        value(ColumnConstraint::AutoIncrement, rule! { AUTOINCREMENT }),
     ));
 
     let comment = map(
@@ -2939,7 +2973,7 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
             ~ #type_name
             ~ ( #nullable | #expr )*
             ~ ( #comment )?
-            : "`<column name> <type> [DEFAULT <expr>] [AS (<expr>) VIRTUAL] [AS (<expr>) STORED] [COMMENT '<comment>']`"
+            : "`<column name> <type> [DEFAULT <expr>] [AS (<expr>) VIRTUAL] [AS (<expr>) STORED] [AUTOINCREMENT] [COMMENT '<comment>']`"
         },
         |(name, data_type, constraints, comment)| {
             let def = ColumnDefinition {
@@ -2978,6 +3012,7 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
             ColumnConstraint::StoredExpr(stored_expr) => {
                 def.expr = Some(ColumnExpr::Stored(stored_expr))
             }
+            ColumnConstraint::AutoIncrement => def.expr = Some(ColumnExpr::AutoIncrement),
         }
     }
 
@@ -3363,9 +3398,17 @@ pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
         },
     );
 
+    let template = map(
+        rule! {
+            USING ~ TEMPLATE ~ "(" ~ #query ~ ")"
+        },
+        |(_, _, _, query, _)| CreateTableSource::Template(Box::new(query)),
+    );
+
     rule!(
         #columns
         | #like
+        | #template
     )(i)
 }
 
@@ -3582,6 +3625,27 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         |(_, _, _, set_options, _)| AlterTableAction::SetOptions { set_options },
     );
 
+    let set_change_tracking = map(
+        rule! {
+            SET ~ CHANGE_TRACKING ~ "=" ~ #literal_bool
+        },
+        |(_, _, _, enabled)| AlterTableAction::SetOptions {
+            set_options: BTreeMap::from([("change_tracking".to_string(), enabled.to_string())]),
+        },
+    );
+
+    let set_data_retention = map(
+        rule! {
+            SET ~ DATA_RETENTION_TIME_IN_DAYS ~ "=" ~ #literal_u64
+        },
+        |(_, _, _, days)| AlterTableAction::SetOptions {
+            set_options: BTreeMap::from([(
+                "data_retention_period_in_hours".to_string(),
+                days.saturating_mul(24).to_string(),
+            )]),
+        },
+    );
+
     let unset_table_options = map(
         rule! {
             UNSET ~ OPTIONS ~ #unset_source
@@ -3601,6 +3665,8 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         | #recluster_table
         | #revert_table
         | #set_table_options
+        | #set_change_tracking
+        | #set_data_retention
         | #unset_table_options
     )(i)
 }
@@ -3651,10 +3717,10 @@ pub fn unmatch_clause(i: Input) -> IResult<MergeOption> {
     alt((
         map(
             rule! {
-                WHEN ~ NOT ~ MATCHED ~ (AND ~ ^#expr)?  ~ THEN ~ INSERT ~ ( "(" ~ ^#comma_separated_list1(ident) ~ ^")" )?
+                WHEN ~ NOT ~ MATCHED ~ (BY ~ TARGET)? ~ (AND ~ ^#expr)?  ~ THEN ~ INSERT ~ ( "(" ~ ^#comma_separated_list1(ident) ~ ^")" )?
                 ~ VALUES ~ ^#row_values
             },
-            |(_, _, _, expr_op, _, _, columns_op, _, values)| {
+            |(_, _, _, _, expr_op, _, _, columns_op, _, values)| {
                 let selection = match expr_op {
                     Some(e) => Some(e.1),
                     None => None,
@@ -3681,9 +3747,9 @@ pub fn unmatch_clause(i: Input) -> IResult<MergeOption> {
         ),
         map(
             rule! {
-                WHEN ~ NOT ~ MATCHED ~ (AND ~ ^#expr)?  ~ THEN ~ INSERT ~ "*"
+                WHEN ~ NOT ~ MATCHED ~ (BY ~ TARGET)? ~ (AND ~ ^#expr)?  ~ THEN ~ INSERT ~ "*"
             },
-            |(_, _, _, expr_op, _, _, _)| {
+            |(_, _, _, _, expr_op, _, _, _)| {
                 let selection = match expr_op {
                     Some(e) => Some(e.1),
                     None => None,
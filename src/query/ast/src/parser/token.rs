@@ -355,6 +355,8 @@ pub enum TokenKind {
     ARGS,
     #[token("AUTO", ignore(ascii_case))]
     AUTO,
+    #[token("AUTOINCREMENT", ignore(ascii_case))]
+    AUTOINCREMENT,
     #[token("SOME", ignore(ascii_case))]
     SOME,
     #[token("ALTER", ignore(ascii_case))]
@@ -433,6 +435,8 @@ pub enum TokenKind {
     CENTURY,
     #[token("CHANGES", ignore(ascii_case))]
     CHANGES,
+    #[token("CHANGE_TRACKING", ignore(ascii_case))]
+    CHANGE_TRACKING,
     #[token("CLUSTER", ignore(ascii_case))]
     CLUSTER,
     #[token("COMMENT", ignore(ascii_case))]
@@ -487,6 +491,8 @@ pub enum TokenKind {
     DATABASES,
     #[token("DATA", ignore(ascii_case))]
     DATA,
+    #[token("DATA_RETENTION_TIME_IN_DAYS", ignore(ascii_case))]
+    DATA_RETENTION_TIME_IN_DAYS,
     #[token("DATE", ignore(ascii_case))]
     DATE,
     #[token("DATE_ADD", ignore(ascii_case))]
@@ -685,6 +691,8 @@ pub enum TokenKind {
     HOURS,
     #[token("ICEBERG", ignore(ascii_case))]
     ICEBERG,
+    #[token("ICEBERG_METADATA", ignore(ascii_case))]
+    ICEBERG_METADATA,
     #[token("INTERSECT", ignore(ascii_case))]
     INTERSECT,
     #[token("IDENTIFIED", ignore(ascii_case))]
@@ -834,6 +842,8 @@ pub enum TokenKind {
     DISABLED,
     #[token("NDJSON", ignore(ascii_case))]
     NDJSON,
+    #[token("NO_CACHE", ignore(ascii_case))]
+    NO_CACHE,
     #[token("NO_PASSWORD", ignore(ascii_case))]
     NO_PASSWORD,
     #[token("NONE", ignore(ascii_case))]
@@ -952,6 +962,8 @@ pub enum TokenKind {
     REFRESH,
     #[token("REGEXP", ignore(ascii_case))]
     REGEXP,
+    #[token("REJECTED_ROWS_PATH", ignore(ascii_case))]
+    REJECTED_ROWS_PATH,
     #[token("RENAME", ignore(ascii_case))]
     RENAME,
     #[token("REPLACE", ignore(ascii_case))]
@@ -1126,12 +1138,16 @@ pub enum TokenKind {
     SYNC,
     #[token("SYSTEM", ignore(ascii_case))]
     SYSTEM,
+    #[token("SYSTEM_TIME", ignore(ascii_case))]
+    SYSTEM_TIME,
     #[token("STORAGE_TYPE", ignore(ascii_case))]
     STORAGE_TYPE,
     #[token("TABLE", ignore(ascii_case))]
     TABLE,
     #[token("TABLES", ignore(ascii_case))]
     TABLES,
+    #[token("TARGET", ignore(ascii_case))]
+    TARGET,
     #[token("TARGET_LAG", ignore(ascii_case))]
     TARGET_LAG,
     #[token("TEXT", ignore(ascii_case))]
@@ -1358,6 +1374,8 @@ pub enum TokenKind {
     TEMPORARY,
     #[token("TEMP", ignore(ascii_case))]
     TEMP,
+    #[token("TEMPLATE", ignore(ascii_case))]
+    TEMPLATE,
     #[token("SECONDS", ignore(ascii_case))]
     SECONDS,
     #[token("DAYS", ignore(ascii_case))]
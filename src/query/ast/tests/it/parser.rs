@@ -130,6 +130,7 @@ fn test_statement() {
         r#"create table if not exists a.b (a string, b string, inverted index idx1 (a,b) tokenizer='chinese');"#,
         r#"create table a.b like c.d;"#,
         r#"create table t like t2 engine = memory;"#,
+        r#"create table a.b using template (select * from t);"#,
         r#"create table if not exists a.b (a int) 's3://testbucket/admin/data/' connection=(aws_key_id='minioadmin' aws_secret_key='minioadmin' endpoint_url='http://127.0.0.1:9900');"#,
         r#"truncate table a;"#,
         r#"truncate table "a".b;"#,
@@ -436,6 +437,7 @@ fn test_statement() {
                     skip_header = 1
                 );
         "#,
+        r#"COPY INTO @my_stage FROM mytable PARTITION BY a FILE_FORMAT = (type = PARQUET);"#,
         r#"
             COPY INTO mytable
                 FROM 's3://mybucket/data.csv'
@@ -554,6 +556,7 @@ fn test_statement() {
         r#"SHOW GRANTS ON TABLE db1.tb1;"#,
         r#"SHOW GRANTS ON DATABASE db;"#,
         r#"UPDATE db1.tb1 set a = a + 1, b = 2 WHERE c > 3;"#,
+        r#"MERGE INTO t USING s ON t.c = s.c WHEN NOT MATCHED BY TARGET THEN INSERT *;"#,
         r#"select $abc + 3"#,
         r#"select IDENTIFIER($abc)"#,
         r#"SET max_threads = 10;"#,
@@ -1049,6 +1052,7 @@ fn test_query() {
         r#"select * exclude c1, b.* exclude (c2, c3, c4) from customer inner join orders on a = b limit 1"#,
         r#"select columns('abc'), columns(a -> length(a) = 3) from t"#,
         r#"select * from customer at(offset => -10 * 30)"#,
+        r#"select * from customer for system_time as of '2023-06-26 09:49:02.038483'::TIMESTAMP"#,
         r#"select * from customer changes(information => default) at (stream => s) order by a, b"#,
         r#"select * from customer with consume as s"#,
         r#"select * from customer inner join orders"#,
@@ -1461,3 +1465,27 @@ fn test_quote() {
         };
     }
 }
+
+#[test]
+fn test_redacted_sql() {
+    let cases = &[
+        (
+            "select * from t where a = 5 and b = 'secret'",
+            "SELECT * FROM t WHERE a = ? AND b = ?",
+        ),
+        (
+            "select * from t where a is null and b in (1, 2)",
+            "SELECT * FROM t WHERE a IS NULL AND b IN(?, ?)",
+        ),
+        (
+            "insert into t values (1, 'x')",
+            "INSERT INTO t VALUES (?, ?)",
+        ),
+    ];
+
+    for (input, expected) in cases {
+        let tokens = tokenize_sql(input).unwrap();
+        let (stmt, _) = parse_sql(&tokens, Dialect::PostgreSQL).unwrap();
+        assert_eq!(stmt.to_redacted_sql(), *expected);
+    }
+}
@@ -42,6 +42,9 @@ pub struct StageTableInfo {
     pub duplicated_files_detected: Vec<String>,
     pub is_select: bool,
     pub copy_into_location_options: CopyIntoLocationOptions,
+    /// For unloading only, the last column is the partition path of each row.
+    #[serde(default)]
+    pub is_partitioned_unload: bool,
 }
 
 impl StageTableInfo {
@@ -219,7 +219,12 @@ impl PushDownInfo {
 
             if let RemoteExpr::<String>::ColumnRef { id, data_type, .. } = &order.0 {
                 // TODO: support sub column of nested type.
-                if !TopK::support_type(data_type) {
+                if !TopK::support_type(&data_type.remove_nullable()) {
+                    return None;
+                }
+                // Block and page statistics don't cover NULLs, so pruning is only
+                // correct when NULLs are sorted after all the other values.
+                if data_type.is_nullable() && order.2 {
                     return None;
                 }
 
@@ -95,6 +95,14 @@ pub struct ProcessInfo {
     /// storage metrics for persisted data reading.
     pub data_metrics: Option<StorageMetrics>,
     pub scan_progress_value: Option<ProgressValues>,
+    /// estimated total rows/bytes to scan, filled in as tables are planned.
+    pub total_scan_value: Option<ProgressValues>,
+    /// bytes spilled by join, aggregate, group by and window partition.
+    pub spill_bytes: Option<usize>,
+    /// plans of the local pipeline with processors running right now.
+    pub running_operators: Vec<String>,
+    /// scan progress of each node of a distributed query, keyed by node id.
+    pub node_scan_progress: Option<BTreeMap<String, ProgressValues>>,
     pub mysql_connection_id: Option<u32>,
     pub created_time: SystemTime,
     pub status_info: Option<String>,
@@ -32,6 +32,9 @@ pub struct BackgroundConfig {
     // Fs compaction related background config.
     #[clap(flatten)]
     pub compaction: BackgroundCompactionConfig,
+    // Snapshot expiration related background config.
+    #[clap(flatten)]
+    pub vacuum: BackgroundVacuumConfig,
 }
 
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Args)]
@@ -67,6 +70,19 @@ pub struct BackgroundCompactionConfig {
     pub scheduled_config: BackgroundScheduledConfig,
 }
 
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Args)]
+#[serde(default)]
+pub struct BackgroundVacuumConfig {
+    // only wake up background vacuum job if it is enabled.
+    #[clap(long, value_name = "VALUE")]
+    pub enable_vacuum: bool,
+
+    // the fixed interval between two runs of the vacuum job,
+    // each run vacuums the tables that set `data_retention_period_in_hours`.
+    #[clap(long, value_name = "VALUE", default_value = "3600")]
+    pub vacuum_interval_secs: u64,
+}
+
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Args)]
 #[serde(default)]
 pub struct BackgroundScheduledConfig {
@@ -106,6 +122,7 @@ impl BackgroundScheduledConfig {
 pub struct InnerBackgroundConfig {
     pub enable: bool,
     pub compaction: InnerBackgroundCompactionConfig,
+    pub vacuum: InnerBackgroundVacuumConfig,
 }
 
 #[derive(Clone, PartialEq, Eq)]
@@ -123,6 +140,12 @@ impl InnerBackgroundCompactionConfig {
     }
 }
 
+#[derive(Clone, PartialEq, Eq)]
+pub struct InnerBackgroundVacuumConfig {
+    pub enable: bool,
+    pub params: BackgroundJobParams,
+}
+
 impl TryInto<InnerBackgroundConfig> for BackgroundConfig {
     type Error = ErrorCode;
 
@@ -130,6 +153,7 @@ impl TryInto<InnerBackgroundConfig> for BackgroundConfig {
         Ok(InnerBackgroundConfig {
             enable: self.enable,
             compaction: self.compaction.try_into()?,
+            vacuum: self.vacuum.into(),
         })
     }
 }
@@ -139,6 +163,7 @@ impl From<InnerBackgroundConfig> for BackgroundConfig {
         Self {
             enable: inner.enable,
             compaction: BackgroundCompactionConfig::from(inner.compaction),
+            vacuum: BackgroundVacuumConfig::from(inner.vacuum),
         }
     }
 }
@@ -220,6 +245,26 @@ impl From<InnerBackgroundCompactionConfig> for BackgroundCompactionConfig {
     }
 }
 
+impl From<BackgroundVacuumConfig> for InnerBackgroundVacuumConfig {
+    fn from(outer: BackgroundVacuumConfig) -> Self {
+        Self {
+            enable: outer.enable_vacuum,
+            params: BackgroundJobParams::new_interval_job(std::time::Duration::from_secs(
+                outer.vacuum_interval_secs,
+            )),
+        }
+    }
+}
+
+impl From<InnerBackgroundVacuumConfig> for BackgroundVacuumConfig {
+    fn from(inner: InnerBackgroundVacuumConfig) -> Self {
+        Self {
+            enable_vacuum: inner.enable,
+            vacuum_interval_secs: inner.params.scheduled_job_interval.as_secs(),
+        }
+    }
+}
+
 impl From<BackgroundJobParams> for BackgroundScheduledConfig {
     fn from(inner: BackgroundJobParams) -> Self {
         Self {
@@ -254,6 +299,24 @@ impl Debug for BackgroundCompactionConfig {
     }
 }
 
+impl Default for BackgroundVacuumConfig {
+    fn default() -> Self {
+        Self {
+            enable_vacuum: false,
+            vacuum_interval_secs: 3600,
+        }
+    }
+}
+
+impl Debug for BackgroundVacuumConfig {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        f.debug_struct("BackgroundVacuumConfig")
+            .field("enable_vacuum", &self.enable_vacuum)
+            .field("vacuum_interval_secs", &self.vacuum_interval_secs)
+            .finish()
+    }
+}
+
 impl Default for BackgroundScheduledConfig {
     fn default() -> Self {
         Self {
@@ -283,6 +346,7 @@ impl Default for InnerBackgroundConfig {
                 block_limit: None,
                 params: Default::default(),
             },
+            vacuum: BackgroundVacuumConfig::default().into(),
         }
     }
 }
@@ -291,6 +355,7 @@ impl Debug for InnerBackgroundConfig {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         f.debug_struct("InnerBackgroundConfig")
             .field("compaction", &self.compaction)
+            .field("vacuum", &self.vacuum)
             .finish()
     }
 }
@@ -304,3 +369,12 @@ impl Debug for InnerBackgroundCompactionConfig {
             .finish()
     }
 }
+
+impl Debug for InnerBackgroundVacuumConfig {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        f.debug_struct("InnerBackgroundVacuumConfig")
+            .field("enable", &self.enable)
+            .field("params", &self.params)
+            .finish()
+    }
+}
@@ -1408,6 +1408,10 @@ pub struct QueryConfig {
     #[clap(long, value_name = "VALUE", default_value = "8")]
     pub max_running_queries: u64,
 
+    /// The max number of queries waiting in the queue, 0 means unlimited.
+    #[clap(long, value_name = "VALUE", default_value = "0")]
+    pub max_queued_queries: u64,
+
     /// The max total memory in bytes that can be used by this process.
     #[clap(long, value_name = "VALUE", default_value = "0")]
     pub max_server_memory_usage: u64,
@@ -1518,6 +1522,10 @@ pub struct QueryConfig {
     #[clap(long, value_name = "VALUE", default_value = "10000")]
     pub max_query_log_size: usize,
 
+    /// Sample the node metrics into `system_history.metrics` every N seconds, 0 means disabled.
+    #[clap(long, value_name = "VALUE", default_value = "0")]
+    pub metrics_history_interval_secs: u64,
+
     #[clap(long, value_name = "VALUE")]
     pub databend_enterprise_license: Option<String>,
     /// If in management mode, only can do some meta level operations(database/table/user/stage etc.) with metasrv.
@@ -1532,6 +1540,24 @@ pub struct QueryConfig {
     #[clap(skip)]
     pub jwt_key_files: Vec<String>,
 
+    /// If not empty, only accept jwt whose `iss` claim is one of these issuers
+    #[clap(skip)]
+    pub jwt_allowed_issuers: Vec<String>,
+
+    /// If not empty, only accept jwt whose `aud` claim contains one of these audiences
+    #[clap(skip)]
+    pub jwt_allowed_audiences: Vec<String>,
+
+    /// If not empty, the jwt claim (a string or an array of strings, e.g. `groups`) whose
+    /// values are mapped to roles of the session by `jwt_role_mapping`
+    #[clap(long, value_name = "VALUE", default_value_t)]
+    pub jwt_role_claim: String,
+
+    /// The allow-list of `<claim value>=<role>` entries used to map `jwt_role_claim`,
+    /// claim values not listed are ignored, built-in admin roles are rejected
+    #[clap(skip)]
+    pub jwt_role_mapping: Vec<String>,
+
     #[clap(long, value_name = "VALUE", default_value = "auto")]
     pub default_storage_format: String,
 
@@ -1687,6 +1713,12 @@ pub struct QueryConfig {
     #[clap(long, value_name = "VALUE", default_value = "50")]
     pub max_cached_queries_profiles: usize,
 
+    /// Keep serving read-only queries from cached metadata for up to this many seconds
+    /// while the meta service is unreachable. DDL and DML are rejected meanwhile.
+    /// 0 disables it.
+    #[clap(long, value_name = "VALUE", default_value = "0")]
+    pub meta_unavailable_stale_read_secs: u64,
+
     #[clap(skip)]
     pub settings: HashMap<String, SettingValue>,
 }
@@ -1715,6 +1747,7 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             mysql_tls_server_key: self.mysql_tls_server_key,
             max_active_sessions: self.max_active_sessions,
             max_running_queries: self.max_running_queries,
+            max_queued_queries: self.max_queued_queries,
             max_server_memory_usage: self.max_server_memory_usage,
             max_memory_limit_enabled: self.max_memory_limit_enabled,
             clickhouse_http_handler_host: self.clickhouse_http_handler_host,
@@ -1744,12 +1777,17 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             table_engine_memory_enabled: self.table_engine_memory_enabled,
             shutdown_wait_timeout_ms: self.shutdown_wait_timeout_ms,
             max_query_log_size: self.max_query_log_size,
+            metrics_history_interval_secs: self.metrics_history_interval_secs,
             databend_enterprise_license: self.databend_enterprise_license,
             management_mode: self.management_mode,
             parquet_fast_read_bytes: self.parquet_fast_read_bytes,
             max_storage_io_requests: self.max_storage_io_requests,
             jwt_key_file: self.jwt_key_file,
             jwt_key_files: self.jwt_key_files,
+            jwt_allowed_issuers: self.jwt_allowed_issuers,
+            jwt_allowed_audiences: self.jwt_allowed_audiences,
+            jwt_role_claim: self.jwt_role_claim,
+            jwt_role_mapping: self.jwt_role_mapping,
             default_storage_format: self.default_storage_format,
             default_compression: self.default_compression,
             builtin: BuiltInConfig {
@@ -1776,6 +1814,7 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             cloud_control_grpc_server_address: self.cloud_control_grpc_server_address,
             cloud_control_grpc_timeout: self.cloud_control_grpc_timeout,
             max_cached_queries_profiles: self.max_cached_queries_profiles,
+            meta_unavailable_stale_read_secs: self.meta_unavailable_stale_read_secs,
             settings: self
                 .settings
                 .into_iter()
@@ -1800,6 +1839,7 @@ impl From<InnerQueryConfig> for QueryConfig {
             mysql_tls_server_key: inner.mysql_tls_server_key,
             max_active_sessions: inner.max_active_sessions,
             max_running_queries: inner.max_running_queries,
+            max_queued_queries: inner.max_queued_queries,
             max_server_memory_usage: inner.max_server_memory_usage,
             max_memory_limit_enabled: inner.max_memory_limit_enabled,
 
@@ -1834,12 +1874,17 @@ impl From<InnerQueryConfig> for QueryConfig {
             table_engine_memory_enabled: inner.table_engine_memory_enabled,
             shutdown_wait_timeout_ms: inner.shutdown_wait_timeout_ms,
             max_query_log_size: inner.max_query_log_size,
+            metrics_history_interval_secs: inner.metrics_history_interval_secs,
             databend_enterprise_license: inner.databend_enterprise_license,
             management_mode: inner.management_mode,
             parquet_fast_read_bytes: inner.parquet_fast_read_bytes,
             max_storage_io_requests: inner.max_storage_io_requests,
             jwt_key_file: inner.jwt_key_file,
             jwt_key_files: inner.jwt_key_files,
+            jwt_allowed_issuers: inner.jwt_allowed_issuers,
+            jwt_allowed_audiences: inner.jwt_allowed_audiences,
+            jwt_role_claim: inner.jwt_role_claim,
+            jwt_role_mapping: inner.jwt_role_mapping,
             default_storage_format: inner.default_storage_format,
             default_compression: inner.default_compression,
             users: inner.builtin.users,
@@ -1878,6 +1923,7 @@ impl From<InnerQueryConfig> for QueryConfig {
             cloud_control_grpc_server_address: inner.cloud_control_grpc_server_address,
             cloud_control_grpc_timeout: inner.cloud_control_grpc_timeout,
             max_cached_queries_profiles: inner.max_cached_queries_profiles,
+            meta_unavailable_stale_read_secs: inner.meta_unavailable_stale_read_secs,
             settings: HashMap::new(),
         }
     }
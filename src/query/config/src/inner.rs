@@ -172,6 +172,7 @@ pub struct QueryConfig {
     pub mysql_tls_server_key: String,
     pub max_active_sessions: u64,
     pub max_running_queries: u64,
+    pub max_queued_queries: u64,
     pub max_server_memory_usage: u64,
     pub max_memory_limit_enabled: bool,
     pub clickhouse_http_handler_host: String,
@@ -206,6 +207,7 @@ pub struct QueryConfig {
     /// Graceful shutdown timeout
     pub shutdown_wait_timeout_ms: u64,
     pub max_query_log_size: usize,
+    pub metrics_history_interval_secs: u64,
     pub databend_enterprise_license: Option<String>,
     /// If in management mode, only can do some meta level operations(database/table/user/stage etc.) with metasrv.
     pub management_mode: bool,
@@ -215,6 +217,10 @@ pub struct QueryConfig {
 
     pub jwt_key_file: String,
     pub jwt_key_files: Vec<String>,
+    pub jwt_allowed_issuers: Vec<String>,
+    pub jwt_allowed_audiences: Vec<String>,
+    pub jwt_role_claim: String,
+    pub jwt_role_mapping: Vec<String>,
     pub default_storage_format: String,
     pub default_compression: String,
     pub builtin: BuiltInConfig,
@@ -246,6 +252,9 @@ pub struct QueryConfig {
     pub cloud_control_grpc_server_address: Option<String>,
     pub cloud_control_grpc_timeout: u64,
     pub max_cached_queries_profiles: usize,
+    /// Serve read-only queries from cached metadata for up to this many seconds
+    /// while the meta service is unreachable, 0 disables it.
+    pub meta_unavailable_stale_read_secs: u64,
     pub settings: HashMap<String, UserSettingValue>,
 }
 
@@ -264,6 +273,7 @@ impl Default for QueryConfig {
             mysql_tls_server_key: "".to_string(),
             max_active_sessions: 256,
             max_running_queries: 8,
+            max_queued_queries: 0,
             max_server_memory_usage: 0,
             max_memory_limit_enabled: false,
             clickhouse_http_handler_host: "127.0.0.1".to_string(),
@@ -292,12 +302,17 @@ impl Default for QueryConfig {
             table_engine_memory_enabled: true,
             shutdown_wait_timeout_ms: 5000,
             max_query_log_size: 10_000,
+            metrics_history_interval_secs: 0,
             databend_enterprise_license: None,
             management_mode: false,
             parquet_fast_read_bytes: None,
             max_storage_io_requests: None,
             jwt_key_file: "".to_string(),
             jwt_key_files: Vec::new(),
+            jwt_allowed_issuers: Vec::new(),
+            jwt_allowed_audiences: Vec::new(),
+            jwt_role_claim: "".to_string(),
+            jwt_role_mapping: Vec::new(),
             default_storage_format: "auto".to_string(),
             default_compression: "auto".to_string(),
             builtin: BuiltInConfig::default(),
@@ -322,6 +337,7 @@ impl Default for QueryConfig {
             cloud_control_grpc_timeout: 0,
             data_retention_time_in_days_max: 90,
             max_cached_queries_profiles: 50,
+            meta_unavailable_stale_read_secs: 0,
             settings: HashMap::new(),
         }
     }
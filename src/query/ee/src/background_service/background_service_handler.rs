@@ -51,6 +51,7 @@ use crate::background_service::session::create_session;
 use crate::background_service::session::get_background_service_user;
 use crate::background_service::CompactionJob;
 use crate::background_service::JobScheduler;
+use crate::background_service::VacuumJob;
 
 pub struct RealBackgroundService {
     conf: InnerConfig,
@@ -152,6 +153,12 @@ impl RealBackgroundService {
             .await?;
             scheduler.add_job(compactor_job).await?;
         }
+        if conf.background.vacuum.enable {
+            let vacuum_job =
+                RealBackgroundService::get_vacuum_job(meta_api.clone(), conf, &user.identity())
+                    .await?;
+            scheduler.add_job(vacuum_job).await?;
+        }
 
         let rm = RealBackgroundService {
             conf: conf.clone(),
@@ -207,6 +214,41 @@ impl RealBackgroundService {
         Ok(job)
     }
 
+    pub fn get_vacuum_job_name(tenant: String) -> String {
+        format!("{}-vacuum-job", tenant)
+    }
+
+    async fn get_vacuum_job(
+        meta: Arc<MetaStore>,
+        conf: &InnerConfig,
+        creator: &UserIdentity,
+    ) -> Result<VacuumJob> {
+        let tenant = conf.query.tenant_id.clone();
+        let name = RealBackgroundService::get_vacuum_job_name(
+            conf.query.tenant_id.tenant_name().to_string(),
+        );
+        let id = BackgroundJobIdent::new(tenant, name);
+
+        let info = BackgroundJobInfo::new_vacuum_job(
+            conf.background.vacuum.params.clone(),
+            creator.clone(),
+        );
+        meta.create_background_job(CreateBackgroundJobReq {
+            if_not_exists: true,
+            job_name: id.clone(),
+            job_info: info,
+        })
+        .await?;
+        meta.update_background_job_params(UpdateBackgroundJobParamsReq {
+            job_name: id.clone(),
+            params: conf.background.vacuum.params.clone(),
+        })
+        .await?;
+        Self::suspend_job(meta.clone(), &id, false).await?;
+
+        VacuumJob::create(conf, id.name()).await
+    }
+
     async fn update_compaction_job_params(
         meta: Arc<MetaStore>,
         id: &BackgroundJobIdent,
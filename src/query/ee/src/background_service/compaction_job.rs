@@ -21,6 +21,8 @@ use databend_common_base::base::tokio::sync::mpsc::Sender;
 use databend_common_base::base::tokio::sync::Mutex;
 use databend_common_base::base::tokio::time::Instant;
 use databend_common_base::base::uuid::Uuid;
+use databend_common_catalog::catalog_kind::CATALOG_DEFAULT;
+use databend_common_catalog::table_context::TableContext;
 use databend_common_config::InnerConfig;
 use databend_common_exception::Result;
 use databend_common_expression::types::StringType;
@@ -45,6 +47,8 @@ use databend_common_meta_app::schema::TableStatistics;
 use databend_common_meta_app::KeyWithTenant;
 use databend_common_meta_store::MetaStore;
 use databend_common_meta_types::SeqV;
+use databend_common_storages_fuse::FUSE_OPT_KEY_AUTO_COMPACTION_BLOCK_COUNT;
+use databend_common_storages_fuse::FUSE_OPT_KEY_ENABLE_AUTO_COMPACTION;
 use databend_common_users::UserApiProvider;
 use databend_query::sessions::QueryContext;
 use databend_query::sessions::Session;
@@ -280,11 +284,20 @@ impl CompactionJob {
         tb_id: u64,
         manual: Option<ManualTriggerParams>,
     ) -> Result<()> {
+        let (enabled, blk_cnt) =
+            Self::get_table_compaction_options(session.clone(), &database, &table).await?;
+        // The opt-out only applies to automatic scheduling, a manually triggered job or
+        // the configured target tables are still compacted.
+        let automatic = manual.is_none() && !self.conf.background.compaction.has_target_tables();
+        if automatic && !enabled {
+            info!(job = "compaction", background = true, database = database.clone(), table = table.clone(); "skip compact, auto compaction is disabled by table option");
+            return Ok(());
+        }
         let (seg, blk, stats) = Self::do_check_table(
             session.clone(),
             database.clone(),
             table.clone(),
-            BLOCK_COUNT,
+            blk_cnt,
             PER_SEGMENT_BLOCK,
             PER_BLOCK_SIZE,
         )
@@ -339,7 +352,7 @@ impl CompactionJob {
         let start = Instant::now();
 
         match self
-            .do_compact_table(session.clone(), database.clone(), table.clone())
+            .do_compact_table(session.clone(), database.clone(), table.clone(), blk_cnt)
             .await
         {
             Ok(_) => {
@@ -347,7 +360,7 @@ impl CompactionJob {
                     session.clone(),
                     database.clone(),
                     table.clone(),
-                    BLOCK_COUNT,
+                    blk_cnt,
                     PER_SEGMENT_BLOCK,
                     PER_BLOCK_SIZE,
                 )
@@ -385,12 +398,13 @@ impl CompactionJob {
         session: Arc<Session>,
         database: String,
         table: String,
+        blk_cnt: u64,
     ) -> Result<bool> {
         let (seg, blk, stats) = Self::do_check_table(
             session.clone(),
             database.clone(),
             table.clone(),
-            BLOCK_COUNT,
+            blk_cnt,
             PER_SEGMENT_BLOCK,
             PER_BLOCK_SIZE,
         )
@@ -413,7 +427,7 @@ impl CompactionJob {
                     session.clone(),
                     database.clone(),
                     table.clone(),
-                    BLOCK_COUNT,
+                    blk_cnt,
                     PER_SEGMENT_BLOCK,
                     PER_BLOCK_SIZE,
                 )
@@ -439,7 +453,7 @@ impl CompactionJob {
                     session.clone(),
                     database.clone(),
                     table.clone(),
-                    BLOCK_COUNT,
+                    blk_cnt,
                     PER_SEGMENT_BLOCK,
                     PER_BLOCK_SIZE,
                 )
@@ -550,6 +564,27 @@ impl CompactionJob {
         Ok(res)
     }
 
+    // Table options `enable_auto_compaction` and `auto_compaction_block_count` override
+    // whether and when the table is picked up by the automatic background compaction.
+    async fn get_table_compaction_options(
+        session: Arc<Session>,
+        database: &str,
+        table: &str,
+    ) -> Result<(bool, u64)> {
+        let ctx = session.create_query_context().await?;
+        let tbl = ctx.get_table(CATALOG_DEFAULT, database, table).await?;
+        let options = tbl.options();
+        let enabled = options
+            .get(FUSE_OPT_KEY_ENABLE_AUTO_COMPACTION)
+            .and_then(|v| v.to_lowercase().parse::<bool>().ok())
+            .unwrap_or(true);
+        let blk_cnt = options
+            .get(FUSE_OPT_KEY_AUTO_COMPACTION_BLOCK_COUNT)
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or(BLOCK_COUNT);
+        Ok((enabled, blk_cnt))
+    }
+
     pub async fn do_check_table(
         session: Arc<Session>,
         database: String,
@@ -0,0 +1,198 @@
+// Copyright 2023 Databend Cloud
+//
+// Licensed under the Elastic License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.elastic.co/licensing/elastic-license
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::catalog_kind::CATALOG_DEFAULT;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_config::InnerConfig;
+use databend_common_exception::Result;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::ValueType;
+use databend_common_meta_api::BackgroundApi;
+use databend_common_meta_app::background::BackgroundJobIdent;
+use databend_common_meta_app::background::BackgroundJobInfo;
+use databend_common_meta_app::background::BackgroundJobParams;
+use databend_common_meta_app::background::BackgroundJobStatus;
+use databend_common_meta_app::background::GetBackgroundJobReq;
+use databend_common_meta_app::background::UpdateBackgroundJobParamsReq;
+use databend_common_meta_app::background::UpdateBackgroundJobStatusReq;
+use databend_common_meta_store::MetaStore;
+use databend_common_meta_types::SeqV;
+use databend_common_storages_fuse::FUSE_OPT_KEY_DATA_RETENTION_PERIOD_IN_HOURS;
+use databend_common_users::UserApiProvider;
+use databend_query::sessions::Session;
+use databend_query::table_functions::SuggestedBackgroundTasksSource;
+use log::debug;
+use log::error;
+use log::info;
+
+use crate::background_service::job::Job;
+use crate::background_service::session::create_session;
+
+/// Expires the snapshots of the tables that set their own `data_retention_period_in_hours`,
+/// by running `VACUUM TABLE` on them, which keeps the data within the table retention.
+#[derive(Clone)]
+pub struct VacuumJob {
+    conf: InnerConfig,
+    meta_api: Arc<MetaStore>,
+    creator: BackgroundJobIdent,
+}
+
+#[async_trait::async_trait]
+impl Job for VacuumJob {
+    async fn run(&mut self) {
+        info!(background = true, job_name :? =(&self.creator.clone()); "Vacuum job started");
+        if let Err(e) = self.do_vacuum_job().await {
+            error!(job = "vacuum", background = true; "vacuum job failed, err: {}", e);
+        }
+    }
+
+    async fn get_info(&self) -> Result<SeqV<BackgroundJobInfo>> {
+        let job = self
+            .meta_api
+            .get_background_job(GetBackgroundJobReq {
+                name: self.creator.clone(),
+            })
+            .await?;
+        Ok(job.info)
+    }
+
+    fn get_name(&self) -> BackgroundJobIdent {
+        self.creator.clone()
+    }
+
+    async fn update_job_status(&mut self, status: BackgroundJobStatus) -> Result<()> {
+        self.meta_api
+            .update_background_job_status(UpdateBackgroundJobStatusReq {
+                job_name: self.creator.clone(),
+                status,
+            })
+            .await?;
+        Ok(())
+    }
+
+    async fn update_job_params(&mut self, param: BackgroundJobParams) -> Result<()> {
+        self.meta_api
+            .update_background_job_params(UpdateBackgroundJobParamsReq {
+                job_name: self.creator.clone(),
+                params: param,
+            })
+            .await?;
+        Ok(())
+    }
+}
+
+impl VacuumJob {
+    pub async fn create(config: &InnerConfig, name: impl ToString) -> Result<Self> {
+        let tenant = config.query.tenant_id.clone();
+
+        let creator = BackgroundJobIdent::new(tenant, name);
+
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        Ok(Self {
+            conf: config.clone(),
+            meta_api,
+            creator,
+        })
+    }
+
+    async fn do_vacuum_job(&mut self) -> Result<()> {
+        let session = create_session(&self.conf).await?;
+        let ctx = session.create_query_context().await?;
+
+        let sql = Self::get_target_tables_sql();
+        let Some(records) = SuggestedBackgroundTasksSource::do_execute_sql(ctx, sql).await? else {
+            return Ok(());
+        };
+        let records = records.consume_convert_to_full();
+
+        let db_names =
+            StringType::try_downcast_column(records.columns()[0].value.as_column().unwrap())
+                .unwrap();
+        let tb_names =
+            StringType::try_downcast_column(records.columns()[1].value.as_column().unwrap())
+                .unwrap();
+
+        for i in 0..records.num_rows() {
+            let db_name = db_names.index(i).unwrap().to_string();
+            let tb_name = tb_names.index(i).unwrap().to_string();
+
+            match self.vacuum_table(session.clone(), &db_name, &tb_name).await {
+                Ok(true) => {
+                    info!("vacuum job success, db: {}, table: {}", db_name, tb_name);
+                }
+                Ok(false) => {}
+                Err(e) => {
+                    error!(
+                        "vacuum job failed, db: {}, table: {}, err: {}",
+                        db_name, tb_name, e
+                    );
+                }
+            }
+        }
+        info!(
+            job = "vacuum",
+            background = true;
+            "vacuum task is done"
+        );
+        Ok(())
+    }
+
+    // Only the tables with their own retention are vacuumed, the others keep relying on
+    // the explicit `VACUUM TABLE` and the global `data_retention_time_in_days`.
+    // Returns true if the table is vacuumed.
+    async fn vacuum_table(
+        &self,
+        session: Arc<Session>,
+        database: &str,
+        table: &str,
+    ) -> Result<bool> {
+        let ctx = session.create_query_context().await?;
+        let tbl = ctx.get_table(CATALOG_DEFAULT, database, table).await?;
+        if !tbl
+            .options()
+            .contains_key(FUSE_OPT_KEY_DATA_RETENTION_PERIOD_IN_HOURS)
+        {
+            return Ok(false);
+        }
+
+        let sql = Self::get_vacuum_table_sql(database, table);
+        debug!(
+            job = "vacuum",
+            background = true,
+            sql = sql.as_str();
+            "vacuum table"
+        );
+        let ctx = session.create_query_context().await?;
+        SuggestedBackgroundTasksSource::do_execute_sql(ctx, sql).await?;
+        Ok(true)
+    }
+
+    pub fn get_target_tables_sql() -> String {
+        "
+        SELECT t.database as database, t.name as table
+        FROM system.tables as t
+        WHERE t.database != 'system'
+            AND t.database != 'information_schema'
+            AND t.engine = 'FUSE'
+            ;
+        "
+        .to_string()
+    }
+
+    pub fn get_vacuum_table_sql(database: &str, table: &str) -> String {
+        format!("VACUUM TABLE {}.{};", database, table)
+    }
+}
@@ -77,6 +77,7 @@ async fn apply_block_pruning(
         push_down,
         bloom_index_cols,
         None,
+        false,
         FuseStorageFormat::Parquet,
     )?
     .read_pruning(segment_locs)
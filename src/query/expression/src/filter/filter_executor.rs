@@ -20,11 +20,13 @@ use databend_common_exception::Result;
 
 use crate::filter::SelectExpr;
 use crate::filter::Selector;
+use crate::Column;
 use crate::DataBlock;
 use crate::Evaluator;
 use crate::Expr;
 use crate::FunctionContext;
 use crate::FunctionRegistry;
+use crate::JitExpr;
 use crate::SelectExprBuilder;
 use crate::SELECTIVITY_THRESHOLD;
 
@@ -40,6 +42,7 @@ pub struct FilterExecutor {
     selection_range: Vec<Range<u32>>,
     fn_registry: &'static FunctionRegistry,
     keep_order: bool,
+    jit_expr: Option<JitExpr>,
 }
 
 impl FilterExecutor {
@@ -52,6 +55,10 @@ impl FilterExecutor {
         keep_order: bool,
     ) -> Self {
         let (select_expr, has_or) = SelectExprBuilder::new().build(&expr).into();
+        let jit_expr = match func_ctx.enable_expression_jit {
+            true => JitExpr::compile(&expr),
+            false => None,
+        };
 
         let true_selection = vec![0; max_block_size];
         let false_selection = if has_or {
@@ -70,6 +77,7 @@ impl FilterExecutor {
             selection_range: vec![],
             fn_registry,
             keep_order,
+            jit_expr,
         }
     }
 
@@ -82,6 +90,21 @@ impl FilterExecutor {
 
     // Store the filtered indices of data_block in `true_selection` and return the number of filtered indices.
     pub fn select(&mut self, data_block: &DataBlock) -> Result<usize> {
+        let jit_result = self
+            .jit_expr
+            .as_ref()
+            .and_then(|expr| expr.eval(data_block));
+        if let Some(Column::Boolean(filter)) = jit_result {
+            let mut count = 0;
+            for (idx, selected) in filter.iter().enumerate() {
+                if selected {
+                    self.true_selection[count] = idx as u32;
+                    count += 1;
+                }
+            }
+            return Ok(count);
+        }
+
         let evaluator = Evaluator::new(data_block, &self.func_ctx, self.fn_registry);
         let selector = Selector::new(evaluator, data_block.num_rows());
         selector.select(
@@ -114,6 +114,7 @@ pub struct FunctionContext {
     pub enable_dst_hour_fix: bool,
     pub enable_strict_datetime_parser: bool,
     pub random_function_seed: bool,
+    pub enable_expression_jit: bool,
 }
 
 impl Default for FunctionContext {
@@ -135,6 +136,7 @@ impl Default for FunctionContext {
             enable_dst_hour_fix: false,
             enable_strict_datetime_parser: true,
             random_function_seed: false,
+            enable_expression_jit: false,
         }
     }
 }
@@ -0,0 +1,477 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use cranelift_codegen::ir::condcodes::IntCC;
+use cranelift_codegen::ir::types;
+use cranelift_codegen::ir::AbiParam;
+use cranelift_codegen::ir::InstBuilder;
+use cranelift_codegen::ir::MemFlags;
+use cranelift_codegen::ir::Type;
+use cranelift_codegen::settings;
+use cranelift_codegen::settings::Configurable;
+use cranelift_frontend::FunctionBuilder;
+use cranelift_frontend::FunctionBuilderContext;
+use cranelift_jit::JITBuilder;
+use cranelift_jit::JITModule;
+use cranelift_module::Module;
+use databend_common_arrow::arrow::bitmap::Bitmap;
+use log::warn;
+
+use crate::types::number::Number;
+use crate::types::number::NumberColumn;
+use crate::types::number::NumberScalar;
+use crate::types::DataType;
+use crate::types::NumberDataType;
+use crate::with_number_mapped_type;
+use crate::Column;
+use crate::DataBlock;
+use crate::Expr;
+use crate::Scalar;
+use crate::Value;
+
+type JitFunction = unsafe extern "C" fn(*const *const u8, *mut u8, usize);
+
+/// A scalar expression compiled by cranelift into one loop over the rows of a block.
+///
+/// Only non-nullable numeric and boolean expressions built from column references,
+/// constants, widening casts, `plus`/`minus`/`multiply`, integer comparisons and
+/// `and`/`or`/`not` are compiled, see [`JitExpr::compile`]. Everything else is left
+/// to the [`Evaluator`](crate::Evaluator).
+pub struct JitExpr {
+    /// Offset and type of the input columns, in the order the compiled loop reads them.
+    columns: Vec<(usize, NumberDataType)>,
+    return_type: DataType,
+    function: JitFunction,
+    module: Option<JITModule>,
+}
+
+// The compiled code is immutable once finalized and the module is only touched again
+// when it is dropped.
+unsafe impl Send for JitExpr {}
+unsafe impl Sync for JitExpr {}
+
+impl Drop for JitExpr {
+    fn drop(&mut self) {
+        if let Some(module) = self.module.take() {
+            unsafe { module.free_memory() };
+        }
+    }
+}
+
+impl JitExpr {
+    /// Compile `expr`, returns `None` if it contains anything that can not be compiled.
+    pub fn compile(expr: &Expr) -> Option<JitExpr> {
+        let mut columns = BTreeMap::new();
+        if !is_supported(expr, &mut columns) {
+            return None;
+        }
+        let columns = columns.into_iter().collect::<Vec<_>>();
+
+        match Self::codegen(expr, &columns) {
+            Ok((module, function)) => Some(JitExpr {
+                columns,
+                return_type: expr.data_type().clone(),
+                function,
+                module: Some(module),
+            }),
+            Err(cause) => {
+                warn!("failed to compile {}: {}", expr.sql_display(), cause);
+                None
+            }
+        }
+    }
+
+    /// Evaluate the compiled expression over `block`, returns `None` if one of the input
+    /// columns is not a full column of the expected type, e.g. a constant.
+    pub fn eval(&self, block: &DataBlock) -> Option<Column> {
+        let num_rows = block.num_rows();
+        let mut inputs = Vec::with_capacity(self.columns.len());
+        for (offset, expected) in &self.columns {
+            let Value::Column(Column::Number(column)) = &block.get_by_offset(*offset).value else {
+                return None;
+            };
+            let (number_type, ptr) = number_column_ptr(column);
+            if number_type != *expected || column.len() < num_rows {
+                return None;
+            }
+            inputs.push(ptr);
+        }
+
+        match &self.return_type {
+            DataType::Boolean => {
+                let mut output = vec![0u8; num_rows];
+                unsafe { (self.function)(inputs.as_ptr(), output.as_mut_ptr(), num_rows) };
+                Some(Column::Boolean(Bitmap::from_iter(
+                    output.into_iter().map(|v| v != 0),
+                )))
+            }
+            DataType::Number(number_type) => {
+                with_number_mapped_type!(|NUM_TYPE| match number_type {
+                    NumberDataType::NUM_TYPE => {
+                        let mut output = vec![NUM_TYPE::default(); num_rows];
+                        unsafe {
+                            (self.function)(
+                                inputs.as_ptr(),
+                                output.as_mut_ptr() as *mut u8,
+                                num_rows,
+                            )
+                        };
+                        Some(Column::Number(NUM_TYPE::upcast_column(output.into())))
+                    }
+                })
+            }
+            _ => unreachable!("jit expression of type {}", self.return_type),
+        }
+    }
+
+    fn codegen(
+        expr: &Expr,
+        columns: &[(usize, NumberDataType)],
+    ) -> Result<(JITModule, JitFunction), String> {
+        let mut flag_builder = settings::builder();
+        flag_builder
+            .set("opt_level", "speed")
+            .map_err(|e| e.to_string())?;
+        let isa = cranelift_native::builder()
+            .map_err(|e| e.to_string())?
+            .finish(settings::Flags::new(flag_builder))
+            .map_err(|e| e.to_string())?;
+        let mut module = JITModule::new(JITBuilder::with_isa(
+            isa,
+            cranelift_module::default_libcall_names(),
+        ));
+
+        let ptr = module.target_config().pointer_type();
+        let mut ctx = module.make_context();
+        // fn(inputs: *const *const u8, output: *mut u8, num_rows: usize)
+        ctx.func.signature.params.push(AbiParam::new(ptr));
+        ctx.func.signature.params.push(AbiParam::new(ptr));
+        ctx.func.signature.params.push(AbiParam::new(ptr));
+
+        let mut func_ctx = FunctionBuilderContext::new();
+        let mut builder = FunctionBuilder::new(&mut ctx.func, &mut func_ctx);
+
+        let entry = builder.create_block();
+        let header = builder.create_block();
+        let body = builder.create_block();
+        let exit = builder.create_block();
+
+        builder.append_block_params_for_function_params(entry);
+        builder.switch_to_block(entry);
+        let params = builder.block_params(entry).to_vec();
+        let (inputs, output, num_rows) = (params[0], params[1], params[2]);
+        let mut column_ptrs = BTreeMap::new();
+        for (i, (offset, _)) in columns.iter().enumerate() {
+            let column_ptr = builder.ins().load(
+                ptr,
+                MemFlags::trusted(),
+                inputs,
+                (i * ptr.bytes() as usize) as i32,
+            );
+            column_ptrs.insert(*offset, column_ptr);
+        }
+        builder.append_block_param(header, ptr);
+        let zero = builder.ins().iconst(ptr, 0);
+        builder.ins().jump(header, &[zero]);
+
+        builder.switch_to_block(header);
+        let row = builder.block_params(header)[0];
+        let done = builder
+            .ins()
+            .icmp(IntCC::UnsignedGreaterThanOrEqual, row, num_rows);
+        builder.ins().brif(done, exit, &[], body, &[]);
+
+        builder.switch_to_block(body);
+        let value = Codegen {
+            builder: &mut builder,
+            column_ptrs: &column_ptrs,
+            row,
+        }
+        .expr(expr);
+        let output_type = ir_type(expr.data_type()).unwrap();
+        let output_offset = builder.ins().imul_imm(row, output_type.bytes() as i64);
+        let output_addr = builder.ins().iadd(output, output_offset);
+        builder
+            .ins()
+            .store(MemFlags::trusted(), value, output_addr, 0);
+        let next = builder.ins().iadd_imm(row, 1);
+        builder.ins().jump(header, &[next]);
+
+        builder.switch_to_block(exit);
+        builder.ins().return_(&[]);
+        builder.seal_all_blocks();
+        builder.finalize();
+
+        let id = module
+            .declare_anonymous_function(&ctx.func.signature)
+            .map_err(|e| e.to_string())?;
+        module
+            .define_function(id, &mut ctx)
+            .map_err(|e| e.to_string())?;
+        module.clear_context(&mut ctx);
+        module.finalize_definitions().map_err(|e| e.to_string())?;
+
+        let code = module.get_finalized_function(id);
+        let function = unsafe { std::mem::transmute::<*const u8, JitFunction>(code) };
+        Ok((module, function))
+    }
+}
+
+struct Codegen<'a, 'b> {
+    builder: &'a mut FunctionBuilder<'b>,
+    column_ptrs: &'a BTreeMap<usize, cranelift_codegen::ir::Value>,
+    row: cranelift_codegen::ir::Value,
+}
+
+impl<'a, 'b> Codegen<'a, 'b> {
+    fn expr(&mut self, expr: &Expr) -> cranelift_codegen::ir::Value {
+        match expr {
+            Expr::Constant {
+                scalar, data_type, ..
+            } => {
+                let ty = ir_type(data_type).unwrap();
+                match scalar {
+                    Scalar::Boolean(v) => self.builder.ins().iconst(ty, *v as i64),
+                    Scalar::Number(NumberScalar::Float32(v)) => self.builder.ins().f32const(v.0),
+                    Scalar::Number(NumberScalar::Float64(v)) => self.builder.ins().f64const(v.0),
+                    Scalar::Number(v) => {
+                        let bits = integer_bits(v);
+                        let mask = match ty.bits() {
+                            64 => u64::MAX,
+                            width => (1u64 << width) - 1,
+                        };
+                        self.builder.ins().iconst(ty, (bits & mask) as i64)
+                    }
+                    _ => unreachable!(),
+                }
+            }
+            Expr::ColumnRef { id, data_type, .. } => {
+                let ty = ir_type(data_type).unwrap();
+                let offset = self.builder.ins().imul_imm(self.row, ty.bytes() as i64);
+                let addr = self.builder.ins().iadd(self.column_ptrs[id], offset);
+                self.builder.ins().load(ty, MemFlags::trusted(), addr, 0)
+            }
+            Expr::Cast {
+                expr, dest_type, ..
+            } => {
+                let value = self.expr(expr);
+                self.convert(value, expr.data_type(), dest_type)
+            }
+            Expr::FunctionCall {
+                id,
+                args,
+                return_type,
+                ..
+            } => {
+                let mut values = Vec::with_capacity(args.len());
+                for arg in args {
+                    values.push(self.expr(arg));
+                }
+
+                let name = id.name();
+                match name.as_ref() {
+                    "plus" | "minus" | "multiply" => {
+                        let lhs = self.convert(values[0], args[0].data_type(), return_type);
+                        let rhs = self.convert(values[1], args[1].data_type(), return_type);
+                        let ins = self.builder.ins();
+                        match (name.as_ref(), is_float(return_type)) {
+                            ("plus", false) => ins.iadd(lhs, rhs),
+                            ("plus", true) => ins.fadd(lhs, rhs),
+                            ("minus", false) => ins.isub(lhs, rhs),
+                            ("minus", true) => ins.fsub(lhs, rhs),
+                            ("multiply", false) => ins.imul(lhs, rhs),
+                            _ => ins.fmul(lhs, rhs),
+                        }
+                    }
+                    "eq" | "noteq" | "gt" | "gte" | "lt" | "lte" => {
+                        let signed = args[0].data_type().as_number().unwrap().is_signed();
+                        let cc = match (name.as_ref(), signed) {
+                            ("eq", _) => IntCC::Equal,
+                            ("noteq", _) => IntCC::NotEqual,
+                            ("gt", true) => IntCC::SignedGreaterThan,
+                            ("gt", false) => IntCC::UnsignedGreaterThan,
+                            ("gte", true) => IntCC::SignedGreaterThanOrEqual,
+                            ("gte", false) => IntCC::UnsignedGreaterThanOrEqual,
+                            ("lt", true) => IntCC::SignedLessThan,
+                            ("lt", false) => IntCC::UnsignedLessThan,
+                            ("lte", true) => IntCC::SignedLessThanOrEqual,
+                            _ => IntCC::UnsignedLessThanOrEqual,
+                        };
+                        self.builder.ins().icmp(cc, values[0], values[1])
+                    }
+                    "and" | "and_filters" => values
+                        .into_iter()
+                        .reduce(|lhs, rhs| self.builder.ins().band(lhs, rhs))
+                        .unwrap(),
+                    "or" => self.builder.ins().bor(values[0], values[1]),
+                    "not" => self.builder.ins().bxor_imm(values[0], 1),
+                    _ => unreachable!("jit function {}", name),
+                }
+            }
+            Expr::LambdaFunctionCall { .. } => unreachable!(),
+        }
+    }
+
+    /// Convert like `AsPrimitive::as_`, which the arithmetic functions use to bring both
+    /// arguments to the return type.
+    fn convert(
+        &mut self,
+        value: cranelift_codegen::ir::Value,
+        from: &DataType,
+        to: &DataType,
+    ) -> cranelift_codegen::ir::Value {
+        let (from, to) = (*from.as_number().unwrap(), *to.as_number().unwrap());
+        if from == to {
+            return value;
+        }
+
+        let ty = number_ir_type(&to);
+        let ins = self.builder.ins();
+        match (from.is_float(), to.is_float()) {
+            (false, false) if to.bit_width() > from.bit_width() => match from.is_signed() {
+                true => ins.sextend(ty, value),
+                false => ins.uextend(ty, value),
+            },
+            (false, false) if to.bit_width() < from.bit_width() => ins.ireduce(ty, value),
+            (false, false) => value,
+            (false, true) => match from.is_signed() {
+                true => ins.fcvt_from_sint(ty, value),
+                false => ins.fcvt_from_uint(ty, value),
+            },
+            (true, true) if to.bit_width() > from.bit_width() => ins.fpromote(ty, value),
+            (true, true) => ins.fdemote(ty, value),
+            (true, false) => unreachable!(),
+        }
+    }
+}
+
+/// Check that `expr` can be compiled and collect the columns it reads.
+fn is_supported(expr: &Expr, columns: &mut BTreeMap<usize, NumberDataType>) -> bool {
+    if ir_type(expr.data_type()).is_none() {
+        return false;
+    }
+
+    match expr {
+        Expr::Constant { scalar, .. } => matches!(scalar, Scalar::Boolean(_) | Scalar::Number(_)),
+        Expr::ColumnRef { id, data_type, .. } => match data_type {
+            DataType::Number(number_type) => {
+                columns.insert(*id, *number_type);
+                true
+            }
+            // Boolean columns are bitmaps, not one byte per row.
+            _ => false,
+        },
+        Expr::Cast {
+            is_try: false,
+            expr,
+            dest_type,
+            ..
+        } => match (expr.data_type(), dest_type) {
+            (DataType::Number(from), DataType::Number(to)) => {
+                is_widening_cast(from, to) && is_supported(expr, columns)
+            }
+            _ => false,
+        },
+        Expr::FunctionCall {
+            id,
+            args,
+            return_type,
+            ..
+        } => {
+            let arg_types = args.iter().map(|arg| arg.data_type()).collect::<Vec<_>>();
+            let supported = match (id.name().as_ref(), arg_types.as_slice()) {
+                ("plus" | "minus" | "multiply", [DataType::Number(lhs), DataType::Number(rhs)]) => {
+                    matches!(return_type, DataType::Number(to)
+                        if to.is_float() || !(lhs.is_float() || rhs.is_float()))
+                }
+                (
+                    "eq" | "noteq" | "gt" | "gte" | "lt" | "lte",
+                    [DataType::Number(lhs), DataType::Number(rhs)],
+                ) => {
+                    // Float comparisons follow `OrderedFloat`, which orders NaN.
+                    lhs == rhs && !lhs.is_float()
+                }
+                ("and" | "or", [DataType::Boolean, DataType::Boolean]) => true,
+                ("and_filters", filters) => {
+                    !filters.is_empty() && filters.iter().all(|ty| **ty == DataType::Boolean)
+                }
+                ("not", [DataType::Boolean]) => true,
+                _ => false,
+            };
+            supported && args.iter().all(|arg| is_supported(arg, columns))
+        }
+        _ => false,
+    }
+}
+
+/// Casts between numbers that can never fail.
+fn is_widening_cast(from: &NumberDataType, to: &NumberDataType) -> bool {
+    match (from.is_float(), to.is_float()) {
+        _ if from == to => true,
+        (false, false) => {
+            to.bit_width() > from.bit_width() && (to.is_signed() || !from.is_signed())
+        }
+        (false, true) => *to == NumberDataType::Float64,
+        (true, true) => *to == NumberDataType::Float64,
+        (true, false) => false,
+    }
+}
+
+fn ir_type(data_type: &DataType) -> Option<Type> {
+    match data_type {
+        DataType::Boolean => Some(types::I8),
+        DataType::Number(number_type) => Some(number_ir_type(number_type)),
+        _ => None,
+    }
+}
+
+fn number_ir_type(number_type: &NumberDataType) -> Type {
+    match number_type {
+        NumberDataType::UInt8 | NumberDataType::Int8 => types::I8,
+        NumberDataType::UInt16 | NumberDataType::Int16 => types::I16,
+        NumberDataType::UInt32 | NumberDataType::Int32 => types::I32,
+        NumberDataType::UInt64 | NumberDataType::Int64 => types::I64,
+        NumberDataType::Float32 => types::F32,
+        NumberDataType::Float64 => types::F64,
+    }
+}
+
+fn is_float(data_type: &DataType) -> bool {
+    data_type
+        .as_number()
+        .map(|number_type| number_type.is_float())
+        .unwrap_or(false)
+}
+
+fn integer_bits(scalar: &NumberScalar) -> u64 {
+    match scalar {
+        NumberScalar::UInt8(v) => *v as u64,
+        NumberScalar::UInt16(v) => *v as u64,
+        NumberScalar::UInt32(v) => *v as u64,
+        NumberScalar::UInt64(v) => *v,
+        NumberScalar::Int8(v) => *v as u64,
+        NumberScalar::Int16(v) => *v as u64,
+        NumberScalar::Int32(v) => *v as u64,
+        NumberScalar::Int64(v) => *v as u64,
+        NumberScalar::Float32(_) | NumberScalar::Float64(_) => unreachable!(),
+    }
+}
+
+fn number_column_ptr(column: &NumberColumn) -> (NumberDataType, *const u8) {
+    with_number_mapped_type!(|NUM_TYPE| match column {
+        NumberColumn::NUM_TYPE(buffer) => (NumberDataType::NUM_TYPE, buffer.as_ptr() as *const u8),
+    })
+}
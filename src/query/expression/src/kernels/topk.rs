@@ -18,6 +18,7 @@ use std::intrinsics::assume;
 use std::mem;
 use std::ptr;
 
+use databend_common_arrow::arrow::bitmap::Bitmap;
 use databend_common_arrow::arrow::bitmap::MutableBitmap;
 use databend_common_base::runtime::drop_guard;
 
@@ -44,25 +45,46 @@ impl TopKSorter {
 
     // Push the column into this sorted and update the bitmap
     // The bitmap could be used in filter
+    //
+    // NULLs are always treated as sorted last: they never enter the heap and
+    // are filtered out once the heap is full.
     pub fn push_column(&mut self, col: &Column, bitmap: &mut MutableBitmap) {
+        let (col, validity) = match col {
+            Column::Nullable(c) => (&c.column, Some(&c.validity)),
+            col => (col, None),
+        };
         with_number_mapped_type!(|NUM_TYPE| match col.data_type() {
             DataType::Number(NumberDataType::NUM_TYPE) =>
-                self.push_column_internal::<NumberType::<NUM_TYPE>>(col, bitmap),
-            DataType::String => self.push_column_internal::<StringType>(col, bitmap),
-            DataType::Timestamp => self.push_column_internal::<TimestampType>(col, bitmap),
-            DataType::Date => self.push_column_internal::<DateType>(col, bitmap),
+                self.push_column_internal::<NumberType::<NUM_TYPE>>(col, validity, bitmap),
+            DataType::String => self.push_column_internal::<StringType>(col, validity, bitmap),
+            DataType::Timestamp =>
+                self.push_column_internal::<TimestampType>(col, validity, bitmap),
+            DataType::Date => self.push_column_internal::<DateType>(col, validity, bitmap),
             _ => {}
         });
     }
 
-    fn push_column_internal<T: ValueType>(&mut self, col: &Column, bitmap: &mut MutableBitmap)
-    where for<'a> T::ScalarRef<'a>: Ord {
+    fn push_column_internal<T: ValueType>(
+        &mut self,
+        col: &Column,
+        validity: Option<&Bitmap>,
+        bitmap: &mut MutableBitmap,
+    ) where
+        for<'a> T::ScalarRef<'a>: Ord,
+    {
         let col = T::try_downcast_column(col).unwrap();
         for (i, value) in T::iter_column(&col).enumerate() {
             if !bitmap.get(i) {
                 continue;
             }
 
+            if validity.is_some_and(|v| !v.get_bit(i)) {
+                if self.data.len() == self.limit {
+                    bitmap.set(i, false);
+                }
+                continue;
+            }
+
             if self.data.len() < self.limit {
                 self.data.push(T::upcast_scalar(T::to_owned_scalar(value)));
                 if self.data.len() == self.limit {
@@ -82,20 +104,25 @@ impl TopKSorter {
         selection: &mut [u32],
         count: usize,
     ) -> usize {
+        let (col, validity) = match col {
+            Column::Nullable(c) => (&c.column, Some(&c.validity)),
+            col => (col, None),
+        };
         with_number_mapped_type!(|NUM_TYPE| match col.data_type() {
             DataType::Number(NumberDataType::NUM_TYPE) => self
                 .push_column_with_selection_internal::<NumberType::<NUM_TYPE>, SELECT_ALL>(
-                    col, selection, count
+                    col, validity, selection, count
                 ),
             DataType::String => self.push_column_with_selection_internal::<StringType, SELECT_ALL>(
-                col, selection, count
+                col, validity, selection, count
             ),
             DataType::Timestamp => self
                 .push_column_with_selection_internal::<TimestampType, SELECT_ALL>(
-                    col, selection, count
+                    col, validity, selection, count
                 ),
-            DataType::Date => self
-                .push_column_with_selection_internal::<DateType, SELECT_ALL>(col, selection, count),
+            DataType::Date => self.push_column_with_selection_internal::<DateType, SELECT_ALL>(
+                col, validity, selection, count
+            ),
             _ => count,
         })
     }
@@ -103,6 +130,7 @@ impl TopKSorter {
     fn push_column_with_selection_internal<T: ValueType, const SELECT_ALL: bool>(
         &mut self,
         col: &Column,
+        validity: Option<&Bitmap>,
         selection: &mut [u32],
         count: usize,
     ) -> usize
@@ -113,6 +141,13 @@ impl TopKSorter {
         let mut result_count = 0;
         for i in 0..count {
             let idx = if SELECT_ALL { i as u32 } else { selection[i] };
+            if validity.is_some_and(|v| !v.get_bit(idx as usize)) {
+                if self.data.len() < self.limit {
+                    selection[result_count] = idx;
+                    result_count += 1;
+                }
+                continue;
+            }
             let value = unsafe { T::index_column_unchecked(&col, idx as usize) };
             if self.data.len() < self.limit {
                 self.data.push(T::upcast_scalar(T::to_owned_scalar(value)));
@@ -156,6 +191,10 @@ impl TopKSorter {
         if self.data.len() != self.limit {
             return false;
         }
+        // All values are NULL.
+        if matches!(min, Scalar::Null) {
+            return true;
+        }
         (self.asc && &self.data[0] < min) || (!self.asc && &self.data[0] > max)
     }
 
@@ -164,6 +203,9 @@ impl TopKSorter {
         if self.data.len() != self.limit {
             return false;
         }
+        if matches!(val, Scalar::Null) {
+            return true;
+        }
         (self.asc && &self.data[0] < val) || (!self.asc && &self.data[0] > val)
     }
 
@@ -172,22 +214,35 @@ impl TopKSorter {
         if self.data.len() != self.limit {
             return false;
         }
+        let (col, validity) = match col {
+            Column::Nullable(c) => (&c.column, Some(&c.validity)),
+            col => (col, None),
+        };
         with_number_mapped_type!(|NUM_TYPE| match col.data_type() {
             DataType::Number(NumberDataType::NUM_TYPE) =>
-                self.never_match_any_internal::<NumberType::<NUM_TYPE>>(col),
-            DataType::String => self.never_match_any_internal::<StringType>(col),
-            DataType::Timestamp => self.never_match_any_internal::<TimestampType>(col),
-            DataType::Date => self.never_match_any_internal::<DateType>(col),
+                self.never_match_any_internal::<NumberType::<NUM_TYPE>>(col, validity),
+            DataType::String => self.never_match_any_internal::<StringType>(col, validity),
+            DataType::Timestamp => self.never_match_any_internal::<TimestampType>(col, validity),
+            DataType::Date => self.never_match_any_internal::<DateType>(col, validity),
             _ => false,
         })
     }
 
-    fn never_match_any_internal<T: ValueType>(&self, col: &Column) -> bool
-    where for<'a> T::ScalarRef<'a>: Ord {
+    fn never_match_any_internal<T: ValueType>(
+        &self,
+        col: &Column,
+        validity: Option<&Bitmap>,
+    ) -> bool
+    where
+        for<'a> T::ScalarRef<'a>: Ord,
+    {
         let col = T::try_downcast_column(col).unwrap();
         let data = self.data[0].as_ref();
 
-        for val in T::iter_column(&col) {
+        for (i, val) in T::iter_column(&col).enumerate() {
+            if validity.is_some_and(|v| !v.get_bit(i)) {
+                continue;
+            }
             let data = T::try_downcast_scalar(&data).unwrap();
             if (self.asc && data >= val) || (!self.asc && data <= val) {
                 return false;
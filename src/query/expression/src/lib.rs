@@ -52,6 +52,7 @@ pub mod filter;
 mod function;
 mod hilbert;
 mod input_columns;
+mod jit;
 mod kernels;
 mod property;
 mod register;
@@ -72,6 +73,7 @@ pub use crate::filter::*;
 pub use crate::function::*;
 pub use crate::hilbert::*;
 pub use crate::input_columns::*;
+pub use crate::jit::*;
 pub use crate::kernels::*;
 pub use crate::property::*;
 pub use crate::register::*;
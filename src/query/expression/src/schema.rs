@@ -68,6 +68,8 @@ pub const CHANGE_ROW_ID_COL_NAME: &str = "change$row_id";
 
 pub const ROW_NUMBER_COL_NAME: &str = "_row_number";
 pub const PREDICATE_COLUMN_NAME: &str = "_predicate";
+// the partition path column appended for `COPY INTO <location> ... PARTITION BY`.
+pub const UNLOAD_PARTITION_COL_NAME: &str = "_unload$partition_path";
 
 // stream column id.
 pub const ORIGIN_BLOCK_ROW_NUM_COLUMN_ID: u32 = u32::MAX - 10;
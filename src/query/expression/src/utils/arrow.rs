@@ -18,12 +18,19 @@ use std::io::Seek;
 use std::io::Write;
 use std::sync::Arc;
 
+use arrow_array::builder::StringDictionaryBuilder;
+use arrow_array::cast::AsArray;
+use arrow_array::types::Int32Type;
+use arrow_array::Array as _;
+use arrow_array::ArrayRef;
 use arrow_array::RecordBatch;
 use arrow_ipc::reader::FileReaderBuilder;
 use arrow_ipc::writer::FileWriter;
 use arrow_ipc::writer::IpcWriteOptions;
 use arrow_ipc::CompressionType;
+use arrow_schema::Field;
 use arrow_schema::Schema;
+use arrow_select::take::take;
 use databend_common_arrow::arrow::array::Array;
 use databend_common_arrow::arrow::bitmap::Bitmap;
 use databend_common_arrow::arrow::bitmap::MutableBitmap;
@@ -79,20 +86,60 @@ pub fn write_column(
     col: &Column,
     w: &mut impl Write,
 ) -> std::result::Result<(), arrow_schema::ArrowError> {
-    let field: arrow_schema::Field = col.arrow_field().into();
+    write_column_with_compression(col, w, Some(CompressionType::LZ4_FRAME))
+}
+
+/// Write a column as an Arrow IPC file, buffers are compressed with `compression` if set.
+/// The compression is recorded in the IPC metadata, so `read_column` handles all of them.
+pub fn write_column_with_compression(
+    col: &Column,
+    w: &mut impl Write,
+    compression: Option<CompressionType>,
+) -> std::result::Result<(), arrow_schema::ArrowError> {
+    write_column_with_options(col, w, compression, false)
+}
+
+/// Same as `write_column_with_compression`, with `dictionary` a string column with
+/// few distinct values is written as a dictionary array, `read_column` decodes it back.
+pub fn write_column_with_options(
+    col: &Column,
+    w: &mut impl Write,
+    compression: Option<CompressionType>,
+    dictionary: bool,
+) -> std::result::Result<(), arrow_schema::ArrowError> {
+    let field: Field = col.arrow_field().into();
+    let mut array = col.clone().into_arrow_rs();
+    let field = match dictionary.then(|| dictionary_encode(&array)).flatten() {
+        Some(encoded) => {
+            array = encoded;
+            field.with_data_type(array.data_type().clone())
+        }
+        None => field,
+    };
     let schema = Schema::new(vec![field]);
     let mut writer = FileWriter::try_new_with_options(
         w,
         &schema,
-        IpcWriteOptions::default().try_with_compression(Some(CompressionType::LZ4_FRAME))?,
+        IpcWriteOptions::default().try_with_compression(compression)?,
     )?;
 
-    let batch = RecordBatch::try_new(Arc::new(schema), vec![col.clone().into_arrow_rs()])?;
+    let batch = RecordBatch::try_new(Arc::new(schema), vec![array])?;
 
     writer.write(&batch)?;
     writer.finish()
 }
 
+/// Dictionary encode a string array if at most half of its values are distinct.
+fn dictionary_encode(array: &ArrayRef) -> Option<ArrayRef> {
+    let strings = array.as_string_view_opt()?;
+    let mut builder = StringDictionaryBuilder::<Int32Type>::new();
+    for value in strings.iter() {
+        builder.append_option(value);
+    }
+    let encoded = builder.finish();
+    (encoded.values().len() * 2 <= encoded.len()).then(|| Arc::new(encoded) as ArrayRef)
+}
+
 pub fn deserialize_column(bytes: &[u8]) -> Result<Column> {
     let mut cursor = Cursor::new(bytes);
     read_column(&mut cursor)
@@ -101,13 +148,19 @@ pub fn deserialize_column(bytes: &[u8]) -> Result<Column> {
 pub fn read_column<R: Read + Seek>(r: &mut R) -> Result<Column> {
     let mut reader = FileReaderBuilder::new().build(r)?;
     let schema = reader.schema();
-    let f = DataField::try_from(schema.field(0))?;
+    let mut field = schema.field(0).clone();
 
-    let col = reader
+    let mut col = reader
         .next()
         .ok_or_else(|| ErrorCode::Internal("expected one arrow array"))??
         .remove_column(0);
 
+    if let Some(dict) = col.as_any_dictionary_opt() {
+        col = take(dict.values().as_ref(), dict.keys(), None)?;
+        field = field.with_data_type(col.data_type().clone());
+    }
+
+    let f = DataField::try_from(&field)?;
     Column::from_arrow_rs(col, f.data_type())
 }
 
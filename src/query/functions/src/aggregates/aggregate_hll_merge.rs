@@ -0,0 +1,210 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::sync::LazyLock;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::binary::BinaryColumnBuilder;
+use databend_common_expression::types::BinaryType;
+use databend_common_expression::types::DataType;
+use databend_common_expression::Scalar;
+use simple_hll::HyperLogLog;
+
+use super::aggregate_function::AggregateFunction;
+use super::aggregate_function_factory::AggregateFunctionDescription;
+use super::AggregateUnaryFunction;
+use super::FunctionData;
+use super::UnaryState;
+use crate::aggregates::aggregator_common::assert_unary_arguments;
+
+const DEFAULT_HLL_PRECISION: usize = 14;
+
+macro_rules! define_hll_sketch {
+    ($($variant:ident => $p:literal),* $(,)?) => {
+        /// A HyperLogLog sketch of any precision supported by `approx_count_distinct`.
+        ///
+        /// Sketches are stored in the same layout as the `approx_count_distinct_state`
+        /// aggregate state, the precision is recognized from the length of the payload.
+        #[derive(BorshSerialize, BorshDeserialize)]
+        pub enum HllSketch {
+            $($variant(HyperLogLog<$p>),)*
+        }
+
+        static HLL_SKETCH_SIZES: LazyLock<Vec<(usize, usize)>> = LazyLock::new(|| {
+            vec![$(($p, borsh::to_vec(&HyperLogLog::<$p>::default()).unwrap().len()),)*]
+        });
+
+        impl HllSketch {
+            pub fn empty(precision: usize) -> Result<Self> {
+                match precision {
+                    $($p => Ok(HllSketch::$variant(HyperLogLog::<$p>::default())),)*
+                    _ => Err(ErrorCode::BadArguments(format!(
+                        "Unsupported hll sketch precision {precision}"
+                    ))),
+                }
+            }
+
+            pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+                let precision = HLL_SKETCH_SIZES
+                    .iter()
+                    .find(|(_, size)| *size == bytes.len())
+                    .map(|(p, _)| *p)
+                    .ok_or_else(|| {
+                        ErrorCode::BadArguments(format!(
+                            "Invalid hll sketch of {} bytes, expected the output of approx_count_distinct_state or hll_merge",
+                            bytes.len()
+                        ))
+                    })?;
+                match precision {
+                    $($p => Ok(HllSketch::$variant(borsh::from_slice(bytes)?)),)*
+                    _ => unreachable!(),
+                }
+            }
+
+            pub fn precision(&self) -> usize {
+                match self {
+                    $(HllSketch::$variant(_) => $p,)*
+                }
+            }
+
+            pub fn to_bytes(&self) -> Result<Vec<u8>> {
+                match self {
+                    $(HllSketch::$variant(hll) => Ok(borsh::to_vec(hll)?),)*
+                }
+            }
+
+            pub fn cardinality(&self) -> u64 {
+                match self {
+                    $(HllSketch::$variant(hll) => hll.count() as u64,)*
+                }
+            }
+
+            pub fn merge(&mut self, other: &Self) -> Result<()> {
+                match (self, other) {
+                    $((HllSketch::$variant(lhs), HllSketch::$variant(rhs)) => {
+                        lhs.merge(rhs);
+                        Ok(())
+                    })*
+                    (lhs, rhs) => Err(ErrorCode::BadArguments(format!(
+                        "Cannot merge hll sketches of different precisions: {} and {}",
+                        lhs.precision(),
+                        rhs.precision()
+                    ))),
+                }
+            }
+        }
+    };
+}
+
+define_hll_sketch!(
+    P4 => 4,
+    P5 => 5,
+    P6 => 6,
+    P7 => 7,
+    P8 => 8,
+    P9 => 9,
+    P10 => 10,
+    P11 => 11,
+    P12 => 12,
+    P13 => 13,
+    P14 => 14,
+);
+
+/// Merge serialized sketches into a single sketch, keeping its precision.
+#[derive(Default, BorshSerialize, BorshDeserialize)]
+struct AggregateHllMergeState {
+    sketch: Option<HllSketch>,
+}
+
+impl AggregateHllMergeState {
+    fn merge_sketch(&mut self, rhs: &HllSketch) -> Result<()> {
+        match &mut self.sketch {
+            Some(sketch) => sketch.merge(rhs),
+            None => {
+                let mut sketch = HllSketch::empty(rhs.precision())?;
+                sketch.merge(rhs)?;
+                self.sketch = Some(sketch);
+                Ok(())
+            }
+        }
+    }
+}
+
+impl UnaryState<BinaryType, BinaryType> for AggregateHllMergeState {
+    fn add(&mut self, other: &[u8], _function_data: Option<&dyn FunctionData>) -> Result<()> {
+        let sketch = HllSketch::from_bytes(other)?;
+        match self.sketch {
+            Some(_) => self.merge_sketch(&sketch),
+            None => {
+                self.sketch = Some(sketch);
+                Ok(())
+            }
+        }
+    }
+
+    fn merge(&mut self, rhs: &Self) -> Result<()> {
+        match &rhs.sketch {
+            Some(sketch) => self.merge_sketch(sketch),
+            None => Ok(()),
+        }
+    }
+
+    fn merge_result(
+        &mut self,
+        builder: &mut BinaryColumnBuilder,
+        _function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        let bytes = match &self.sketch {
+            Some(sketch) => sketch.to_bytes()?,
+            None => HllSketch::empty(DEFAULT_HLL_PRECISION)?.to_bytes()?,
+        };
+        builder.put_slice(&bytes);
+        builder.commit_row();
+        Ok(())
+    }
+}
+
+pub fn try_create_aggregate_hll_merge_function(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<Arc<dyn AggregateFunction>> {
+    assert_unary_arguments(display_name, arguments.len())?;
+
+    if arguments[0].remove_nullable() != DataType::Binary {
+        return Err(ErrorCode::BadDataValueType(format!(
+            "{} expects binary hll sketches, but got {}",
+            display_name, arguments[0]
+        )));
+    }
+
+    let func =
+        AggregateUnaryFunction::<AggregateHllMergeState, BinaryType, BinaryType>::try_create(
+            display_name,
+            DataType::Binary,
+            params,
+            arguments[0].clone(),
+        )
+        .with_need_drop(true);
+
+    Ok(Arc::new(func))
+}
+
+pub fn aggregate_hll_merge_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_hll_merge_function))
+}
@@ -42,6 +42,7 @@ use super::borsh_serialize_state;
 use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
 use crate::aggregates::assert_params;
 use crate::aggregates::assert_unary_arguments;
+use crate::aggregates::assert_variadic_params;
 use crate::aggregates::AggregateFunction;
 use crate::aggregates::AggregateFunctionRef;
 use crate::aggregates::StateAddr;
@@ -49,6 +50,8 @@ use crate::BUILTIN_FUNCTIONS;
 
 pub(crate) const MEDIAN: u8 = 0;
 pub(crate) const QUANTILE: u8 = 1;
+pub(crate) const APPROX_PERCENTILE: u8 = 2;
+pub(crate) const APPROX_PERCENTILE_ARRAY: u8 = 3;
 
 #[derive(BorshSerialize, BorshDeserialize)]
 pub(crate) struct QuantileTDigestState {
@@ -112,20 +115,19 @@ impl QuantileTDigestState {
         builder: &mut ColumnBuilder,
         levels: Vec<f64>,
     ) -> Result<()> {
-        if levels.len() > 1 {
-            let builder = match builder {
-                ColumnBuilder::Array(box b) => b,
-                _ => unreachable!(),
-            };
-            levels.iter().for_each(|level| {
-                let q = self.quantile(*level);
-                builder.put_item(ScalarRef::Number(NumberScalar::Float64(q.into())))
-            });
-            builder.commit_row();
-        } else {
-            let builder = NumberType::<F64>::try_downcast_builder(builder).unwrap();
-            let q = self.quantile(levels[0]);
-            builder.push(q.into());
+        match builder {
+            ColumnBuilder::Array(box builder) => {
+                levels.iter().for_each(|level| {
+                    let q = self.quantile(*level);
+                    builder.put_item(ScalarRef::Number(NumberScalar::Float64(q.into())))
+                });
+                builder.commit_row();
+            }
+            _ => {
+                let builder = NumberType::<F64>::try_downcast_builder(builder).unwrap();
+                let q = self.quantile(levels[0]);
+                builder.push(q.into());
+            }
         }
         Ok(())
     }
@@ -470,14 +472,19 @@ pub fn try_create_aggregate_quantile_tdigest_function<const TYPE: u8>(
     params: Vec<Scalar>,
     arguments: Vec<DataType>,
 ) -> Result<AggregateFunctionRef> {
-    if TYPE == MEDIAN {
-        assert_params(display_name, params.len(), 0)?;
+    match TYPE {
+        MEDIAN => assert_params(display_name, params.len(), 0)?,
+        APPROX_PERCENTILE => assert_params(display_name, params.len(), 1)?,
+        APPROX_PERCENTILE_ARRAY => {
+            assert_variadic_params(display_name, params.len(), (1, usize::MAX))?
+        }
+        _ => {}
     }
 
     assert_unary_arguments(display_name, arguments.len())?;
     with_number_mapped_type!(|NUM_TYPE| match &arguments[0] {
         DataType::Number(NumberDataType::NUM_TYPE) => {
-            let return_type = if params.len() > 1 {
+            let return_type = if params.len() > 1 || TYPE == APPROX_PERCENTILE_ARRAY {
                 DataType::Array(Box::new(DataType::Number(NumberDataType::Float64)))
             } else {
                 DataType::Number(NumberDataType::Float64)
@@ -509,3 +516,15 @@ pub fn aggregate_median_tdigest_function_desc() -> AggregateFunctionDescription
         try_create_aggregate_quantile_tdigest_function::<MEDIAN>,
     ))
 }
+
+pub fn aggregate_approx_percentile_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_quantile_tdigest_function::<APPROX_PERCENTILE>,
+    ))
+}
+
+pub fn aggregate_approx_percentile_array_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_quantile_tdigest_function::<APPROX_PERCENTILE_ARRAY>,
+    ))
+}
@@ -28,6 +28,7 @@ use super::aggregate_combinator_distinct::aggregate_combinator_uniq_desc;
 use super::aggregate_combinator_state::AggregateStateCombinator;
 use super::aggregate_covariance::aggregate_covariance_population_desc;
 use super::aggregate_covariance::aggregate_covariance_sample_desc;
+use super::aggregate_hll_merge::aggregate_hll_merge_function_desc;
 use super::aggregate_min_max_any::aggregate_any_function_desc;
 use super::aggregate_min_max_any::aggregate_max_function_desc;
 use super::aggregate_min_max_any::aggregate_min_function_desc;
@@ -38,6 +39,8 @@ use super::aggregate_window_funnel::aggregate_window_funnel_function_desc;
 use super::AggregateCountFunction;
 use super::AggregateFunctionFactory;
 use super::AggregateIfCombinator;
+use crate::aggregates::aggregate_approx_percentile_array_function_desc;
+use crate::aggregates::aggregate_approx_percentile_function_desc;
 use crate::aggregates::aggregate_array_agg_function_desc;
 use crate::aggregates::aggregate_array_moving_avg_function_desc;
 use crate::aggregates::aggregate_array_moving_sum_function_desc;
@@ -70,6 +73,7 @@ impl Aggregators {
         factory.register("min", aggregate_min_function_desc());
         factory.register("max", aggregate_max_function_desc());
         factory.register("any", aggregate_any_function_desc());
+        factory.register("any_value", aggregate_any_function_desc());
         factory.register("arg_min", aggregate_arg_min_function_desc());
         factory.register("arg_max", aggregate_arg_max_function_desc());
 
@@ -90,6 +94,14 @@ impl Aggregators {
             "quantile_tdigest_weighted",
             aggregate_quantile_tdigest_weighted_function_desc(),
         );
+        factory.register(
+            "approx_percentile",
+            aggregate_approx_percentile_function_desc(),
+        );
+        factory.register(
+            "approx_percentile_array",
+            aggregate_approx_percentile_array_function_desc(),
+        );
         factory.register("median", aggregate_median_function_desc());
         factory.register("median_tdigest", aggregate_median_tdigest_function_desc());
         factory.register(
@@ -101,6 +113,7 @@ impl Aggregators {
             "approx_count_distinct",
             aggregate_approx_count_distinct_function_desc(),
         );
+        factory.register("hll_merge", aggregate_hll_merge_function_desc());
         factory.register("retention", aggregate_retention_function_desc());
         factory.register("array_agg", aggregate_array_agg_function_desc());
         factory.register("list", aggregate_array_agg_function_desc());
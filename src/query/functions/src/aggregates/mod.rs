@@ -29,6 +29,7 @@ mod aggregate_combinator_state;
 mod aggregate_covariance;
 mod aggregate_distinct_state;
 mod aggregate_histogram;
+mod aggregate_hll_merge;
 mod aggregate_json_array_agg;
 mod aggregate_json_object_agg;
 mod aggregate_kurtosis;
@@ -61,6 +62,7 @@ pub use aggregate_covariance::AggregateCovarianceFunction;
 pub use aggregate_function::*;
 pub use aggregate_function_factory::AggregateFunctionFactory;
 pub use aggregate_histogram::*;
+pub use aggregate_hll_merge::HllSketch;
 pub use aggregate_json_array_agg::*;
 pub use aggregate_json_object_agg::*;
 pub use aggregate_kurtosis::*;
@@ -29,10 +29,13 @@ use databend_common_expression::types::number::Float32Type;
 use databend_common_expression::types::number::Float64Type;
 use databend_common_expression::types::number::Int64Type;
 use databend_common_expression::types::number::UInt32Type;
+use databend_common_expression::types::number::UInt64Type;
 use databend_common_expression::types::number::UInt8Type;
 use databend_common_expression::types::number::F64;
 use databend_common_expression::types::string::StringColumn;
 use databend_common_expression::types::ArgType;
+use databend_common_expression::types::BinaryType;
+use databend_common_expression::types::BooleanType;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::DateType;
 use databend_common_expression::types::GenericType;
@@ -48,6 +51,7 @@ use databend_common_expression::types::TimestampType;
 use databend_common_expression::types::ValueType;
 use databend_common_expression::vectorize_with_builder_1_arg;
 use databend_common_expression::vectorize_with_builder_2_arg;
+use databend_common_expression::vectorize_with_builder_3_arg;
 use databend_common_expression::Column;
 use databend_common_expression::Domain;
 use databend_common_expression::EvalContext;
@@ -65,8 +69,10 @@ use databend_common_io::number::FmtCacheEntry;
 use rand::Rng;
 use rand::SeedableRng;
 
+use crate::aggregates::HllSketch;
+
 pub fn register(registry: &mut FunctionRegistry) {
-    registry.register_aliases("inet_aton", &["ipv4_string_to_num"]);
+    registry.register_aliases("inet_aton", &["ipv4_string_to_num", "ip_to_int"]);
     registry.register_aliases("try_inet_aton", &["try_ipv4_string_to_num"]);
     registry.register_aliases("inet_ntoa", &["ipv4_num_to_string"]);
     registry.register_aliases("try_inet_ntoa", &["try_ipv4_num_to_string"]);
@@ -75,9 +81,11 @@ pub fn register(registry: &mut FunctionRegistry) {
 
     register_inet_aton(registry);
     register_inet_ntoa(registry);
+    register_ip_range(registry);
     register_run_diff(registry);
     register_grouping(registry);
     register_num_to_char(registry);
+    register_hll_cardinality(registry);
 
     registry.properties.insert(
         "rand".to_string(),
@@ -274,6 +282,96 @@ fn register_inet_aton(registry: &mut FunctionRegistry) {
     }
 }
 
+fn register_ip_range(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_2_arg::<StringType, StringType, BooleanType, _, _>(
+        "ipv4_cidr_contains",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<StringType, StringType, BooleanType>(
+            |cidr, addr, output, ctx| match parse_ipv4_cidr(cidr)
+                .and_then(|range| Ok((range, parse_ipv4(addr)?)))
+            {
+                Ok(((lower, upper), addr)) => output.push(lower <= addr && addr <= upper),
+                Err(err) => {
+                    ctx.set_error(output.len(), err);
+                    output.push(false);
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<StringType, Int64Type, BooleanType, _, _>(
+        "ipv4_cidr_contains",
+        |_, cidr, addr| {
+            // A constant CIDR turns into an address range, so the domain of an address column
+            // (e.g. min/max of a block) can decide the result and prune blocks.
+            if cidr.max.as_ref() != Some(&cidr.min) {
+                return FunctionDomain::MayThrow;
+            }
+            match parse_ipv4_cidr(&cidr.min) {
+                Ok((lower, upper)) => FunctionDomain::Domain(BooleanDomain {
+                    has_true: addr.max >= lower as i64 && addr.min <= upper as i64,
+                    has_false: addr.min < lower as i64 || addr.max > upper as i64,
+                }),
+                Err(_) => FunctionDomain::MayThrow,
+            }
+        },
+        vectorize_with_builder_2_arg::<StringType, Int64Type, BooleanType>(
+            |cidr, addr, output, ctx| match parse_ipv4_cidr(cidr) {
+                Ok((lower, upper)) => {
+                    output.push(lower as i64 <= addr && addr <= upper as i64);
+                }
+                Err(err) => {
+                    ctx.set_error(output.len(), err);
+                    output.push(false);
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_3_arg::<StringType, StringType, StringType, BooleanType, _, _>(
+        "ip_in_range",
+        |_, _, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_3_arg::<StringType, StringType, StringType, BooleanType>(
+            |addr, start, end, output, ctx| {
+                let res = parse_ipv4(addr)
+                    .and_then(|addr| Ok((addr, parse_ipv4(start)?, parse_ipv4(end)?)));
+                match res {
+                    Ok((addr, start, end)) => output.push(start <= addr && addr <= end),
+                    Err(err) => {
+                        ctx.set_error(output.len(), err);
+                        output.push(false);
+                    }
+                }
+            },
+        ),
+    );
+}
+
+fn parse_ipv4(addr: &str) -> Result<u32, String> {
+    addr.parse::<Ipv4Addr>()
+        .map(u32::from)
+        .map_err(|_| format!("Failed to parse '{}' into a IPV4 address", addr))
+}
+
+/// Parses `a.b.c.d/prefix` into the inclusive range of addresses it covers.
+fn parse_ipv4_cidr(cidr: &str) -> Result<(u32, u32), String> {
+    let (addr, prefix) = match cidr.split_once('/') {
+        Some((addr, prefix)) => {
+            let prefix = prefix
+                .parse::<u32>()
+                .ok()
+                .filter(|prefix| *prefix <= 32)
+                .ok_or_else(|| format!("Invalid prefix length in IPV4 CIDR '{}'", cidr))?;
+            (addr, prefix)
+        }
+        None => (cidr, 32),
+    };
+    let addr = parse_ipv4(addr)?;
+    let mask = u32::MAX.checked_shl(32 - prefix).unwrap_or(0);
+    let lower = addr & mask;
+    Ok((lower, lower | !mask))
+}
+
 fn register_inet_ntoa(registry: &mut FunctionRegistry) {
     registry.register_passthrough_nullable_1_arg::<Int64Type, StringType, _, _>(
         "inet_ntoa",
@@ -488,3 +586,25 @@ pub fn compute_grouping(cols: &[usize], grouping_id: u32) -> u32 {
     }
     grouping
 }
+
+fn register_hll_cardinality(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<BinaryType, UInt64Type, _, _>(
+        "hll_cardinality",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<BinaryType, UInt64Type>(|sketch, output, ctx| {
+            if let Some(validity) = &ctx.validity {
+                if !validity.get_bit(output.len()) {
+                    output.push(0);
+                    return;
+                }
+            }
+            match HllSketch::from_bytes(sketch) {
+                Ok(sketch) => output.push(sketch.cardinality()),
+                Err(e) => {
+                    ctx.set_error(output.len(), e.message());
+                    output.push(0);
+                }
+            }
+        }),
+    );
+}
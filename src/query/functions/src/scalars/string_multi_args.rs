@@ -16,12 +16,16 @@ use std::sync::Arc;
 
 use databend_common_arrow::arrow::bitmap::MutableBitmap;
 use databend_common_expression::passthrough_nullable;
+use databend_common_expression::types::array::ArrayColumnBuilder;
 use databend_common_expression::types::nullable::NullableColumn;
+use databend_common_expression::types::nullable::NullableColumnBuilder;
 use databend_common_expression::types::number::Int64Type;
 use databend_common_expression::types::number::NumberScalar;
 use databend_common_expression::types::string::StringDomain;
 use databend_common_expression::types::NumberColumn;
 use databend_common_expression::types::*;
+use databend_common_expression::vectorize_with_builder_2_arg;
+use databend_common_expression::vectorize_with_builder_3_arg;
 use databend_common_expression::Column;
 use databend_common_expression::Domain;
 use databend_common_expression::EvalContext;
@@ -33,8 +37,11 @@ use databend_common_expression::FunctionSignature;
 use databend_common_expression::Scalar;
 use databend_common_expression::Value;
 use databend_common_expression::ValueRef;
+use regex::Regex;
 use string::StringColumnBuilder;
 
+use self::regexp::RegexpGroup;
+
 pub fn register(registry: &mut FunctionRegistry) {
     registry.register_function_factory("concat", |_, args_type| {
         if args_type.is_empty() {
@@ -417,6 +424,202 @@ pub fn register(registry: &mut FunctionRegistry) {
             Some(Arc::new(f))
         }
     });
+
+    register_regexp_extract(registry);
+}
+
+fn register_regexp_extract(registry: &mut FunctionRegistry) {
+    registry.register_combine_nullable_2_arg::<StringType, StringType, StringType, _, _>(
+        "regexp_extract",
+        |_, _, _| FunctionDomain::MayThrow,
+        |source, pat, ctx| {
+            eval_regexp_extract::<Int64Type, _>(
+                "regexp_extract",
+                source,
+                pat,
+                ValueRef::Scalar(0),
+                regexp_extract_row,
+                ctx,
+            )
+        },
+    );
+
+    registry
+        .register_combine_nullable_3_arg::<StringType, StringType, Int64Type, StringType, _, _>(
+            "regexp_extract",
+            |_, _, _, _| FunctionDomain::MayThrow,
+            |source, pat, group, ctx| {
+                eval_regexp_extract::<Int64Type, _>(
+                    "regexp_extract",
+                    source,
+                    pat,
+                    group,
+                    regexp_extract_row,
+                    ctx,
+                )
+            },
+        );
+
+    registry
+        .register_combine_nullable_3_arg::<StringType, StringType, StringType, StringType, _, _>(
+            "regexp_extract",
+            |_, _, _, _| FunctionDomain::MayThrow,
+            |source, pat, group, ctx| {
+                eval_regexp_extract::<StringType, _>(
+                    "regexp_extract",
+                    source,
+                    pat,
+                    group,
+                    regexp_extract_row,
+                    ctx,
+                )
+            },
+        );
+
+    registry
+        .register_passthrough_nullable_2_arg::<StringType, StringType, ArrayType<StringType>, _, _>(
+            "regexp_extract_all",
+            |_, _, _| FunctionDomain::MayThrow,
+            |source, pat, ctx| {
+                eval_regexp_extract::<Int64Type, _>(
+                    "regexp_extract_all",
+                    source,
+                    pat,
+                    ValueRef::Scalar(0),
+                    regexp_extract_all_row,
+                    ctx,
+                )
+            },
+        );
+
+    registry.register_passthrough_nullable_3_arg::<StringType, StringType, Int64Type, ArrayType<StringType>, _, _>(
+        "regexp_extract_all",
+        |_, _, _, _| FunctionDomain::MayThrow,
+        |source, pat, group, ctx| {
+            eval_regexp_extract::<Int64Type, _>(
+                "regexp_extract_all",
+                source,
+                pat,
+                group,
+                regexp_extract_all_row,
+                ctx,
+            )
+        },
+    );
+
+    registry.register_passthrough_nullable_3_arg::<StringType, StringType, StringType, ArrayType<StringType>, _, _>(
+        "regexp_extract_all",
+        |_, _, _, _| FunctionDomain::MayThrow,
+        |source, pat, group, ctx| {
+            eval_regexp_extract::<StringType, _>(
+                "regexp_extract_all",
+                source,
+                pat,
+                group,
+                regexp_extract_all_row,
+                ctx,
+            )
+        },
+    );
+}
+
+/// Type of the group argument of `regexp_extract` and `regexp_extract_all`.
+trait RegexpGroupType: ArgType {
+    fn to_group<'a>(group: Self::ScalarRef<'a>) -> RegexpGroup<'a>;
+}
+
+impl RegexpGroupType for Int64Type {
+    fn to_group<'a>(group: Self::ScalarRef<'a>) -> RegexpGroup<'a> {
+        RegexpGroup::Index(group)
+    }
+}
+
+impl RegexpGroupType for StringType {
+    fn to_group<'a>(group: Self::ScalarRef<'a>) -> RegexpGroup<'a> {
+        RegexpGroup::Name(group)
+    }
+}
+
+type RegexpExtractRow<O> = fn(
+    &str,
+    Result<&Regex, String>,
+    &RegexpGroup,
+    &mut <O as ValueType>::ColumnBuilder,
+    &mut EvalContext,
+);
+
+/// A constant pattern is compiled once for the whole block, other patterns are
+/// looked up in the per thread cache row by row.
+fn eval_regexp_extract<G: RegexpGroupType, O: ArgType>(
+    fn_name: &'static str,
+    source: ValueRef<StringType>,
+    pat: ValueRef<StringType>,
+    group: ValueRef<G>,
+    row: RegexpExtractRow<O>,
+    ctx: &mut EvalContext,
+) -> Value<O> {
+    if let ValueRef::Scalar(pat) = &pat {
+        // An invalid pattern falls through, so that the error is reported by each row.
+        if let Ok(re) = regexp::build_regexp_from_pattern(fn_name, pat, Some("c")) {
+            let re = &re;
+            return vectorize_with_builder_2_arg::<StringType, G, O>(
+                move |source, group, output, ctx| {
+                    row(source, Ok(re), &G::to_group(group), output, ctx)
+                },
+            )(source, group, ctx);
+        }
+    }
+
+    vectorize_with_builder_3_arg::<StringType, StringType, G, O>(
+        move |source, pat, group, output, ctx| {
+            let re = regexp::cached_regexp_from_pattern(fn_name, pat, Some("c"));
+            row(
+                source,
+                re.as_deref().map_err(Clone::clone),
+                &G::to_group(group),
+                output,
+                ctx,
+            )
+        },
+    )(source, pat, group, ctx)
+}
+
+fn regexp_extract_row(
+    source: &str,
+    re: Result<&Regex, String>,
+    group: &RegexpGroup,
+    output: &mut NullableColumnBuilder<StringType>,
+    ctx: &mut EvalContext,
+) {
+    match re.and_then(|re| regexp::regexp_extract(source, re, group)) {
+        Ok(Some(v)) => output.push(v),
+        Ok(None) => output.push_null(),
+        Err(err) => {
+            ctx.set_error(output.len(), err);
+            output.push_null();
+        }
+    }
+}
+
+fn regexp_extract_all_row(
+    source: &str,
+    re: Result<&Regex, String>,
+    group: &RegexpGroup,
+    output: &mut ArrayColumnBuilder<StringType>,
+    ctx: &mut EvalContext,
+) {
+    match re.and_then(|re| regexp::regexp_extract_all(source, re, group)) {
+        Ok(values) => {
+            for v in values {
+                output.put_item(v);
+            }
+            output.commit_row();
+        }
+        Err(err) => {
+            ctx.set_error(output.len(), err);
+            output.commit_row();
+        }
+    }
 }
 
 fn concat_fn(args: &[ValueRef<AnyType>], _: &mut EvalContext) -> Value<AnyType> {
@@ -521,7 +724,7 @@ fn regexp_instr_fn(args: &[ValueRef<AnyType>], ctx: &mut EvalContext) -> Value<A
 
         let mut local_re = None;
         if cached_reg.is_none() {
-            match regexp::build_regexp_from_pattern("regexp_instr", pat, mt) {
+            match regexp::cached_regexp_from_pattern("regexp_instr", pat, mt) {
                 Ok(re) => {
                     local_re = Some(re);
                 }
@@ -534,7 +737,7 @@ fn regexp_instr_fn(args: &[ValueRef<AnyType>], ctx: &mut EvalContext) -> Value<A
         };
         let re = cached_reg
             .as_ref()
-            .unwrap_or_else(|| local_re.as_ref().unwrap());
+            .unwrap_or_else(|| local_re.as_deref().unwrap());
 
         let pos = pos.unwrap_or(1);
         let occur = occur.unwrap_or(1);
@@ -593,7 +796,7 @@ fn regexp_like_fn(args: &[ValueRef<AnyType>], ctx: &mut EvalContext) -> Value<An
 
         let mut local_re = None;
         if cached_reg.is_none() {
-            match regexp::build_regexp_from_pattern("regexp_like", pat, mt) {
+            match regexp::cached_regexp_from_pattern("regexp_like", pat, mt) {
                 Ok(re) => {
                     local_re = Some(re);
                 }
@@ -606,7 +809,7 @@ fn regexp_like_fn(args: &[ValueRef<AnyType>], ctx: &mut EvalContext) -> Value<An
         };
         let re = cached_reg
             .as_ref()
-            .unwrap_or_else(|| local_re.as_ref().unwrap());
+            .unwrap_or_else(|| local_re.as_deref().unwrap());
         builder.push(re.is_match(source));
     }
     match len {
@@ -697,7 +900,7 @@ fn regexp_replace_fn(args: &[ValueRef<AnyType>], ctx: &mut EvalContext) -> Value
 
         let mut local_re = None;
         if cached_reg.is_none() {
-            match regexp::build_regexp_from_pattern("regexp_replace", pat, mt) {
+            match regexp::cached_regexp_from_pattern("regexp_replace", pat, mt) {
                 Ok(re) => {
                     local_re = Some(re);
                 }
@@ -710,7 +913,7 @@ fn regexp_replace_fn(args: &[ValueRef<AnyType>], ctx: &mut EvalContext) -> Value
         };
         let re = cached_reg
             .as_ref()
-            .unwrap_or_else(|| local_re.as_ref().unwrap());
+            .unwrap_or_else(|| local_re.as_deref().unwrap());
 
         let pos = pos.unwrap_or(1);
         let occur = occur.unwrap_or(0);
@@ -798,7 +1001,7 @@ fn regexp_substr_fn(args: &[ValueRef<AnyType>], ctx: &mut EvalContext) -> Value<
 
         let mut local_re = None;
         if cached_reg.is_none() {
-            match regexp::build_regexp_from_pattern("regexp_substr", pat, mt) {
+            match regexp::cached_regexp_from_pattern("regexp_substr", pat, mt) {
                 Ok(re) => {
                     local_re = Some(re);
                 }
@@ -812,7 +1015,7 @@ fn regexp_substr_fn(args: &[ValueRef<AnyType>], ctx: &mut EvalContext) -> Value<
         };
         let re = cached_reg
             .as_ref()
-            .unwrap_or_else(|| local_re.as_ref().unwrap());
+            .unwrap_or_else(|| local_re.as_deref().unwrap());
 
         let substr = regexp::regexp_substr(source, re, pos, occur);
         match substr {
@@ -845,10 +1048,68 @@ fn regexp_substr_fn(args: &[ValueRef<AnyType>], ctx: &mut EvalContext) -> Value<
 }
 
 pub mod regexp {
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+    use std::rc::Rc;
+
     use databend_common_expression::types::string::StringColumnBuilder;
     use regex::Regex;
     use regex::RegexBuilder;
 
+    /// Max number of compiled patterns kept by each thread.
+    const REGEXP_CACHE_CAPACITY: usize = 1024;
+
+    thread_local! {
+        /// Compiled patterns keyed by match type and then by pattern, so that non-constant
+        /// patterns are not recompiled for every row. A hit is looked up by `&str`.
+        static REGEXP_CACHE: RefCell<RegexpCache> = RefCell::new(RegexpCache::default());
+    }
+
+    #[derive(Default)]
+    struct RegexpCache {
+        len: usize,
+        patterns: HashMap<String, HashMap<String, Rc<Regex>>>,
+    }
+
+    /// The capture group to extract, by index or by name.
+    pub enum RegexpGroup<'a> {
+        Index(i64),
+        Name(&'a str),
+    }
+
+    /// Same as `build_regexp_from_pattern`, but reuses the patterns compiled by this thread,
+    /// for the patterns that are not constant.
+    pub fn cached_regexp_from_pattern(
+        fn_name: &str,
+        pat: &str,
+        mt: Option<&str>,
+    ) -> Result<Rc<Regex>, String> {
+        let mt = mt.unwrap_or_default();
+        let cached = REGEXP_CACHE.with(|cache| {
+            let cache = cache.borrow();
+            cache.patterns.get(mt).and_then(|m| m.get(pat)).cloned()
+        });
+        if let Some(re) = cached {
+            return Ok(re);
+        }
+
+        let re = Rc::new(build_regexp_from_pattern(fn_name, pat, Some(mt))?);
+        REGEXP_CACHE.with(|cache| {
+            let mut cache = cache.borrow_mut();
+            if cache.len >= REGEXP_CACHE_CAPACITY {
+                cache.patterns.clear();
+                cache.len = 0;
+            }
+            cache.len += 1;
+            cache
+                .patterns
+                .entry(mt.to_string())
+                .or_default()
+                .insert(pat.to_string(), re.clone());
+        });
+        Ok(re)
+    }
+
     #[inline]
     pub fn build_regexp_from_pattern(
         fn_name: &str,
@@ -1013,4 +1274,64 @@ pub mod regexp {
         let m = re.find_iter(&s[char_pos..]).nth((occur - 1) as _);
         m.map(|m| m.as_str())
     }
+
+    fn check_regexp_group(fn_name: &str, re: &Regex, group: &RegexpGroup) -> Result<(), String> {
+        match group {
+            RegexpGroup::Index(idx) => {
+                if *idx < 0 || *idx as usize >= re.captures_len() {
+                    return Err(format!(
+                        "Invalid capture group index {} for {}, the pattern has {} groups",
+                        idx,
+                        fn_name,
+                        re.captures_len() - 1
+                    ));
+                }
+            }
+            RegexpGroup::Name(name) => {
+                if !re.capture_names().flatten().any(|n| n == *name) {
+                    return Err(format!(
+                        "Unknown capture group name '{}' for {}",
+                        name, fn_name
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the first match of `group`, or None if the pattern or the group does not match.
+    pub fn regexp_extract<'a>(
+        s: &'a str,
+        re: &Regex,
+        group: &RegexpGroup,
+    ) -> Result<Option<&'a str>, String> {
+        check_regexp_group("regexp_extract", re, group)?;
+        let Some(caps) = re.captures(s) else {
+            return Ok(None);
+        };
+        let m = match group {
+            RegexpGroup::Index(idx) => caps.get(*idx as usize),
+            RegexpGroup::Name(name) => caps.name(name),
+        };
+        Ok(m.map(|m| m.as_str()))
+    }
+
+    /// Returns `group` of every match, unmatched optional groups are returned as empty strings.
+    pub fn regexp_extract_all<'a>(
+        s: &'a str,
+        re: &Regex,
+        group: &RegexpGroup,
+    ) -> Result<Vec<&'a str>, String> {
+        check_regexp_group("regexp_extract_all", re, group)?;
+        Ok(re
+            .captures_iter(s)
+            .map(|caps| {
+                let m = match group {
+                    RegexpGroup::Index(idx) => caps.get(*idx as usize),
+                    RegexpGroup::Name(name) => caps.name(name),
+                };
+                m.map(|m| m.as_str()).unwrap_or("")
+            })
+            .collect())
+    }
 }
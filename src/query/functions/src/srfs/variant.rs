@@ -420,6 +420,14 @@ pub fn register(registry: &mut FunctionRegistry) {
                         max_nums_per_row.iter_mut().enumerate().take(ctx.num_rows)
                     {
                         match arg.index(row).unwrap() {
+                            ScalarRef::Null if outer => {
+                                // `OUTER => TRUE` keeps the input row even if it is NULL,
+                                // so that `LATERAL FLATTEN` behaves like a left join.
+                                let columns =
+                                    generator.generate((row + 1) as u64, &[], "", &params);
+                                *max_nums_per_row = std::cmp::max(*max_nums_per_row, 1);
+                                results.push((Value::Column(Column::Tuple(columns)), 1));
+                            }
                             ScalarRef::Null => {
                                 results.push((
                                     Value::Scalar(Scalar::Tuple(vec![
@@ -0,0 +1,96 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_expression::type_check;
+use databend_common_expression::types::*;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Evaluator;
+use databend_common_expression::FromData;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::JitExpr;
+use databend_common_expression::Value;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+
+use super::parser;
+
+#[test]
+fn test_jit_expr() {
+    let columns = [
+        ("a", Int32Type::from_data(vec![1i32, -2, 3, i32::MAX, 0])),
+        ("b", Int32Type::from_data(vec![4i32, 5, -6, 1, 0])),
+        ("c", UInt64Type::from_data(vec![7u64, 0, 9, u64::MAX, 1])),
+        (
+            "d",
+            Float64Type::from_data(vec![0.5f64, -1.5, 2.0, 1e300, 0.0]),
+        ),
+        (
+            "e",
+            Int32Type::from_data_with_validity(vec![1i32, 2, 3, 4, 5], vec![
+                true, false, true, true, false,
+            ]),
+        ),
+        ("s", StringType::from_data(vec!["a", "b", "c", "d", "e"])),
+    ];
+
+    for text in [
+        "a + b",
+        "a * b - a",
+        "d * d + d",
+        "a + d",
+        "a > b",
+        "a <= 3",
+        "c > 8",
+        "a > b and not (a = 3)",
+        "a < 0 or c = 0",
+    ] {
+        assert!(check_jit(text, &columns), "{text} should be compiled");
+    }
+
+    for text in ["e + 1", "d > 1.0", "concat(s, s)", "a / b"] {
+        assert!(!check_jit(text, &columns), "{text} should not be compiled");
+    }
+}
+
+/// Returns whether `text` was compiled, and if so checks the result against the evaluator.
+fn check_jit(text: &str, columns: &[(&str, Column)]) -> bool {
+    let raw_expr = parser::parse_raw_expr(
+        text,
+        &columns
+            .iter()
+            .map(|(name, c)| (*name, c.data_type()))
+            .collect::<Vec<_>>(),
+    );
+    let expr = type_check::check(&raw_expr, &BUILTIN_FUNCTIONS).unwrap();
+    let Some(jit_expr) = JitExpr::compile(&expr) else {
+        return false;
+    };
+
+    let num_rows = columns[0].1.len();
+    let block = DataBlock::new(
+        columns
+            .iter()
+            .map(|(_, c)| BlockEntry::new(c.data_type(), Value::Column(c.clone())))
+            .collect(),
+        num_rows,
+    );
+    let func_ctx = FunctionContext::default();
+    let expected = Evaluator::new(&block, &func_ctx, &BUILTIN_FUNCTIONS)
+        .run(&expr)
+        .unwrap()
+        .convert_to_full_column(expr.data_type(), num_rows);
+    assert_eq!(jit_expr.eval(&block).unwrap(), expected, "{text}");
+    true
+}
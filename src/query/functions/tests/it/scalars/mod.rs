@@ -46,6 +46,7 @@ mod geo_h3;
 mod geography;
 mod geometry;
 mod hash;
+mod jit;
 mod map;
 mod math;
 mod misc;
@@ -284,6 +284,7 @@ impl Compiler {
                 span: expr.span(),
                 hints: None,
                 distinct: false,
+                distinct_on: None,
                 top_n: None,
                 select_list: vec![SelectTarget::AliasedExpr {
                     expr: Box::new(expr.clone()),
@@ -362,6 +363,7 @@ impl Compiler {
                 span: variable.span,
                 hints: None,
                 distinct: false,
+                distinct_on: None,
                 top_n: None,
                 select_list: vec![SelectTarget::StarColumns {
                     qualified: vec![Indirection::Star(None)],
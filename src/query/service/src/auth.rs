@@ -72,7 +72,7 @@ impl Credential {
 
 impl AuthMgr {
     pub fn init(cfg: &InnerConfig) -> Result<()> {
-        GlobalInstance::set(AuthMgr::create(cfg));
+        GlobalInstance::set(AuthMgr::create(cfg)?);
         Ok(())
     }
 
@@ -80,13 +80,20 @@ impl AuthMgr {
         GlobalInstance::get()
     }
 
-    fn create(cfg: &InnerConfig) -> Arc<AuthMgr> {
-        Arc::new(AuthMgr {
+    fn create(cfg: &InnerConfig) -> Result<Arc<AuthMgr>> {
+        Ok(Arc::new(AuthMgr {
             jwt_auth: JwtAuthenticator::create(
                 cfg.query.jwt_key_file.clone(),
                 cfg.query.jwt_key_files.clone(),
-            ),
-        })
+            )
+            .map(|auth| {
+                auth.with_allowed_issuers(cfg.query.jwt_allowed_issuers.clone())
+                    .with_allowed_audiences(cfg.query.jwt_allowed_audiences.clone())
+                    .with_role_claim(cfg.query.jwt_role_claim.clone())
+                    .with_role_mapping(cfg.query.jwt_role_mapping.clone())
+            })
+            .transpose()?,
+        }))
     }
 
     #[async_backtrace::framed]
@@ -119,6 +126,7 @@ impl AuthMgr {
                     .as_ref()
                     .ok_or_else(|| ErrorCode::AuthenticateFailure("jwt auth not configured."))?;
                 let jwt = jwt_auth.parse_jwt_claims(t.as_str()).await?;
+                let claim_roles = jwt_auth.claim_roles(&jwt.custom);
                 let user_name = jwt.subject.ok_or_else(|| {
                     ErrorCode::AuthenticateFailure(
                         "jwt auth not configured correctly, user name is missing.",
@@ -135,7 +143,7 @@ impl AuthMgr {
                 let identity = UserIdentity::new(&user_name, "%");
 
                 // create a new user for this identity if not exists
-                let user = match user_api
+                let mut user = match user_api
                     .get_user_with_client_ip(&tenant, identity.clone(), client_ip.as_deref())
                     .await
                 {
@@ -169,6 +177,18 @@ impl AuthMgr {
                     }
                 };
 
+                // the roles mapped from the role claim are only available to this session,
+                // they are not granted to the user in meta, roles that do not exist are ignored
+                let granted_roles = user.grants.roles();
+                for role in claim_roles {
+                    if granted_roles.contains(&role)
+                        || user_api.get_role(&tenant, role.clone()).await.is_err()
+                    {
+                        continue;
+                    }
+                    user.grants.grant_role(role);
+                }
+
                 session.set_authed_user(user, jwt.custom.role).await?;
                 Ok((user_name, None))
             }
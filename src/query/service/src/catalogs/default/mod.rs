@@ -17,6 +17,7 @@ mod database_catalog;
 mod immutable_catalog;
 mod mutable_catalog;
 mod session_catalog;
+mod stale_meta_cache;
 pub mod table_memory_meta;
 
 pub use catalog_context::CatalogContext;
@@ -24,3 +25,4 @@ pub use database_catalog::DatabaseCatalog;
 pub use immutable_catalog::ImmutableCatalog;
 pub use mutable_catalog::MutableCatalog;
 pub use session_catalog::SessionCatalog;
+pub use stale_meta_cache::StaleMetaCache;
@@ -16,10 +16,12 @@ use std::any::Any;
 use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::sync::Arc;
+use std::time::Duration;
 use std::time::Instant;
 
 use databend_common_catalog::catalog::Catalog;
 use databend_common_config::InnerConfig;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_meta_api::kv_app_error::KVAppError;
 use databend_common_meta_api::name_id_value_api::NameIdValueApiCompat;
@@ -123,6 +125,7 @@ use log::info;
 use log::warn;
 
 use crate::catalogs::default::catalog_context::CatalogContext;
+use crate::catalogs::default::stale_meta_cache::StaleMetaCache;
 use crate::databases::Database;
 use crate::databases::DatabaseContext;
 use crate::databases::DatabaseFactory;
@@ -140,6 +143,8 @@ pub struct MutableCatalog {
     ctx: CatalogContext,
     tenant: Tenant,
     disable_table_info_refresh: bool,
+    /// Serves read-only metadata while the meta service is unreachable, if enabled.
+    stale_meta_cache: Option<Arc<StaleMetaCache>>,
 }
 
 impl Debug for MutableCatalog {
@@ -194,10 +199,16 @@ impl MutableCatalog {
             storage_factory: Arc::new(storage_factory),
             database_factory: Arc::new(database_factory),
         };
+        let stale_meta_cache = match conf.query.meta_unavailable_stale_read_secs {
+            0 => None,
+            secs => Some(Arc::new(StaleMetaCache::create(Duration::from_secs(secs)))),
+        };
+
         Ok(MutableCatalog {
             ctx,
             tenant,
             disable_table_info_refresh: false,
+            stale_meta_cache,
         })
     }
 
@@ -216,6 +227,18 @@ impl MutableCatalog {
     pub(crate) fn disable_table_info_refresh(&mut self) {
         self.disable_table_info_refresh = true;
     }
+
+    /// Every write to the meta service goes through here, so that writes fail with a clear
+    /// rejection while the meta service is unreachable, see [`StaleMetaCache::reject_write`].
+    fn write_result<T, E: Into<ErrorCode>>(&self, res: std::result::Result<T, E>) -> Result<T> {
+        res.map_err(|e| {
+            let err = e.into();
+            match &self.stale_meta_cache {
+                Some(cache) => cache.reject_write(err),
+                None => err,
+            }
+        })
+    }
 }
 
 #[async_trait::async_trait]
@@ -234,11 +257,21 @@ impl Catalog for MutableCatalog {
 
     #[async_backtrace::framed]
     async fn get_database(&self, tenant: &Tenant, db_name: &str) -> Result<Arc<dyn Database>> {
-        let db_info = self
+        let res = self
             .ctx
             .meta
             .get_database(GetDatabaseReq::new(tenant, db_name))
-            .await?;
+            .await;
+
+        let db_info = match (res, &self.stale_meta_cache) {
+            (Ok(db_info), Some(cache)) => {
+                cache.put_database(tenant, db_name, &db_info);
+                db_info
+            }
+            (Ok(db_info), None) => db_info,
+            (Err(err), Some(cache)) => cache.get_database(err.into(), tenant, db_name)?,
+            (Err(err), None) => return Err(err.into()),
+        };
 
         self.build_db_instance(&db_info)
     }
@@ -297,7 +330,7 @@ impl Catalog for MutableCatalog {
     #[async_backtrace::framed]
     async fn create_database(&self, req: CreateDatabaseReq) -> Result<CreateDatabaseReply> {
         // Create database.
-        let res = self.ctx.meta.create_database(req.clone()).await?;
+        let res = self.write_result(self.ctx.meta.create_database(req.clone()).await)?;
         info!(
             "db name: {}, engine: {}",
             req.name_ident.database_name(),
@@ -318,18 +351,23 @@ impl Catalog for MutableCatalog {
 
     #[async_backtrace::framed]
     async fn drop_database(&self, req: DropDatabaseReq) -> Result<DropDatabaseReply> {
-        Ok(self.ctx.meta.drop_database(req).await?)
+        let name_ident = req.name_ident.clone();
+        let res = self.write_result(self.ctx.meta.drop_database(req).await)?;
+        if let Some(cache) = &self.stale_meta_cache {
+            cache.invalidate_database(name_ident.tenant(), name_ident.database_name());
+        }
+        Ok(res)
     }
 
     #[async_backtrace::framed]
     async fn create_index(&self, req: CreateIndexReq) -> Result<CreateIndexReply> {
-        Ok(self.ctx.meta.create_index(req).await?)
+        self.write_result(self.ctx.meta.create_index(req).await)
     }
 
     #[async_backtrace::framed]
     async fn drop_index(&self, req: DropIndexReq) -> Result<()> {
         let res = self.ctx.meta.drop_index(&req.name_ident).await;
-        let dropped = res.map_err(KVAppError::from)?;
+        let dropped = self.write_result(res.map_err(KVAppError::from))?;
 
         if dropped.is_none() {
             if req.if_exists {
@@ -354,7 +392,8 @@ impl Catalog for MutableCatalog {
         let index_id = IndexId::new(req.index_id);
         let id_ident = IndexIdIdent::new_generic(tenant, index_id);
 
-        let change = self.ctx.meta.update_index(id_ident, req.index_meta).await?;
+        let change =
+            self.write_result(self.ctx.meta.update_index(id_ident, req.index_meta).await)?;
 
         if !change.is_changed() {
             Err(
@@ -399,17 +438,17 @@ impl Catalog for MutableCatalog {
 
     #[async_backtrace::framed]
     async fn create_virtual_column(&self, req: CreateVirtualColumnReq) -> Result<()> {
-        Ok(self.ctx.meta.create_virtual_column(req).await?)
+        self.write_result(self.ctx.meta.create_virtual_column(req).await)
     }
 
     #[async_backtrace::framed]
     async fn update_virtual_column(&self, req: UpdateVirtualColumnReq) -> Result<()> {
-        Ok(self.ctx.meta.update_virtual_column(req).await?)
+        self.write_result(self.ctx.meta.update_virtual_column(req).await)
     }
 
     #[async_backtrace::framed]
     async fn drop_virtual_column(&self, req: DropVirtualColumnReq) -> Result<()> {
-        Ok(self.ctx.meta.drop_virtual_column(req).await?)
+        self.write_result(self.ctx.meta.drop_virtual_column(req).await)
     }
 
     #[async_backtrace::framed]
@@ -422,13 +461,16 @@ impl Catalog for MutableCatalog {
 
     #[async_backtrace::framed]
     async fn undrop_database(&self, req: UndropDatabaseReq) -> Result<UndropDatabaseReply> {
-        let res = self.ctx.meta.undrop_database(req).await?;
-        Ok(res)
+        self.write_result(self.ctx.meta.undrop_database(req).await)
     }
 
     #[async_backtrace::framed]
     async fn rename_database(&self, req: RenameDatabaseReq) -> Result<RenameDatabaseReply> {
-        let res = self.ctx.meta.rename_database(req).await?;
+        let name_ident = req.name_ident.clone();
+        let res = self.write_result(self.ctx.meta.rename_database(req).await)?;
+        if let Some(cache) = &self.stale_meta_cache {
+            cache.invalidate_database(name_ident.tenant(), name_ident.database_name());
+        }
         Ok(res)
     }
 
@@ -509,7 +551,22 @@ impl Catalog for MutableCatalog {
         table_name: &str,
     ) -> Result<Arc<dyn Table>> {
         let db = self.get_database(tenant, db_name).await?;
-        db.get_table(table_name).await
+        let res = db.get_table(table_name).await;
+
+        let Some(cache) = &self.stale_meta_cache else {
+            return res;
+        };
+
+        match res {
+            Ok(table) => {
+                cache.put_table(tenant, db_name, table.get_table_info());
+                Ok(table)
+            }
+            Err(err) => {
+                let table_info = cache.get_table(err, tenant, db_name, table_name)?;
+                self.get_table_by_info(&table_info)
+            }
+        }
     }
 
     #[async_backtrace::framed]
@@ -573,8 +630,7 @@ impl Catalog for MutableCatalog {
 
     async fn gc_drop_tables(&self, req: GcDroppedTableReq) -> Result<()> {
         let meta = self.ctx.meta.clone();
-        let resp = meta.gc_drop_tables(req).await?;
-        Ok(resp)
+        self.write_result(meta.gc_drop_tables(req).await)
     }
 
     #[async_backtrace::framed]
@@ -582,12 +638,26 @@ impl Catalog for MutableCatalog {
         let db = self
             .get_database(&req.name_ident.tenant, &req.name_ident.db_name)
             .await?;
-        db.create_table(req).await
+        let name_ident = req.name_ident.clone();
+        let res = self.write_result(db.create_table(req).await)?;
+        // `CREATE OR REPLACE` replaces the table under the same name.
+        if let Some(cache) = &self.stale_meta_cache {
+            cache.invalidate_table(
+                &name_ident.tenant,
+                &name_ident.db_name,
+                &name_ident.table_name,
+            );
+        }
+        Ok(res)
     }
 
     #[async_backtrace::framed]
     async fn drop_table_by_id(&self, req: DropTableByIdReq) -> Result<DropTableReply> {
-        let res = self.ctx.meta.drop_table_by_id(req).await?;
+        let (tenant, table_id) = (req.tenant.clone(), req.tb_id);
+        let res = self.write_result(self.ctx.meta.drop_table_by_id(req).await)?;
+        if let Some(cache) = &self.stale_meta_cache {
+            cache.invalidate_table_by_id(&tenant, table_id);
+        }
         Ok(res)
     }
 
@@ -596,19 +666,18 @@ impl Catalog for MutableCatalog {
         let db = self
             .get_database(&req.name_ident.tenant, &req.name_ident.db_name)
             .await?;
-        db.undrop_table(req).await
+        self.write_result(db.undrop_table(req).await)
     }
 
     async fn undrop_table_by_id(&self, req: UndropTableByIdReq) -> Result<()> {
-        let res = self.ctx.meta.undrop_table_by_id(req).await?;
-        Ok(res)
+        self.write_result(self.ctx.meta.undrop_table_by_id(req).await)
     }
 
     async fn commit_table_meta(&self, req: CommitTableMetaReq) -> Result<CommitTableMetaReply> {
         let db = self
             .get_database(&req.name_ident.tenant, &req.name_ident.db_name)
             .await?;
-        db.commit_table_meta(req).await
+        self.write_result(db.commit_table_meta(req).await)
     }
 
     #[async_backtrace::framed]
@@ -616,7 +685,16 @@ impl Catalog for MutableCatalog {
         let db = self
             .get_database(&req.name_ident.tenant, &req.name_ident.db_name)
             .await?;
-        db.rename_table(req).await
+        let name_ident = req.name_ident.clone();
+        let res = self.write_result(db.rename_table(req).await)?;
+        if let Some(cache) = &self.stale_meta_cache {
+            cache.invalidate_table(
+                &name_ident.tenant,
+                &name_ident.db_name,
+                &name_ident.table_name,
+            );
+        }
+        Ok(res)
     }
 
     #[async_backtrace::framed]
@@ -627,7 +705,7 @@ impl Catalog for MutableCatalog {
         req: UpsertTableOptionReq,
     ) -> Result<UpsertTableOptionReply> {
         let db = self.get_database(tenant, db_name).await?;
-        db.upsert_table_option(req).await
+        self.write_result(db.upsert_table_option(req).await)
     }
 
     #[async_backtrace::framed]
@@ -654,14 +732,14 @@ impl Catalog for MutableCatalog {
             "update multi table meta done. time used {:?}",
             begin.elapsed()
         );
-        Ok(res?)
+        self.write_result(res)
     }
 
     async fn set_table_column_mask_policy(
         &self,
         req: SetTableColumnMaskPolicyReq,
     ) -> Result<SetTableColumnMaskPolicyReply> {
-        Ok(self.ctx.meta.set_table_column_mask_policy(req).await?)
+        self.write_result(self.ctx.meta.set_table_column_mask_policy(req).await)
     }
 
     #[async_backtrace::framed]
@@ -682,18 +760,18 @@ impl Catalog for MutableCatalog {
         req: TruncateTableReq,
     ) -> Result<TruncateTableReply> {
         match table_info.db_type.clone() {
-            DatabaseType::NormalDB => Ok(self.ctx.meta.truncate_table(req).await?),
+            DatabaseType::NormalDB => self.write_result(self.ctx.meta.truncate_table(req).await),
         }
     }
 
     #[async_backtrace::framed]
     async fn create_table_index(&self, req: CreateTableIndexReq) -> Result<()> {
-        Ok(self.ctx.meta.create_table_index(req).await?)
+        self.write_result(self.ctx.meta.create_table_index(req).await)
     }
 
     #[async_backtrace::framed]
     async fn drop_table_index(&self, req: DropTableIndexReq) -> Result<()> {
-        Ok(self.ctx.meta.drop_table_index(req).await?)
+        self.write_result(self.ctx.meta.drop_table_index(req).await)
     }
 
     #[async_backtrace::framed]
@@ -703,17 +781,17 @@ impl Catalog for MutableCatalog {
 
     #[async_backtrace::framed]
     async fn create_lock_revision(&self, req: CreateLockRevReq) -> Result<CreateLockRevReply> {
-        Ok(self.ctx.meta.create_lock_revision(req).await?)
+        self.write_result(self.ctx.meta.create_lock_revision(req).await)
     }
 
     #[async_backtrace::framed]
     async fn extend_lock_revision(&self, req: ExtendLockRevReq) -> Result<()> {
-        Ok(self.ctx.meta.extend_lock_revision(req).await?)
+        self.write_result(self.ctx.meta.extend_lock_revision(req).await)
     }
 
     #[async_backtrace::framed]
     async fn delete_lock_revision(&self, req: DeleteLockRevReq) -> Result<()> {
-        Ok(self.ctx.meta.delete_lock_revision(req).await?)
+        self.write_result(self.ctx.meta.delete_lock_revision(req).await)
     }
 
     #[async_backtrace::framed]
@@ -726,7 +804,7 @@ impl Catalog for MutableCatalog {
     }
 
     async fn create_sequence(&self, req: CreateSequenceReq) -> Result<CreateSequenceReply> {
-        Ok(self.ctx.meta.create_sequence(req).await?)
+        self.write_result(self.ctx.meta.create_sequence(req).await)
     }
 
     async fn get_sequence(&self, req: GetSequenceReq) -> Result<GetSequenceReply> {
@@ -747,22 +825,22 @@ impl Catalog for MutableCatalog {
         &self,
         req: GetSequenceNextValueReq,
     ) -> Result<GetSequenceNextValueReply> {
-        Ok(self.ctx.meta.get_sequence_next_value(req).await?)
+        self.write_result(self.ctx.meta.get_sequence_next_value(req).await)
     }
 
     async fn drop_sequence(&self, req: DropSequenceReq) -> Result<DropSequenceReply> {
-        Ok(self.ctx.meta.drop_sequence(req).await?)
+        self.write_result(self.ctx.meta.drop_sequence(req).await)
     }
 
     /// Dictionary
     #[async_backtrace::framed]
     async fn create_dictionary(&self, req: CreateDictionaryReq) -> Result<CreateDictionaryReply> {
-        Ok(self.ctx.meta.create_dictionary(req).await?)
+        self.write_result(self.ctx.meta.create_dictionary(req).await)
     }
 
     #[async_backtrace::framed]
     async fn update_dictionary(&self, req: UpdateDictionaryReq) -> Result<UpdateDictionaryReply> {
-        Ok(self.ctx.meta.update_dictionary(req).await?)
+        self.write_result(self.ctx.meta.update_dictionary(req).await)
     }
 
     #[async_backtrace::framed]
@@ -771,8 +849,7 @@ impl Catalog for MutableCatalog {
         dict_ident: DictionaryNameIdent,
     ) -> Result<Option<SeqV<DictionaryMeta>>> {
         let reply = self.ctx.meta.drop_dictionary(dict_ident.clone()).await;
-        let reply = reply.map_err(KVAppError::from)?;
-        Ok(reply)
+        self.write_result(reply.map_err(KVAppError::from))
     }
 
     #[async_backtrace::framed]
@@ -795,7 +872,6 @@ impl Catalog for MutableCatalog {
 
     #[async_backtrace::framed]
     async fn rename_dictionary(&self, req: RenameDictionaryReq) -> Result<()> {
-        let res = self.ctx.meta.rename_dictionary(req).await?;
-        Ok(res)
+        self.write_result(self.ctx.meta.rename_dictionary(req).await)
     }
 }
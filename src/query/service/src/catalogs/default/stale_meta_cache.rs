@@ -0,0 +1,244 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use databend_common_cache::Cache;
+use databend_common_cache::LruCache;
+use databend_common_cache::MemSized;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::DatabaseInfo;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_meta_types::MetaId;
+use log::warn;
+use parking_lot::Mutex;
+
+const MAX_CACHED_DATABASES: usize = 1024;
+const MAX_CACHED_TABLES: usize = 16 * 1024;
+/// Tables are read by every query, the table cache is sharded to keep lock contention low.
+const TABLE_CACHE_SHARDS: usize = 16;
+
+/// The caches are bounded by the number of items, the size of an entry does not matter.
+struct Entry<T>(T);
+
+impl<T> MemSized for Entry<T> {
+    fn mem_bytes(&self) -> usize {
+        std::mem::size_of::<T>()
+    }
+}
+
+fn database_key(tenant: &Tenant, db_name: &str) -> String {
+    format!("{}\0{}", tenant.tenant_name(), db_name)
+}
+
+fn table_key(tenant: &Tenant, db_name: &str, table_name: &str) -> String {
+    format!("{}\0{}\0{}", tenant.tenant_name(), db_name, table_name)
+}
+
+/// Metadata last read from the meta service, used to keep serving read-only queries
+/// while the meta service is briefly unreachable.
+///
+/// The cache is refreshed by every successful read. Once the meta service cannot be
+/// reached, cached entries are served for at most `max_staleness` since the last
+/// successful read, and writes are rejected with [`ErrorCode::MetaServiceUnavailable`].
+///
+/// Both caches are LRU bounded. The DDL executed on this node invalidates the affected
+/// entries, DDL from other nodes is only picked up by the next successful read.
+pub struct StaleMetaCache {
+    max_staleness: Duration,
+    created_at: Instant,
+    // milliseconds since `created_at`
+    last_success: AtomicU64,
+    databases: Mutex<LruCache<String, Entry<Arc<DatabaseInfo>>>>,
+    tables: Vec<Mutex<LruCache<String, Entry<Arc<TableInfo>>>>>,
+}
+
+impl StaleMetaCache {
+    pub fn create(max_staleness: Duration) -> Self {
+        Self::create_with_capacity(max_staleness, MAX_CACHED_DATABASES, MAX_CACHED_TABLES)
+    }
+
+    /// A small table cache is not sharded, so that its LRU bound is exact.
+    pub fn create_with_capacity(
+        max_staleness: Duration,
+        max_databases: usize,
+        max_tables: usize,
+    ) -> Self {
+        let shards = if max_tables >= TABLE_CACHE_SHARDS * 64 {
+            TABLE_CACHE_SHARDS
+        } else {
+            1
+        };
+        let tables = (0..shards)
+            .map(|_| Mutex::new(LruCache::with_items_capacity(max_tables.div_ceil(shards))))
+            .collect();
+        StaleMetaCache {
+            max_staleness,
+            created_at: Instant::now(),
+            last_success: AtomicU64::new(0),
+            databases: Mutex::new(LruCache::with_items_capacity(max_databases)),
+            tables,
+        }
+    }
+
+    pub fn put_database(&self, tenant: &Tenant, db_name: &str, db_info: &Arc<DatabaseInfo>) {
+        self.mark_success();
+        let key = database_key(tenant, db_name);
+        self.databases.lock().insert(key, Entry(db_info.clone()));
+    }
+
+    pub fn put_table(&self, tenant: &Tenant, db_name: &str, table_info: &TableInfo) {
+        self.mark_success();
+        let key = table_key(tenant, db_name, &table_info.name);
+        let shard = self.table_shard(&key);
+
+        // Most reads return the cached version of the table, skip copying the table info then.
+        if let Some(cached) = shard.lock().get(&key) {
+            if cached.0.ident == table_info.ident {
+                return;
+            }
+        }
+
+        let table_info = Arc::new(table_info.clone());
+        shard.lock().insert(key, Entry(table_info));
+    }
+
+    /// Forget a dropped or renamed database and all of its tables.
+    pub fn invalidate_database(&self, tenant: &Tenant, db_name: &str) {
+        let key = database_key(tenant, db_name);
+        self.databases.lock().pop(&key);
+
+        let prefix = format!("{key}\0");
+        for shard in &self.tables {
+            let mut tables = shard.lock();
+            let keys = tables
+                .iter()
+                .filter(|(k, _)| k.starts_with(&prefix))
+                .map(|(k, _)| k.clone())
+                .collect::<Vec<_>>();
+            for key in keys {
+                tables.pop(&key);
+            }
+        }
+    }
+
+    /// Forget a dropped, renamed or replaced table.
+    pub fn invalidate_table(&self, tenant: &Tenant, db_name: &str, table_name: &str) {
+        let key = table_key(tenant, db_name, table_name);
+        self.table_shard(&key).lock().pop(&key);
+    }
+
+    /// Forget a table dropped by id.
+    pub fn invalidate_table_by_id(&self, tenant: &Tenant, table_id: MetaId) {
+        let prefix = format!("{}\0", tenant.tenant_name());
+        for shard in &self.tables {
+            let mut tables = shard.lock();
+            let keys = tables
+                .iter()
+                .filter(|(k, v)| k.starts_with(&prefix) && v.0.ident.table_id == table_id)
+                .map(|(k, _)| k.clone())
+                .collect::<Vec<_>>();
+            for key in keys {
+                tables.pop(&key);
+            }
+        }
+    }
+
+    /// Serves `db_name` from the cache if `err` is caused by an unreachable meta service.
+    pub fn get_database(
+        &self,
+        err: ErrorCode,
+        tenant: &Tenant,
+        db_name: &str,
+    ) -> Result<Arc<DatabaseInfo>> {
+        self.check_fallback(err, || {
+            let key = database_key(tenant, db_name);
+            self.databases.lock().get(&key).map(|v| v.0.clone())
+        })
+    }
+
+    /// Serves `db_name.table_name` from the cache if `err` is caused by an unreachable meta service.
+    pub fn get_table(
+        &self,
+        err: ErrorCode,
+        tenant: &Tenant,
+        db_name: &str,
+        table_name: &str,
+    ) -> Result<TableInfo> {
+        self.check_fallback(err, || {
+            let key = table_key(tenant, db_name, table_name);
+            let cached = self.table_shard(&key).lock().get(&key).map(|v| v.0.clone());
+            cached.map(|table_info| table_info.as_ref().clone())
+        })
+    }
+
+    /// Turns a write that failed because of an unreachable meta service into a clear rejection.
+    pub fn reject_write(&self, err: ErrorCode) -> ErrorCode {
+        if err.code() != ErrorCode::META_SERVICE_ERROR {
+            return err;
+        }
+
+        ErrorCode::MetaServiceUnavailable(format!(
+            "Meta service is unavailable, only read-only queries on cached metadata are served, DDL and DML are rejected: {}",
+            err.message()
+        ))
+    }
+
+    fn mark_success(&self) {
+        let elapsed = self.created_at.elapsed().as_millis() as u64;
+        self.last_success.fetch_max(elapsed, Ordering::Relaxed);
+    }
+
+    fn table_shard(&self, key: &str) -> &Mutex<LruCache<String, Entry<Arc<TableInfo>>>> {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        &self.tables[hasher.finish() as usize % self.tables.len()]
+    }
+
+    fn check_fallback<T>(&self, err: ErrorCode, lookup: impl FnOnce() -> Option<T>) -> Result<T> {
+        if err.code() != ErrorCode::META_SERVICE_ERROR {
+            return Err(err);
+        }
+
+        let last_success = Duration::from_millis(self.last_success.load(Ordering::Relaxed));
+        let staleness = self.created_at.elapsed().saturating_sub(last_success);
+        if staleness > self.max_staleness {
+            return Err(err.add_message_back(format!(
+                " (cached metadata is {:?} old, exceeds the allowed staleness {:?})",
+                staleness, self.max_staleness
+            )));
+        }
+
+        match lookup() {
+            Some(v) => {
+                warn!(
+                    "Meta service is unavailable, serving metadata cached {:?} ago: {}",
+                    staleness,
+                    err.message()
+                );
+                Ok(v)
+            }
+            None => Err(err),
+        }
+    }
+}
@@ -33,6 +33,7 @@ use databend_common_storages_system::ClustersTable;
 use databend_common_storages_system::ColumnsTable;
 use databend_common_storages_system::ConfigsTable;
 use databend_common_storages_system::ContributorsTable;
+use databend_common_storages_system::CopyHistoryTable;
 use databend_common_storages_system::CreditsTable;
 use databend_common_storages_system::DatabasesTableWithHistory;
 use databend_common_storages_system::DatabasesTableWithoutHistory;
@@ -42,6 +43,7 @@ use databend_common_storages_system::FullStreamsTable;
 use databend_common_storages_system::FunctionsTable;
 use databend_common_storages_system::IndexesTable;
 use databend_common_storages_system::LocksTable;
+use databend_common_storages_system::LoginFailuresTable;
 #[cfg(feature = "jemalloc")]
 use databend_common_storages_system::MallocStatsTable;
 #[cfg(feature = "jemalloc")]
@@ -125,6 +127,10 @@ impl SystemDatabase {
                 sys_db_meta.next_table_id(),
                 config.query.max_query_log_size,
             )),
+            Arc::new(CopyHistoryTable::create(
+                sys_db_meta.next_table_id(),
+                config.query.max_query_log_size,
+            )),
             EnginesTable::create(sys_db_meta.next_table_id()),
             RolesTable::create(sys_db_meta.next_table_id()),
             StagesTable::create(sys_db_meta.next_table_id()),
@@ -144,6 +150,7 @@ impl SystemDatabase {
             LocksTable::create(sys_db_meta.next_table_id()),
             VirtualColumnsTable::create(sys_db_meta.next_table_id()),
             PasswordPoliciesTable::create(sys_db_meta.next_table_id()),
+            LoginFailuresTable::create(sys_db_meta.next_table_id()),
             UserFunctionsTable::create(sys_db_meta.next_table_id()),
             NotificationsTable::create(sys_db_meta.next_table_id()),
             NotificationHistoryTable::create(sys_db_meta.next_table_id()),
@@ -1192,6 +1192,9 @@ impl AccessChecker for PrivilegeAccess {
             }
             Plan::CopyIntoTable(plan) => {
                 self.validate_stage_access(&plan.stage_table_info.stage_info, UserPrivilegeType::Read).await?;
+                if plan.stage_table_info.stage_info.copy_options.rejected_rows_path.is_some() {
+                    self.validate_stage_access(&plan.stage_table_info.stage_info, UserPrivilegeType::Write).await?;
+                }
                 self.validate_table_access(plan.catalog_info.catalog_name(), &plan.database_name, &plan.table_name, UserPrivilegeType::Insert, false, false).await?;
                 if let Some(query) = &plan.query {
                     self.check(ctx, query).await?;
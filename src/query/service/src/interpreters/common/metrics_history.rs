@@ -0,0 +1,191 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use databend_common_ast::ast::QuotedString;
+use databend_common_base::runtime::metrics::MetricSample;
+use databend_common_base::runtime::metrics::MetricValue;
+use databend_common_base::runtime::metrics::GLOBAL_METRICS_REGISTRY;
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_config::InnerConfig;
+use databend_common_exception::Result;
+use databend_common_storages_system::MetricsTable;
+use log::info;
+use log::warn;
+
+use super::query_audit::create_audit_context;
+use super::query_audit::execute_sql;
+use super::query_audit::format_event_time;
+use super::query_audit::QUERY_AUDIT_DATABASE;
+
+pub const METRICS_HISTORY_TABLE: &str = "metrics";
+
+const MAX_ROWS_PER_INSERT: usize = 1000;
+
+/// One sampled value. Histograms and summaries are sampled as one row per bucket or
+/// quantile, with the bound in the `le` or `quantile` label, so that `value` is always
+/// a number.
+struct MetricsHistoryRow {
+    metric: String,
+    kind: String,
+    labels: String,
+    value: f64,
+}
+
+impl MetricsHistoryRow {
+    fn from_sample(sample: MetricSample) -> Result<Vec<Self>> {
+        let kind = sample.value.kind();
+        let row = |labels: &BTreeMap<String, String>, value: f64| -> Result<Self> {
+            Ok(MetricsHistoryRow {
+                metric: sample.name.clone(),
+                kind: kind.clone(),
+                labels: serde_json::to_string(labels)?,
+                value,
+            })
+        };
+        let mut labels = sample
+            .labels
+            .clone()
+            .into_iter()
+            .collect::<BTreeMap<_, _>>();
+        let rows = match &sample.value {
+            MetricValue::Counter(v) | MetricValue::Gauge(v) | MetricValue::Untyped(v) => {
+                vec![row(&labels, *v)?]
+            }
+            MetricValue::Histogram(buckets) => {
+                let mut rows = Vec::with_capacity(buckets.len());
+                for bucket in buckets {
+                    labels.insert("le".to_string(), format_bound(bucket.less_than));
+                    rows.push(row(&labels, bucket.count)?);
+                }
+                rows
+            }
+            MetricValue::Summary(quantiles) => {
+                let mut rows = Vec::with_capacity(quantiles.len());
+                for quantile in quantiles {
+                    labels.insert("quantile".to_string(), format_bound(quantile.quantile));
+                    rows.push(row(&labels, quantile.count)?);
+                }
+                rows
+            }
+        };
+        // a double column can not hold them and they are useless for the analysis anyway
+        Ok(rows.into_iter().filter(|r| r.value.is_finite()).collect())
+    }
+
+    fn values_sql(&self, event_time: &str, node: &str) -> String {
+        format!(
+            "({}, {}, {}, {}, {}, {})",
+            QuotedString(event_time, '\''),
+            QuotedString(node, '\''),
+            QuotedString(&self.metric, '\''),
+            QuotedString(&self.kind, '\''),
+            QuotedString(&self.labels, '\''),
+            self.value,
+        )
+    }
+}
+
+// same as the prometheus text format
+fn format_bound(v: f64) -> String {
+    if v == f64::INFINITY {
+        "+Inf".to_string()
+    } else {
+        v.to_string()
+    }
+}
+
+fn metrics_history_create_table_sql() -> String {
+    format!(
+        "CREATE TABLE IF NOT EXISTS {QUERY_AUDIT_DATABASE}.{METRICS_HISTORY_TABLE} (\
+            event_time TIMESTAMP, \
+            node VARCHAR, \
+            metric VARCHAR, \
+            kind VARCHAR, \
+            labels VARCHAR, \
+            value DOUBLE\
+        )"
+    )
+}
+
+/// Samples the metrics of this node every `metrics_history_interval_secs` into
+/// `system_history.metrics` of the tenant of the node, the same values `system.metrics`
+/// shows at that moment.
+pub struct MetricsHistory;
+
+impl MetricsHistory {
+    pub fn start(conf: &InnerConfig) {
+        let interval = conf.query.metrics_history_interval_secs;
+        if interval == 0 {
+            return;
+        }
+        let tenant = conf.query.tenant_id.tenant_name().to_string();
+        let node = conf.query.node_id.clone();
+        info!("sample metrics into system_history every {}s", interval);
+        GlobalIORuntime::instance().spawn(async move {
+            loop {
+                tokio::time::sleep(Duration::from_secs(interval)).await;
+                if let Err(e) = Self::write(&tenant, &node).await {
+                    warn!("failed to write metrics history: {:?}", e);
+                }
+            }
+        });
+    }
+
+    /// Sample the metrics now and write them, returns the number of rows written.
+    pub async fn write(tenant: &str, node: &str) -> Result<usize> {
+        let event_time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_micros() as i64;
+        let event_time = format_event_time(event_time);
+
+        let mut samples = GLOBAL_METRICS_REGISTRY.dump_sample()?;
+        samples.extend(MetricsTable::custom_metric_samples()?);
+        let mut rows = Vec::with_capacity(samples.len());
+        for sample in samples {
+            rows.extend(MetricsHistoryRow::from_sample(sample)?);
+        }
+        if rows.is_empty() {
+            return Ok(0);
+        }
+
+        let ctx = create_audit_context(tenant).await?;
+        execute_sql(
+            &ctx,
+            &format!("CREATE DATABASE IF NOT EXISTS {QUERY_AUDIT_DATABASE}"),
+        )
+        .await?;
+        execute_sql(&ctx, &metrics_history_create_table_sql()).await?;
+        for chunk in rows.chunks(MAX_ROWS_PER_INSERT) {
+            let values = chunk
+                .iter()
+                .map(|row| row.values_sql(&event_time, node))
+                .collect::<Vec<_>>()
+                .join(", ");
+            execute_sql(
+                &ctx,
+                &format!(
+                    "INSERT INTO {QUERY_AUDIT_DATABASE}.{METRICS_HISTORY_TABLE} VALUES {values}"
+                ),
+            )
+            .await?;
+        }
+        Ok(rows.len())
+    }
+}
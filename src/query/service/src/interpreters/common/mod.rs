@@ -14,21 +14,29 @@
 
 mod grant;
 mod metrics;
+mod metrics_history;
 mod notification;
+mod query_audit;
 mod query_log;
 mod stream;
 mod table;
+mod table_write_limit;
 mod task;
 mod util;
 
 pub mod table_option_validation;
 
 pub use grant::validate_grant_object_exists;
+pub use metrics_history::MetricsHistory;
 pub use notification::get_notification_client_config;
+pub use query_audit::QueryAudit;
+pub use query_audit::QueryAuditRecord;
 pub use query_log::InterpreterQueryLog;
 pub use stream::dml_build_update_stream_req;
 pub use stream::query_build_update_stream_req;
 pub use table::check_referenced_computed_columns;
+pub use table_write_limit::acquire_table_write_permit;
+pub use table_write_limit::attach_table_write_permit;
 pub use task::get_task_client_config;
 pub use task::make_schedule_options;
 pub use task::make_warehouse_options;
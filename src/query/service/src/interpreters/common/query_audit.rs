@@ -0,0 +1,327 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::VecDeque;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::LazyLock;
+use std::time::Duration;
+
+use chrono::DateTime;
+use databend_common_ast::ast::QuotedString;
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::GrantObject;
+use databend_common_meta_app::principal::UserInfo;
+use databend_common_meta_app::principal::UserPrivilegeSet;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_sql::Planner;
+use databend_common_storages_system::QueryLogElement;
+use fastrace::func_name;
+use futures_util::TryStreamExt;
+use log::info;
+use log::warn;
+use parking_lot::Mutex;
+use serde::Serialize;
+
+use crate::interpreters::InterpreterFactory;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+use crate::sessions::TableContext;
+
+pub const QUERY_AUDIT_DATABASE: &str = "system_history";
+pub const QUERY_AUDIT_TABLE: &str = "query_audit";
+
+const FLUSH_INTERVAL: Duration = Duration::from_secs(5);
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+// records beyond this are dropped (oldest first) if the flush can not keep up
+const MAX_PENDING_RECORDS: usize = 100_000;
+const MAX_ROWS_PER_INSERT: usize = 1000;
+
+/// One executed statement, written to `system_history.query_audit` of its tenant
+/// and, if `query_audit_webhook_url` is set, posted to the webhook as a JSON line.
+#[derive(Clone, Debug, Serialize)]
+pub struct QueryAuditRecord {
+    #[serde(skip)]
+    pub tenant: String,
+    #[serde(skip)]
+    pub webhook_url: String,
+
+    /// Microseconds since the unix epoch.
+    pub event_time: i64,
+    pub query_id: String,
+    pub user_name: String,
+    pub client_address: String,
+    pub current_database: String,
+    pub query_kind: String,
+    /// Redacted if `enable_query_text_redaction` is set.
+    pub query_text: String,
+    pub status: String,
+    pub exception_code: i32,
+    pub exception_text: String,
+    pub written_rows: u64,
+    pub result_rows: u64,
+    pub query_duration_ms: i64,
+}
+
+impl QueryAuditRecord {
+    fn values_sql(&self) -> String {
+        format!(
+            "({}, {}, {}, {}, {}, {}, {}, {}, {}, {}, {}, {}, {})",
+            QuotedString(format_event_time(self.event_time), '\''),
+            QuotedString(&self.query_id, '\''),
+            QuotedString(&self.user_name, '\''),
+            QuotedString(&self.client_address, '\''),
+            QuotedString(&self.current_database, '\''),
+            QuotedString(&self.query_kind, '\''),
+            QuotedString(&self.query_text, '\''),
+            QuotedString(&self.status, '\''),
+            self.exception_code,
+            QuotedString(&self.exception_text, '\''),
+            self.written_rows,
+            self.result_rows,
+            self.query_duration_ms,
+        )
+    }
+}
+
+pub(super) fn format_event_time(micros: i64) -> String {
+    DateTime::from_timestamp_micros(micros)
+        .unwrap_or_default()
+        .naive_utc()
+        .format("%Y-%m-%d %H:%M:%S%.6f")
+        .to_string()
+}
+
+fn query_audit_create_database_sql() -> String {
+    format!("CREATE DATABASE IF NOT EXISTS {QUERY_AUDIT_DATABASE}")
+}
+
+fn query_audit_create_table_sql() -> String {
+    format!(
+        "CREATE TABLE IF NOT EXISTS {QUERY_AUDIT_DATABASE}.{QUERY_AUDIT_TABLE} (\
+            event_time TIMESTAMP, \
+            query_id VARCHAR, \
+            user_name VARCHAR, \
+            client_address VARCHAR, \
+            current_database VARCHAR, \
+            query_kind VARCHAR, \
+            query_text VARCHAR, \
+            status VARCHAR, \
+            exception_code INT, \
+            exception_text VARCHAR, \
+            written_rows UINT64, \
+            result_rows UINT64, \
+            query_duration_ms INT64\
+        )"
+    )
+}
+
+fn query_audit_insert_sql(records: &[QueryAuditRecord]) -> String {
+    let values = records
+        .iter()
+        .map(|record| record.values_sql())
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("INSERT INTO {QUERY_AUDIT_DATABASE}.{QUERY_AUDIT_TABLE} VALUES {values}")
+}
+
+static PENDING: LazyLock<Mutex<VecDeque<QueryAuditRecord>>> =
+    LazyLock::new(|| Mutex::new(VecDeque::new()));
+
+static FLUSHER_STARTED: AtomicBool = AtomicBool::new(false);
+
+/// Collects the audit records of finished statements on this node, a background task
+/// flushes them every few seconds.
+///
+/// The statements executed to write the records run in an internal session, which is
+/// not audited itself.
+pub struct QueryAudit;
+
+impl QueryAudit {
+    /// Record the finished statement if `enable_query_audit` is set for the tenant.
+    pub fn try_append(ctx: &QueryContext, event: &QueryLogElement) -> Result<()> {
+        if !ctx.get_current_session().get_type().is_user_session() {
+            return Ok(());
+        }
+        let settings = ctx.get_settings();
+        if !settings.get_enable_query_audit()? {
+            return Ok(());
+        }
+
+        Self::append(QueryAuditRecord {
+            tenant: ctx.get_tenant().tenant_name().to_string(),
+            webhook_url: settings.get_query_audit_webhook_url()?,
+            event_time: event.event_time,
+            query_id: event.query_id.clone(),
+            user_name: event.sql_user.clone(),
+            client_address: event.client_address.clone(),
+            current_database: event.current_database.clone(),
+            query_kind: event.query_kind.clone(),
+            query_text: event.query_text.clone(),
+            status: event.log_type_name.clone(),
+            exception_code: event.exception_code,
+            exception_text: event.exception_text.clone(),
+            written_rows: event.written_rows,
+            result_rows: event.result_rows,
+            query_duration_ms: event.query_duration_ms,
+        });
+        Ok(())
+    }
+
+    pub fn append(record: QueryAuditRecord) {
+        {
+            let mut pending = PENDING.lock();
+            if pending.len() >= MAX_PENDING_RECORDS {
+                pending.pop_front();
+                warn!("too many pending query audit records, the oldest one is dropped");
+            }
+            pending.push_back(record);
+        }
+
+        if !FLUSHER_STARTED.swap(true, Ordering::SeqCst) {
+            GlobalIORuntime::instance().spawn(async move {
+                loop {
+                    tokio::time::sleep(FLUSH_INTERVAL).await;
+                    if let Err(e) = Self::flush().await {
+                        warn!("failed to flush query audit records: {:?}", e);
+                    }
+                }
+            });
+        }
+    }
+
+    /// Write the pending records to the audit tables and the webhooks.
+    ///
+    /// Records that fail to be written to the table are dropped, so that a broken tenant
+    /// does not block the others.
+    pub async fn flush() -> Result<()> {
+        let records = PENDING.lock().drain(..).collect::<Vec<_>>();
+        if records.is_empty() {
+            return Ok(());
+        }
+
+        let mut by_tenant: BTreeMap<&str, Vec<QueryAuditRecord>> = BTreeMap::new();
+        let mut by_webhook: BTreeMap<&str, Vec<&QueryAuditRecord>> = BTreeMap::new();
+        for record in &records {
+            by_tenant
+                .entry(&record.tenant)
+                .or_default()
+                .push(record.clone());
+            if !record.webhook_url.is_empty() {
+                by_webhook
+                    .entry(&record.webhook_url)
+                    .or_default()
+                    .push(record);
+            }
+        }
+
+        let mut last_error = None;
+        for (tenant, records) in by_tenant {
+            if let Err(e) = write_table(tenant, &records).await {
+                warn!(
+                    "failed to write {} query audit records of tenant {}: {:?}",
+                    records.len(),
+                    tenant,
+                    e
+                );
+                last_error = Some(e);
+            }
+        }
+        for (url, records) in by_webhook {
+            if let Err(e) = post_webhook(url, &records).await {
+                warn!(
+                    "failed to post {} query audit records to webhook: {:?}",
+                    records.len(),
+                    e
+                );
+                last_error = Some(e);
+            }
+        }
+
+        match last_error {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+}
+
+async fn write_table(tenant: &str, records: &[QueryAuditRecord]) -> Result<()> {
+    let ctx = create_audit_context(tenant).await?;
+    execute_sql(&ctx, &query_audit_create_database_sql()).await?;
+    execute_sql(&ctx, &query_audit_create_table_sql()).await?;
+    for chunk in records.chunks(MAX_ROWS_PER_INSERT) {
+        execute_sql(&ctx, &query_audit_insert_sql(chunk)).await?;
+    }
+    info!(
+        "wrote {} query audit records of tenant {}",
+        records.len(),
+        tenant
+    );
+    Ok(())
+}
+
+async fn post_webhook(url: &str, records: &[&QueryAuditRecord]) -> Result<()> {
+    let mut body = String::new();
+    for record in records {
+        body.push_str(&serde_json::to_string(record)?);
+        body.push('\n');
+    }
+
+    let response = reqwest::Client::new()
+        .post(url)
+        .header("Content-Type", "application/x-ndjson")
+        .timeout(WEBHOOK_TIMEOUT)
+        .body(body)
+        .send()
+        .await
+        .map_err(|e| ErrorCode::Internal(format!("fail to post query audit records: {e}")))?;
+    if !response.status().is_success() {
+        return Err(ErrorCode::Internal(format!(
+            "fail to post query audit records, webhook responded {}",
+            response.status()
+        )));
+    }
+    Ok(())
+}
+
+/// The records are written with the privileges of the internal root user of the tenant.
+pub(super) async fn create_audit_context(tenant: &str) -> Result<Arc<QueryContext>> {
+    let session_manager = SessionManager::instance();
+    let mut session = session_manager.create_session(SessionType::Dummy).await?;
+    session.set_current_tenant(Tenant::new_or_err(tenant, func_name!())?);
+    let session = session_manager.register_session(session)?;
+
+    let mut user = UserInfo::new_no_auth("root", "%");
+    user.grants.grant_privileges(
+        &GrantObject::Global,
+        UserPrivilegeSet::available_privileges_on_global(),
+    );
+    session.set_authed_user(user, None).await?;
+    session.create_query_context().await
+}
+
+pub(super) async fn execute_sql(ctx: &Arc<QueryContext>, sql: &str) -> Result<()> {
+    let ctx = ctx.get_current_session().create_query_context().await?;
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(sql).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    let stream = interpreter.execute(ctx).await?;
+    stream.try_collect::<Vec<_>>().await?;
+    Ok(())
+}
@@ -27,6 +27,7 @@ use log::error;
 use log::info;
 use serde_json;
 
+use crate::interpreters::common::query_audit::QueryAudit;
 use crate::sessions::convert_query_log_timestamp;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
@@ -323,7 +324,7 @@ impl InterpreterQueryLog {
         let txn_id = guard.txn_id().to_string();
         drop(guard);
 
-        Self::write_log(QueryLogElement {
+        let event = QueryLogElement {
             log_type,
             log_type_name,
             handler_type,
@@ -385,6 +386,11 @@ impl InterpreterQueryLog {
             has_profiles,
             txn_state,
             txn_id,
-        })
+        };
+
+        if let Err(e) = QueryAudit::try_append(ctx, &event) {
+            error!("failed to record query audit: {:?}", e);
+        }
+        Self::write_log(event)
     }
 }
@@ -17,15 +17,20 @@ use std::collections::HashSet;
 use std::sync::LazyLock;
 
 use chrono::Duration;
+use databend_common_base::base::tokio::sync::Semaphore;
 use databend_common_exception::ErrorCode;
 use databend_common_expression::TableSchemaRef;
 use databend_common_io::constants::DEFAULT_BLOCK_MAX_ROWS;
 use databend_common_io::constants::DEFAULT_MIN_TABLE_LEVEL_DATA_RETENTION_PERIOD_IN_HOURS;
 use databend_common_settings::Settings;
 use databend_common_sql::BloomIndexColumns;
+use databend_common_storages_fuse::FUSE_OPT_KEY_AUTO_COMPACTION_BLOCK_COUNT;
 use databend_common_storages_fuse::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
 use databend_common_storages_fuse::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
 use databend_common_storages_fuse::FUSE_OPT_KEY_DATA_RETENTION_PERIOD_IN_HOURS;
+use databend_common_storages_fuse::FUSE_OPT_KEY_ENABLE_AUTO_COMPACTION;
+use databend_common_storages_fuse::FUSE_OPT_KEY_ENABLE_NGRAM_BLOOM_INDEX;
+use databend_common_storages_fuse::FUSE_OPT_KEY_MAX_CONCURRENT_WRITES;
 use databend_common_storages_fuse::FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD;
 use databend_common_storages_fuse::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use databend_common_storages_fuse::FUSE_OPT_KEY_ROW_PER_PAGE;
@@ -53,6 +58,10 @@ pub static CREATE_TABLE_OPTIONS: LazyLock<HashSet<&'static str>> = LazyLock::new
     r.insert(FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD);
     r.insert(FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD);
     r.insert(FUSE_OPT_KEY_DATA_RETENTION_PERIOD_IN_HOURS);
+    r.insert(FUSE_OPT_KEY_MAX_CONCURRENT_WRITES);
+    r.insert(FUSE_OPT_KEY_ENABLE_AUTO_COMPACTION);
+    r.insert(FUSE_OPT_KEY_AUTO_COMPACTION_BLOCK_COUNT);
+    r.insert(FUSE_OPT_KEY_ENABLE_NGRAM_BLOOM_INDEX);
 
     r.insert(OPT_KEY_BLOOM_INDEX_COLUMNS);
     r.insert(OPT_KEY_TABLE_COMPRESSION);
@@ -82,6 +91,10 @@ pub static UNSET_TABLE_OPTIONS_WHITE_LIST: LazyLock<HashSet<&'static str>> = Laz
     r.insert(FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD);
     r.insert(FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD);
     r.insert(FUSE_OPT_KEY_DATA_RETENTION_PERIOD_IN_HOURS);
+    r.insert(FUSE_OPT_KEY_MAX_CONCURRENT_WRITES);
+    r.insert(FUSE_OPT_KEY_ENABLE_AUTO_COMPACTION);
+    r.insert(FUSE_OPT_KEY_AUTO_COMPACTION_BLOCK_COUNT);
+    r.insert(FUSE_OPT_KEY_ENABLE_NGRAM_BLOOM_INDEX);
     r
 });
 
@@ -121,6 +134,73 @@ pub fn is_valid_row_per_block(
     Ok(())
 }
 
+pub fn is_valid_max_concurrent_writes(
+    options: &BTreeMap<String, String>,
+) -> databend_common_exception::Result<()> {
+    if let Some(value) = options.get(FUSE_OPT_KEY_MAX_CONCURRENT_WRITES) {
+        let limit = value.parse::<u64>().map_err(|_| {
+            ErrorCode::TableOptionInvalid(format!(
+                "Invalid max_concurrent_writes {:?}, it should be a non-negative integer",
+                value
+            ))
+        })?;
+        if limit > Semaphore::MAX_PERMITS as u64 {
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "Invalid max_concurrent_writes {}, it should not be greater than {}",
+                limit,
+                Semaphore::MAX_PERMITS
+            )));
+        }
+    }
+    Ok(())
+}
+
+pub fn is_valid_auto_compaction_options(
+    options: &BTreeMap<String, String>,
+) -> databend_common_exception::Result<()> {
+    if let Some(value) = options.get(FUSE_OPT_KEY_ENABLE_AUTO_COMPACTION) {
+        value.to_lowercase().parse::<bool>().map_err(|_| {
+            ErrorCode::TableOptionInvalid(format!(
+                "Invalid enable_auto_compaction {:?}, it should be true or false",
+                value
+            ))
+        })?;
+    }
+    if let Some(value) = options.get(FUSE_OPT_KEY_AUTO_COMPACTION_BLOCK_COUNT) {
+        value.parse::<u64>().map_err(|_| {
+            ErrorCode::TableOptionInvalid(format!(
+                "Invalid auto_compaction_block_count {:?}, it should be a non-negative integer",
+                value
+            ))
+        })?;
+    }
+    Ok(())
+}
+
+pub fn is_valid_ngram_bloom_index(
+    options: &BTreeMap<String, String>,
+) -> databend_common_exception::Result<()> {
+    if let Some(value) = options.get(FUSE_OPT_KEY_ENABLE_NGRAM_BLOOM_INDEX) {
+        value.to_lowercase().parse::<bool>().map_err(|_| {
+            ErrorCode::TableOptionInvalid(format!(
+                "Invalid enable_ngram_bloom_index {:?}, it should be true or false",
+                value
+            ))
+        })?;
+    }
+    Ok(())
+}
+
+/// Formats a retention period stored in hours, in days as well if it is a whole number of days,
+/// since it may be set by `DATA_RETENTION_TIME_IN_DAYS`.
+pub fn display_data_retention_period(hours: u64) -> String {
+    if hours > 0 && hours % 24 == 0 {
+        format!("{} days ({} hours)", hours / 24, hours)
+    } else {
+        format!("{} hours", hours)
+    }
+}
+
 pub fn is_valid_data_retention_period(
     options: &BTreeMap<String, String>,
 ) -> databend_common_exception::Result<()> {
@@ -129,8 +209,11 @@ pub fn is_valid_data_retention_period(
 
         if new_duration_in_hours < DEFAULT_MIN_TABLE_LEVEL_DATA_RETENTION_PERIOD_IN_HOURS {
             return Err(ErrorCode::TableOptionInvalid(format!(
-                "Invalid data_retention_period_in_hours {:?}, it should not be lesser than {:?}",
-                new_duration_in_hours, DEFAULT_MIN_TABLE_LEVEL_DATA_RETENTION_PERIOD_IN_HOURS
+                "Invalid data retention period {}, it should not be lesser than {}",
+                display_data_retention_period(new_duration_in_hours),
+                display_data_retention_period(
+                    DEFAULT_MIN_TABLE_LEVEL_DATA_RETENTION_PERIOD_IN_HOURS
+                )
             )));
         }
 
@@ -141,8 +224,9 @@ pub fn is_valid_data_retention_period(
 
         if new_duration > default_max_duration {
             return Err(ErrorCode::TableOptionInvalid(format!(
-                "Invalid data_retention_period_in_hours {:?}, it should not be larger than {:?}",
-                new_duration, default_max_duration
+                "Invalid data retention period {}, it should not be larger than {} days",
+                display_data_retention_period(new_duration_in_hours),
+                default_max_period_in_days
             )));
         }
     }
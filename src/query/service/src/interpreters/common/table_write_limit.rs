@@ -0,0 +1,120 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::LazyLock;
+use std::time::Duration;
+use std::time::Instant;
+
+use databend_common_base::base::tokio::sync::OwnedSemaphorePermit;
+use databend_common_base::base::tokio::sync::Semaphore;
+use databend_common_base::base::tokio::time::timeout;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_exception::ResultExt;
+use databend_common_pipeline_core::always_callback;
+use databend_common_pipeline_core::ExecutionInfo;
+use databend_common_pipeline_core::Pipeline;
+use databend_common_storages_fuse::FUSE_OPT_KEY_MAX_CONCURRENT_WRITES;
+use log::info;
+use parking_lot::Mutex;
+
+use crate::sessions::QueryContext;
+
+/// How often a job waiting for a write permit checks whether its query was killed.
+const ABORT_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Table id -> (limit, semaphore) of the tables with `max_concurrent_writes` set.
+/// Only the tables with running or waiting jobs are kept.
+static TABLE_WRITE_PERMITS: LazyLock<Mutex<HashMap<u64, (usize, Arc<Semaphore>)>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Wait for a write permit of the table if it has `max_concurrent_writes` option,
+/// the limit is enforced for the INSERT/COPY jobs running on the current node.
+///
+/// The wait gives up when the query is killed, or after `statement_queued_timeout_in_seconds`
+/// if it is set. Only the number of concurrent jobs is limited, not the ingest bytes per second.
+pub async fn acquire_table_write_permit(
+    ctx: &QueryContext,
+    table: &dyn Table,
+) -> Result<Option<OwnedSemaphorePermit>> {
+    let limit = match table.options().get(FUSE_OPT_KEY_MAX_CONCURRENT_WRITES) {
+        Some(value) => value.parse::<usize>()?.min(Semaphore::MAX_PERMITS),
+        None => return Ok(None),
+    };
+    if limit == 0 {
+        return Ok(None);
+    }
+
+    let table_id = table.get_id();
+    let semaphore = {
+        let mut permits = TABLE_WRITE_PERMITS.lock();
+        // Nobody holds or waits for the semaphores only referenced by the map,
+        // drop them so that dropped or unlimited tables do not stay forever.
+        permits.retain(|_, (_, semaphore)| Arc::strong_count(semaphore) > 1);
+        match permits.get(&table_id) {
+            Some((old_limit, semaphore)) if *old_limit == limit => semaphore.clone(),
+            _ => {
+                // The option is altered, the running jobs keep the permits of the old semaphore.
+                let semaphore = Arc::new(Semaphore::new(limit));
+                permits.insert(table_id, (limit, semaphore.clone()));
+                semaphore
+            }
+        }
+    };
+
+    if semaphore.available_permits() == 0 {
+        info!(
+            "Waiting for write permit of table {}, max_concurrent_writes: {}",
+            table.name(),
+            limit
+        );
+    }
+    let max_wait = match ctx.get_settings().get_statement_queued_timeout()? {
+        0 => None,
+        secs => Some(Duration::from_secs(secs)),
+    };
+    let start = Instant::now();
+    let mut acquire = Box::pin(semaphore.acquire_owned());
+    loop {
+        if let Ok(permit) = timeout(ABORT_CHECK_INTERVAL, &mut acquire).await {
+            let permit = permit.map_err(|e| {
+                ErrorCode::Internal(format!("Failed to acquire table write permit: {}", e))
+            })?;
+            return Ok(Some(permit));
+        }
+        ctx.check_aborting()
+            .with_context(|| "waiting for table write permit")?;
+        if max_wait.is_some_and(|max_wait| start.elapsed() >= max_wait) {
+            return Err(ErrorCode::Timeout(format!(
+                "Timeout waiting for write permit of table {}, max_concurrent_writes: {}",
+                table.name(),
+                limit
+            )));
+        }
+    }
+}
+
+/// Hold the permit until the pipeline is finished.
+pub fn attach_table_write_permit(pipeline: &mut Pipeline, permit: Option<OwnedSemaphorePermit>) {
+    if let Some(permit) = permit {
+        pipeline.set_on_finished(always_callback(move |_info: &ExecutionInfo| {
+            drop(permit);
+            Ok(())
+        }));
+    }
+}
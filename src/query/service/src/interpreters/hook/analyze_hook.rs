@@ -0,0 +1,270 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::sync::LazyLock;
+
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::Thread;
+use databend_common_catalog::table::NavigationPoint;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::UserInfo;
+use databend_common_pipeline_core::ExecutionInfo;
+use databend_common_pipeline_core::Pipeline;
+use databend_common_sql::plans::AnalyzeTablePlan;
+use databend_common_storages_fuse::FuseTable;
+use log::info;
+use parking_lot::Mutex;
+
+use crate::interpreters::AnalyzeTableInterpreter;
+use crate::interpreters::Interpreter;
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelineCompleteExecutor;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+
+type TableKey = (String, String, String);
+
+/// Rows inserted, deleted or updated on this node since the last auto analyze of the table.
+static CHANGED_ROWS: LazyLock<Mutex<HashMap<TableKey, u64>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Tables with a running background analyze job, at most one job per table.
+static RUNNING_ANALYZE: LazyLock<Mutex<HashSet<TableKey>>> =
+    LazyLock::new(|| Mutex::new(HashSet::new()));
+
+/// Removes the table from `RUNNING_ANALYZE` when the job ends, even if it panics.
+struct RunningAnalyzeGuard(TableKey);
+
+impl RunningAnalyzeGuard {
+    fn try_acquire(key: TableKey) -> Option<Self> {
+        RUNNING_ANALYZE
+            .lock()
+            .insert(key.clone())
+            .then_some(RunningAnalyzeGuard(key))
+    }
+}
+
+impl Drop for RunningAnalyzeGuard {
+    fn drop(&mut self) {
+        RUNNING_ANALYZE.lock().remove(&self.0);
+    }
+}
+
+pub struct AnalyzeDesc {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+}
+
+impl AnalyzeDesc {
+    fn key(&self) -> TableKey {
+        (
+            self.catalog.clone(),
+            self.database.clone(),
+            self.table.clone(),
+        )
+    }
+}
+
+/// Hook analyze action with a on-finished callback.
+///
+/// The callback only records the number of changed rows, the staleness check and
+/// ANALYZE run in a background job, so that the write does not wait for them.
+/// errors (if any) are ignored.
+pub async fn hook_analyze(ctx: Arc<QueryContext>, pipeline: &mut Pipeline, desc: AnalyzeDesc) {
+    if pipeline.is_empty() {
+        return;
+    }
+
+    pipeline.set_on_finished(move |info: &ExecutionInfo| {
+        if info.res.is_ok() {
+            let changed_rows = statement_changed_rows(&ctx);
+            *CHANGED_ROWS.lock().entry(desc.key()).or_default() += changed_rows;
+
+            if let Err(e) = spawn_analyze(&ctx, desc) {
+                info!("failed to start background analyze job. {:?}", e);
+            }
+        }
+        Ok(())
+    });
+}
+
+/// UPDATE/DELETE/MERGE report the affected rows in the mutation status,
+/// INSERT/COPY/REPLACE only have the written rows.
+fn statement_changed_rows(ctx: &QueryContext) -> u64 {
+    let status = ctx.get_mutation_status();
+    let status = status.read();
+    let mutated = status.insert_rows + status.deleted_rows + status.update_rows;
+    if mutated > 0 {
+        mutated
+    } else {
+        ctx.get_write_progress_value().rows as u64
+    }
+}
+
+/// With `enable_auto_analyze_sync`, the job runs before the statement returns.
+fn spawn_analyze(ctx: &QueryContext, desc: AnalyzeDesc) -> Result<()> {
+    let Some(guard) = RunningAnalyzeGuard::try_acquire(desc.key()) else {
+        return Ok(());
+    };
+
+    let user = ctx.get_current_user()?;
+    let role = ctx.get_current_role().map(|role| role.name);
+    let settings = ctx.get_settings();
+    let ratio = settings.get_auto_analyze_ratio()?;
+    let sync = settings.get_enable_auto_analyze_sync()?;
+    let job = move || {
+        let _guard = guard;
+        info!(
+            "starting background analyze job of table {}.{}",
+            desc.database, desc.table
+        );
+        match GlobalIORuntime::instance().block_on(background_analyze(user, role, ratio, desc)) {
+            Ok(_) => {
+                info!("execute analyze job successfully.");
+            }
+            Err(e) => {
+                info!("execute analyze job failed. {:?}", e);
+            }
+        }
+    };
+    if sync {
+        job();
+    } else {
+        Thread::named_spawn(Some("auto-analyze".to_string()), job);
+    }
+    Ok(())
+}
+
+/// The job runs in its own session, the query that triggered it may already be gone.
+async fn background_analyze(
+    user: UserInfo,
+    role: Option<String>,
+    ratio: u64,
+    desc: AnalyzeDesc,
+) -> Result<()> {
+    let session_manager = SessionManager::instance();
+    let session = session_manager.create_session(SessionType::Dummy).await?;
+    let session = session_manager.register_session(session)?;
+    session.set_authed_user(user, role).await?;
+    let ctx = session.create_query_context().await?;
+    do_analyze(ctx, ratio, desc).await
+}
+
+async fn do_analyze(ctx: Arc<QueryContext>, ratio: u64, desc: AnalyzeDesc) -> Result<()> {
+    let table = ctx
+        .get_table(&desc.catalog, &desc.database, &desc.table)
+        .await?;
+    let Ok(fuse_table) = FuseTable::try_from_table(table.as_ref()) else {
+        return Ok(());
+    };
+    let Some(snapshot) = fuse_table.read_table_snapshot().await? else {
+        return Ok(());
+    };
+
+    let analyzed_rows = match fuse_table
+        .read_table_snapshot_statistics(Some(&snapshot))
+        .await?
+    {
+        Some(stats) if stats.snapshot_id == snapshot.snapshot_id => {
+            CHANGED_ROWS.lock().remove(&desc.key());
+            return Ok(());
+        }
+        Some(stats) => {
+            let point = NavigationPoint::SnapshotID(stats.snapshot_id.simple().to_string());
+            match fuse_table
+                .navigate_to_point(&point, ctx.clone().get_abort_checker())
+                .await
+            {
+                Ok(t) => t
+                    .read_table_snapshot()
+                    .await?
+                    .map_or(0, |s| s.summary.row_count),
+                // The analyzed snapshot may have been purged, treat it as never analyzed.
+                Err(_) => 0,
+            }
+        }
+        None => 0,
+    };
+
+    // Rows changed on other nodes are only seen through the net change of the row count.
+    let recorded_rows = CHANGED_ROWS
+        .lock()
+        .get(&desc.key())
+        .copied()
+        .unwrap_or_default();
+    let changed_rows = recorded_rows.max(snapshot.summary.row_count.abs_diff(analyzed_rows));
+    if !is_statistics_stale(
+        analyzed_rows,
+        snapshot.summary.row_count,
+        changed_rows,
+        ratio,
+    ) {
+        return Ok(());
+    }
+    info!(
+        "statistics of table {}.{} are stale, rows at last analyze: {}, current rows: {}, changed rows: {}",
+        desc.database, desc.table, analyzed_rows, snapshot.summary.row_count, changed_rows
+    );
+    let key = desc.key();
+
+    let plan = AnalyzeTablePlan {
+        catalog: desc.catalog,
+        database: desc.database,
+        table: desc.table,
+    };
+    let analyze_interpreter = AnalyzeTableInterpreter::try_create(ctx.clone(), plan)?;
+    let mut build_res = analyze_interpreter.execute2().await?;
+    if build_res.main_pipeline.is_empty() {
+        return Ok(());
+    }
+
+    let settings = ctx.get_settings();
+    build_res.set_max_threads(settings.get_max_threads()? as usize);
+    let settings = ExecutorSettings::try_create(ctx.clone())?;
+
+    if build_res.main_pipeline.is_complete_pipeline()? {
+        let mut pipelines = build_res.sources_pipelines;
+        pipelines.push(build_res.main_pipeline);
+
+        let complete_executor = PipelineCompleteExecutor::from_pipelines(pipelines, settings)?;
+        ctx.set_executor(complete_executor.get_inner())?;
+        complete_executor.execute()?;
+    }
+
+    // Keep the rows changed while analyzing for the next check.
+    if let Some(rows) = CHANGED_ROWS.lock().get_mut(&key) {
+        *rows = rows.saturating_sub(recorded_rows);
+    }
+    Ok(())
+}
+
+/// The statistics are stale if at least `ratio` percent of the analyzed rows were
+/// inserted, deleted or updated since the last analyze, or the table has never been analyzed.
+fn is_statistics_stale(
+    analyzed_rows: u64,
+    current_rows: u64,
+    changed_rows: u64,
+    ratio: u64,
+) -> bool {
+    if analyzed_rows == 0 {
+        return current_rows > 0;
+    }
+    changed_rows as u128 * 100 >= analyzed_rows as u128 * ratio as u128
+}
@@ -22,6 +22,8 @@ use databend_common_sql::executor::physical_plans::MutationKind;
 use log::info;
 use log::warn;
 
+use crate::interpreters::hook::analyze_hook::hook_analyze;
+use crate::interpreters::hook::analyze_hook::AnalyzeDesc;
 use crate::interpreters::hook::compact_hook::hook_compact;
 use crate::interpreters::hook::compact_hook::CompactHookTraceCtx;
 use crate::interpreters::hook::compact_hook::CompactTargetTableDescription;
@@ -63,11 +65,13 @@ impl HookOperator {
     /// 1. Compact if needed.
     /// 2. Refresh aggregating index if needed.
     /// 3. Refresh virtual columns if needed.
+    /// 4. Analyze if the table statistics are stale.
     #[fastrace::trace]
     #[async_backtrace::framed]
     pub async fn execute(&self, pipeline: &mut Pipeline) {
         self.execute_compact(pipeline).await;
         self.execute_refresh(pipeline).await;
+        self.execute_analyze(pipeline).await;
     }
 
     /// Execute the compact hook operator.
@@ -125,4 +129,29 @@ impl HookOperator {
 
         hook_refresh(self.ctx.clone(), pipeline, refresh_desc).await;
     }
+
+    /// Execute the analyze hook operator.
+    #[fastrace::trace]
+    #[async_backtrace::framed]
+    pub async fn execute_analyze(&self, pipeline: &mut Pipeline) {
+        match self.ctx.get_settings().get_enable_auto_analyze() {
+            Ok(false) => {
+                return;
+            }
+            Err(e) => {
+                // swallow the exception, analyze hook should not prevent the main operation.
+                warn!("failed to get auto analyze settings, ignored. {}", e);
+                return;
+            }
+            Ok(true) => {}
+        }
+
+        let analyze_desc = AnalyzeDesc {
+            catalog: self.catalog.to_owned(),
+            database: self.database.to_owned(),
+            table: self.table.to_owned(),
+        };
+
+        hook_analyze(self.ctx.clone(), pipeline, analyze_desc).await;
+    }
 }
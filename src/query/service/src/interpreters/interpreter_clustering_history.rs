@@ -30,6 +30,8 @@ impl InterpreterClusteringHistory {
         start: SystemTime,
         db_name: &str,
         table_name: &str,
+        average_overlaps: Option<f64>,
+        average_depth: Option<f64>,
     ) -> Result<()> {
         ClusteringHistoryQueue::instance()?.append_data(ClusteringHistoryLogElement {
             start_time: start
@@ -44,6 +46,8 @@ impl InterpreterClusteringHistory {
             table: table_name.to_string(),
             byte_size: ctx.get_scan_progress_value().bytes as u64,
             row_count: ctx.get_scan_progress_value().rows as u64,
+            average_overlaps,
+            average_depth,
         })
     }
 }
@@ -88,6 +88,7 @@ impl CopyIntoLocationInterpreter {
         path: &str,
         query: &Plan,
         options: &CopyIntoLocationOptions,
+        partitioned: bool,
     ) -> Result<(PipelineBuildResult, Vec<UpdateStreamMetaReq>)> {
         let (query_interpreter, update_stream_meta_req) = self.build_query(query).await?;
         let query_physical_plan = query_interpreter.build_physical_plan().await?;
@@ -112,6 +113,7 @@ impl CopyIntoLocationInterpreter {
                 is_select: false,
                 default_values: None,
                 copy_into_location_options: options.clone(),
+                is_partitioned_unload: partitioned,
             },
         }));
 
@@ -149,6 +151,7 @@ impl Interpreter for CopyIntoLocationInterpreter {
                 &self.plan.path,
                 &self.plan.from,
                 &self.plan.options,
+                self.plan.partitioned,
             )
             .await?;
 
@@ -14,6 +14,8 @@
 
 use std::collections::BTreeMap;
 use std::sync::Arc;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
 
 use databend_common_catalog::lock::LockTableOption;
 use databend_common_exception::Result;
@@ -34,9 +36,13 @@ use databend_common_sql::executor::table_read_plan::ToReadDataSourcePlan;
 use databend_common_sql::executor::PhysicalPlan;
 use databend_common_storage::StageFileInfo;
 use databend_common_storages_stage::StageTable;
+use databend_common_storages_system::CopyHistoryLogElement;
+use databend_common_storages_system::CopyHistoryQueue;
 use log::debug;
 use log::info;
 
+use crate::interpreters::common::acquire_table_write_permit;
+use crate::interpreters::common::attach_table_write_permit;
 use crate::interpreters::common::check_deduplicate_label;
 use crate::interpreters::common::dml_build_update_stream_req;
 use crate::interpreters::HookOperator;
@@ -224,6 +230,55 @@ impl CopyIntoTableInterpreter {
         Ok(blocks)
     }
 
+    /// Record the loaded and the skipped files in `system.copy_history`.
+    fn write_copy_history(&self) -> Result<()> {
+        let event_time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_micros() as i64;
+        let element = |file_name: String| CopyHistoryLogElement {
+            event_time,
+            query_id: self.ctx.get_id(),
+            database: self.plan.database_name.clone(),
+            table: self.plan.table_name.clone(),
+            stage: self.plan.stage_table_info.stage_info.stage_name.clone(),
+            file_name,
+            file_size: None,
+            etag: None,
+            status: "SKIPPED".to_string(),
+            rows_loaded: 0,
+            errors_seen: 0,
+            first_error: None,
+            first_error_line: None,
+        };
+
+        let queue = CopyHistoryQueue::instance()?;
+        let cs = self.ctx.get_copy_status();
+        let files_to_copy = self.plan.stage_table_info.files_to_copy.as_deref();
+        for file in files_to_copy.unwrap_or_default() {
+            let mut element = CopyHistoryLogElement {
+                file_size: Some(file.size),
+                etag: file.etag.clone(),
+                status: "LOADED".to_string(),
+                ..element(file.path.clone())
+            };
+            if let Some(status) = cs.files.get(&file.path) {
+                element.rows_loaded = status.num_rows_loaded as u64;
+                if let Some(err) = &status.error {
+                    element.status = "PARTIALLY_LOADED".to_string();
+                    element.errors_seen = err.num_errors as u64;
+                    element.first_error = Some(err.first_error.error.to_string());
+                    element.first_error_line = Some(err.first_error.line as u64 + 1);
+                }
+            }
+            queue.append_data(element)?;
+        }
+        for file in &self.plan.stage_table_info.duplicated_files_detected {
+            queue.append_data(element(file.clone()))?;
+        }
+        Ok(())
+    }
+
     /// Build commit insertion pipeline.
     async fn commit_insertion(
         &self,
@@ -347,9 +402,20 @@ impl Interpreter for CopyIntoTableInterpreter {
 
         if self.plan.no_file_to_copy {
             info!("no file to copy");
+            self.write_copy_history()?;
             return self.on_no_files_to_copy().await;
         }
 
+        let table = self
+            .ctx
+            .get_table(
+                self.plan.catalog_info.catalog_name(),
+                &self.plan.database_name,
+                &self.plan.table_name,
+            )
+            .await?;
+        let write_permit = acquire_table_write_permit(&self.ctx, table.as_ref()).await?;
+
         let (physical_plan, update_stream_meta) = self.build_physical_plan(&self.plan).await?;
         let mut build_res =
             build_query_pipeline_without_render_result_set(&self.ctx, &physical_plan).await?;
@@ -390,6 +456,7 @@ impl Interpreter for CopyIntoTableInterpreter {
             hook_operator.execute(&mut build_res.main_pipeline).await;
         }
 
+        attach_table_write_permit(&mut build_res.main_pipeline, write_permit);
         Ok(build_res)
     }
 
@@ -399,6 +466,7 @@ impl Interpreter for CopyIntoTableInterpreter {
         } else {
             self.get_copy_into_table_result()?
         };
+        self.write_copy_history()?;
 
         Ok(Box::pin(DataBlockStream::create(None, blocks)))
     }
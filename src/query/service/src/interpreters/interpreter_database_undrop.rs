@@ -14,6 +14,9 @@
 
 use std::sync::Arc;
 
+use chrono::Duration;
+use chrono::Utc;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_sql::plans::UndropDatabasePlan;
 
@@ -47,6 +50,27 @@ impl Interpreter for UndropDatabaseInterpreter {
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let catalog_name = self.plan.catalog.as_str();
         let catalog = self.ctx.get_catalog(catalog_name).await?;
+
+        // Refuse to restore a database that has been dropped for longer than the retention
+        // period, its tables may already be vacuumed.
+        let retention_days = self.ctx.get_settings().get_data_retention_time_in_days()?;
+        let retention_boundary = Utc::now() - Duration::days(retention_days as i64);
+        let databases = catalog.list_databases_history(&self.plan.tenant).await?;
+        let latest_dropped = databases
+            .iter()
+            .map(|db| db.get_db_info())
+            .filter(|info| info.name_ident.database_name() == self.plan.database)
+            .filter(|info| info.meta.drop_on.is_some())
+            .max_by_key(|info| info.database_id.db_id);
+        if let Some(drop_on) = latest_dropped.and_then(|info| info.meta.drop_on) {
+            if drop_on < retention_boundary {
+                return Err(ErrorCode::UndropDbHasNoHistory(format!(
+                    "Cannot undrop database {}: it was dropped at {}, beyond the retention period of {} days",
+                    self.plan.database, drop_on, retention_days
+                )));
+            }
+        }
+
         catalog.undrop_database(self.plan.clone().into()).await?;
         Ok(PipelineBuildResult::create())
     }
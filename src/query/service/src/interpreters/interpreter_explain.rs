@@ -332,9 +332,12 @@ impl ExplainInterpreter {
             }
         }
 
-        let result = plan
+        let mut result = plan
             .format(metadata.clone(), Default::default())?
             .format_pretty()?;
+        if metadata.read().is_small_query() {
+            result = format!("execution mode: local (small query)\n{result}");
+        }
         let line_split_result: Vec<&str> = result.lines().collect();
         let formatted_plan = StringType::from_data(line_split_result);
         Ok(vec![DataBlock::new_from_columns(vec![formatted_plan])])
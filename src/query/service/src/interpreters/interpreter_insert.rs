@@ -31,6 +31,8 @@ use databend_common_sql::plans::Plan;
 use databend_common_sql::NameResolutionContext;
 use log::info;
 
+use crate::interpreters::common::acquire_table_write_permit;
+use crate::interpreters::common::attach_table_write_permit;
 use crate::interpreters::common::check_deduplicate_label;
 use crate::interpreters::common::dml_build_update_stream_req;
 use crate::interpreters::HookOperator;
@@ -103,6 +105,8 @@ impl Interpreter for InsertInterpreter {
         // check mutability
         table.check_mutable()?;
 
+        let write_permit = acquire_table_write_permit(&self.ctx, table.as_ref()).await?;
+
         let mut build_res = PipelineBuildResult::create();
 
         match &self.plan.source {
@@ -228,6 +232,7 @@ impl Interpreter for InsertInterpreter {
                     hook_operator.execute(&mut build_res.main_pipeline).await;
                 }
 
+                attach_table_write_permit(&mut build_res.main_pipeline, write_permit);
                 return Ok(build_res);
             }
         };
@@ -256,6 +261,7 @@ impl Interpreter for InsertInterpreter {
             hook_operator.execute(&mut build_res.main_pipeline).await;
         }
 
+        attach_table_write_permit(&mut build_res.main_pipeline, write_permit);
         Ok(build_res)
     }
 }
@@ -26,6 +26,7 @@ use crate::pipelines::PipelineBuildResult;
 use crate::servers::flight::v1::actions::KILL_QUERY;
 use crate::sessions::QueriesQueueManager;
 use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
 
 pub struct KillInterpreter {
     ctx: Arc<QueryContext>,
@@ -79,7 +80,12 @@ impl KillInterpreter {
 
     #[async_backtrace::framed]
     async fn execute_kill(&self, session_id: &String) -> Result<PipelineBuildResult> {
-        match self.ctx.get_session_by_id(session_id) {
+        // The id can also be a query id, e.g. `KILL QUERY '<query_id>'`.
+        let session = self
+            .ctx
+            .get_session_by_id(session_id)
+            .or_else(|| SessionManager::instance().get_session_by_query_id(session_id));
+        match session {
             None => match self.proxy_to_cluster {
                 true => self.kill_cluster_query().await,
                 false => Err(ErrorCode::UnknownSession(format!(
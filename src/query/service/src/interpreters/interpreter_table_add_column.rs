@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use databend_common_catalog::lock::LockTableOption;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table::TableExt;
 use databend_common_exception::ErrorCode;
@@ -71,6 +72,18 @@ impl Interpreter for AddTableColumnInterpreter {
         let db_name = self.plan.database.as_str();
         let tbl_name = self.plan.table.as_str();
 
+        // Fail fast if a long-running operation(e.g. recluster, compact) holds the table lock.
+        let lock_guard = self
+            .ctx
+            .clone()
+            .acquire_table_lock(
+                catalog_name,
+                db_name,
+                tbl_name,
+                &LockTableOption::LockNoRetry,
+            )
+            .await?;
+
         let tbl = self.ctx.get_table(catalog_name, db_name, tbl_name).await?;
         // check mutability
         tbl.check_mutable()?;
@@ -120,6 +133,8 @@ impl Interpreter for AddTableColumnInterpreter {
         };
 
         let _resp = catalog.update_single_table_meta(req, table_info).await?;
+        // The following update acquires the table lock by itself.
+        drop(lock_guard);
 
         // If the column is not deterministic, update to refresh the value with default expr.
         if !self.plan.is_deterministic {
@@ -30,7 +30,9 @@ use databend_common_management::RoleApi;
 use databend_common_meta_app::principal::OwnershipObject;
 use databend_common_meta_app::schema::CommitTableMetaReq;
 use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::CreateSequenceReq;
 use databend_common_meta_app::schema::CreateTableReq;
+use databend_common_meta_app::schema::SequenceIdent;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
@@ -38,6 +40,7 @@ use databend_common_meta_app::schema::TableNameIdent;
 use databend_common_meta_app::schema::TableStatistics;
 use databend_common_meta_types::MatchSeq;
 use databend_common_pipeline_core::ExecutionInfo;
+use databend_common_sql::default_expr_sequence;
 use databend_common_sql::field_default_value;
 use databend_common_sql::plans::CreateTablePlan;
 use databend_common_storages_fuse::io::MetaReaders;
@@ -56,11 +59,14 @@ use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
 use log::error;
 use log::info;
 
+use crate::interpreters::common::table_option_validation::is_valid_auto_compaction_options;
 use crate::interpreters::common::table_option_validation::is_valid_block_per_segment;
 use crate::interpreters::common::table_option_validation::is_valid_bloom_index_columns;
 use crate::interpreters::common::table_option_validation::is_valid_change_tracking;
 use crate::interpreters::common::table_option_validation::is_valid_create_opt;
 use crate::interpreters::common::table_option_validation::is_valid_data_retention_period;
+use crate::interpreters::common::table_option_validation::is_valid_max_concurrent_writes;
+use crate::interpreters::common::table_option_validation::is_valid_ngram_bloom_index;
 use crate::interpreters::common::table_option_validation::is_valid_random_seed;
 use crate::interpreters::common::table_option_validation::is_valid_row_per_block;
 use crate::interpreters::InsertInterpreter;
@@ -151,6 +157,8 @@ impl Interpreter for CreateTableInterpreter {
             }
         }
 
+        self.create_auto_increment_sequences().await?;
+
         match &self.plan.as_select {
             Some(select_plan_node) => self.create_table_as_select(select_plan_node.clone()).await,
             None => self.create_table().await,
@@ -159,6 +167,34 @@ impl Interpreter for CreateTableInterpreter {
 }
 
 impl CreateTableInterpreter {
+    /// AUTOINCREMENT columns draw their values from a sequence of their own, named in the
+    /// default expr of the column.
+    #[async_backtrace::framed]
+    async fn create_auto_increment_sequences(&self) -> Result<()> {
+        let catalog = self.ctx.get_default_catalog()?;
+        for field in self.plan.schema.fields() {
+            let Some(sequence) = field
+                .default_expr()
+                .and_then(|expr| default_expr_sequence(expr))
+            else {
+                continue;
+            };
+            let req = CreateSequenceReq {
+                create_option: CreateOption::CreateIfNotExists,
+                ident: SequenceIdent::new(&self.plan.tenant, sequence),
+                create_on: Utc::now(),
+                comment: Some(format!(
+                    "AUTOINCREMENT column {} of table {}.{}",
+                    field.name(),
+                    self.plan.database,
+                    self.plan.table
+                )),
+            };
+            catalog.create_sequence(req).await?;
+        }
+        Ok(())
+    }
+
     #[async_backtrace::framed]
     async fn create_table_as_select(&self, select_plan: Box<Plan>) -> Result<PipelineBuildResult> {
         assert!(
@@ -397,6 +433,9 @@ impl CreateTableInterpreter {
         is_valid_change_tracking(&table_meta.options)?;
         // check random seed
         is_valid_random_seed(&table_meta.options)?;
+        is_valid_max_concurrent_writes(&table_meta.options)?;
+        is_valid_auto_compaction_options(&table_meta.options)?;
+        is_valid_ngram_bloom_index(&table_meta.options)?;
         // check table level data_retention_period_in_hours
         is_valid_data_retention_period(&table_meta.options)?;
 
@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use databend_common_catalog::lock::LockTableOption;
 use databend_common_catalog::table::TableExt;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -60,6 +61,18 @@ impl Interpreter for DropTableColumnInterpreter {
         let catalog_name = self.plan.catalog.as_str();
         let db_name = self.plan.database.as_str();
         let tbl_name = self.plan.table.as_str();
+        // Fail fast if a long-running operation(e.g. recluster, compact) holds the table lock.
+        let _guard = self
+            .ctx
+            .clone()
+            .acquire_table_lock(
+                catalog_name,
+                db_name,
+                tbl_name,
+                &LockTableOption::LockNoRetry,
+            )
+            .await?;
+
         let table = self
             .ctx
             .get_catalog(catalog_name)
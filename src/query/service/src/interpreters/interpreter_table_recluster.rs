@@ -24,6 +24,8 @@ use databend_common_sql::executor::PhysicalPlanBuilder;
 use databend_common_sql::optimizer::SExpr;
 use databend_common_sql::plans::Recluster;
 use databend_common_sql::MetadataRef;
+use databend_common_storages_fuse::table_functions::ClusteringInformation;
+use databend_common_storages_fuse::FuseTable;
 use log::error;
 use log::warn;
 
@@ -184,7 +186,50 @@ impl ReclusterTableInterpreter {
         // make sure the lock guard is dropped before the next loop.
         drop(lock_guard);
 
-        InterpreterClusteringHistory::write_log(&self.ctx, start, &plan.database, &plan.table)?;
+        // Record the clustering depth after this run, so that the progress of
+        // incremental recluster can be tracked from `system.clustering_history`.
+        // It reads all segments, so it is opt-in: the compact hook reclusters after writes.
+        let (average_overlaps, average_depth) = if self
+            .ctx
+            .get_settings()
+            .get_enable_clustering_history_depth()?
+        {
+            match self.clustering_depth(&plan).await {
+                Ok((overlaps, depth)) => (Some(overlaps), Some(depth)),
+                Err(e) => {
+                    warn!(
+                        "Get clustering depth of table {} failed: {:?}",
+                        plan.table, e
+                    );
+                    (None, None)
+                }
+            }
+        } else {
+            (None, None)
+        };
+        InterpreterClusteringHistory::write_log(
+            &self.ctx,
+            start,
+            &plan.database,
+            &plan.table,
+            average_overlaps,
+            average_depth,
+        )?;
         Ok(false)
     }
+
+    async fn clustering_depth(&self, plan: &Recluster) -> Result<(f64, f64)> {
+        // Read the latest table from catalog, the one cached in the context is stale.
+        let table = self
+            .ctx
+            .get_catalog(&plan.catalog)
+            .await?
+            .get_table(&self.ctx.get_tenant(), &plan.database, &plan.table)
+            .await?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        let stats = ClusteringInformation::new(self.ctx.clone(), fuse_table, None)
+            .get_clustering_statistics()
+            .await?;
+        Ok((stats.average_overlaps, stats.average_depth))
+    }
 }
@@ -14,9 +14,14 @@
 
 use std::sync::Arc;
 
+use chrono::Duration;
+use chrono::Utc;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_sql::plans::UndropTablePlan;
+use databend_common_storages_fuse::FUSE_OPT_KEY_DATA_RETENTION_PERIOD_IN_HOURS;
 
+use crate::interpreters::common::table_option_validation::display_data_retention_period;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -47,6 +52,39 @@ impl Interpreter for UndropTableInterpreter {
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let catalog_name = self.plan.catalog.as_str();
         let catalog = self.ctx.get_catalog(catalog_name).await?;
+
+        // The latest dropped table of this name is the one to be restored. Once it has been
+        // dropped for longer than the retention period, its data may already be vacuumed.
+        let tables = catalog
+            .list_tables_history(&self.plan.tenant, &self.plan.database)
+            .await?;
+        let latest_dropped = tables
+            .iter()
+            .filter(|t| t.name() == self.plan.table)
+            .filter(|t| t.get_table_info().meta.drop_on.is_some())
+            .max_by_key(|t| t.get_id());
+        if let Some(table) = latest_dropped {
+            let retention_hours = match table
+                .options()
+                .get(FUSE_OPT_KEY_DATA_RETENTION_PERIOD_IN_HOURS)
+            {
+                Some(hours) => hours.parse::<u64>()?,
+                None => self.ctx.get_settings().get_data_retention_time_in_days()? * 24,
+            };
+            let retention = Duration::hours(retention_hours as i64);
+            if let Some(drop_on) = table.get_table_info().meta.drop_on {
+                if drop_on < Utc::now() - retention {
+                    return Err(ErrorCode::UndropTableHasNoHistory(format!(
+                        "Cannot undrop table {}.{}: it was dropped at {}, beyond the retention period of {}",
+                        self.plan.database,
+                        self.plan.table,
+                        drop_on,
+                        display_data_retention_period(retention_hours)
+                    )));
+                }
+            }
+        }
+
         catalog.undrop_table(self.plan.clone().into()).await?;
 
         Ok(PipelineBuildResult::create())
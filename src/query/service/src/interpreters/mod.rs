@@ -149,6 +149,9 @@ mod util;
 
 pub use access::ManagementModeAccess;
 pub use common::InterpreterQueryLog;
+pub use common::MetricsHistory;
+pub use common::QueryAudit;
+pub use common::QueryAuditRecord;
 pub use hook::HookOperator;
 pub use interpreter::interpreter_plan_sql;
 pub use interpreter::Interpreter;
@@ -32,6 +32,7 @@ use databend_common_meta_app::schema::CreateLockRevReq;
 use databend_common_meta_app::schema::DeleteLockRevReq;
 use databend_common_meta_app::schema::ExtendLockRevReq;
 use databend_common_meta_app::schema::ListLockRevReq;
+use databend_common_meta_app::schema::LockMeta;
 use databend_common_meta_app::schema::TableLockIdent;
 use databend_common_meta_kvapi::kvapi::Key;
 use databend_common_meta_types::protobuf::watch_request::FilterType;
@@ -48,6 +49,17 @@ use rand::Rng;
 
 use crate::sessions::SessionManager;
 
+/// Describe the session holding a lock, to make conflicts easy to diagnose.
+fn lock_holder_display(meta: &LockMeta) -> String {
+    format!(
+        "held by query '{}' of user '{}' on node '{}' since {}",
+        meta.query_id,
+        meta.user,
+        meta.node,
+        meta.acquired_on.unwrap_or(meta.created_on)
+    )
+}
+
 #[derive(Default)]
 pub struct LockHolder {
     shutdown_flag: AtomicBool,
@@ -79,15 +91,13 @@ impl LockHolder {
 
         loop {
             // List all revisions and check if the current is the minimum.
-            let mut rev_list = catalog
+            let mut locks = catalog
                 .list_lock_revisions(list_table_lock_req.clone())
-                .await?
-                .into_iter()
-                .map(|(x, _)| x)
-                .collect::<Vec<_>>();
+                .await?;
             // list_lock_revisions are returned in big-endian order,
             // we need to sort them in ascending numeric order.
-            rev_list.sort();
+            locks.sort_by_key(|(x, _)| *x);
+            let rev_list = locks.iter().map(|(x, _)| *x).collect::<Vec<_>>();
             let position = rev_list.iter().position(|x| *x == revision).ok_or_else(||
                 // If the current is not found in list,  it means that the current has been expired.
                 ErrorCode::TableLockExpired(format!(
@@ -108,14 +118,16 @@ impl LockHolder {
             }
 
             let prev_revision = rev_list[position - 1];
+            let holder = lock_holder_display(&locks[0].1);
             let elapsed = start.elapsed();
             // if no need retry, return error directly.
             if !should_retry || elapsed >= acquire_timeout {
                 return Err(ErrorCode::TableAlreadyLocked(format!(
-                    "Table is locked by other session(rev: {}, prev: {}, elapsed: {:?})",
+                    "Table is locked by other session(rev: {}, prev: {}, elapsed: {:?}), {}",
                     revision,
                     prev_revision,
-                    start.elapsed()
+                    start.elapsed(),
+                    holder
                 )));
             }
 
@@ -151,10 +163,11 @@ impl LockHolder {
             .await
             {
                 return Err(ErrorCode::TableAlreadyLocked(format!(
-                    "Table is locked by other session(rev: {}, prev: {}, elapsed: {:?})",
+                    "Table is locked by other session(rev: {}, prev: {}, elapsed: {:?}), {}",
                     revision,
                     prev_revision,
-                    start.elapsed()
+                    start.elapsed(),
+                    holder
                 )));
             }
         }
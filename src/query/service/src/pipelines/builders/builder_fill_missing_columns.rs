@@ -16,11 +16,14 @@ use std::sync::Arc;
 
 use databend_common_catalog::table::Table;
 use databend_common_exception::Result;
+use databend_common_expression::DataSchema;
 use databend_common_expression::DataSchemaRef;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
+use databend_common_sql::default_expr_sequence;
 
 use crate::pipelines::processors::transforms::TransformAddComputedColumns;
+use crate::pipelines::processors::transforms::TransformAddSequenceColumns;
 use crate::pipelines::processors::TransformResortAddOn;
 use crate::pipelines::PipelineBuilder;
 use crate::sessions::QueryContext;
@@ -40,6 +43,34 @@ impl PipelineBuilder {
         let default_schema: DataSchemaRef = Arc::new(table_default_schema.into());
         let computed_schema: DataSchemaRef = Arc::new(table_computed_schema.into());
 
+        // Fill missing columns whose default is a sequence, which can not be
+        // evaluated by the expressions below.
+        let sequence_fields = default_schema
+            .fields()
+            .iter()
+            .filter(|f| !source_schema.has_field(f.name()))
+            .filter_map(|f| {
+                let sequence = f
+                    .default_expr()
+                    .and_then(|expr| default_expr_sequence(expr))?;
+                Some((sequence, f.clone()))
+            })
+            .collect::<Vec<_>>();
+        let source_schema = if sequence_fields.is_empty() {
+            source_schema
+        } else {
+            let columns = sequence_fields
+                .iter()
+                .map(|(sequence, f)| (sequence.clone(), f.data_type().clone()))
+                .collect::<Vec<_>>();
+            pipeline.add_async_transformer(|| {
+                TransformAddSequenceColumns::new(ctx.clone(), columns.clone())
+            });
+            let mut fields = source_schema.fields().clone();
+            fields.extend(sequence_fields.into_iter().map(|(_, f)| f));
+            Arc::new(DataSchema::new(fields))
+        };
+
         // Fill missing default columns and resort the columns.
         if source_schema != default_schema {
             pipeline.try_add_transformer(|| {
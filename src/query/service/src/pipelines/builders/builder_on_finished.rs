@@ -65,7 +65,15 @@ impl PipelineBuilder {
                             }
                         }
 
-                        // 2. Try to purge copied files if purge option is true, if error will skip.
+                        // 2. write the rows rejected by ON_ERROR = CONTINUE.
+                        if let Some(path) = &stage_info.copy_options.rejected_rows_path {
+                            if let Err(e) = Self::write_rejected_rows(&ctx, &stage_info, path).await
+                            {
+                                error!("copy: failed to write rejected rows to {}: {}", path, e);
+                            }
+                        }
+
+                        // 3. Try to purge copied files if purge option is true, if error will skip.
                         // If a file is already copied(status with AlreadyCopied) we will try to purge them.
                         if !is_active && copy_purge_option {
                             Self::try_purge_files(ctx.clone(), &stage_info, &files).await;
@@ -83,6 +91,51 @@ impl PipelineBuilder {
         Ok(())
     }
 
+    /// Write the rejected rows of all files as one ndjson file named by the query id,
+    /// each line has the `file`, `line`, `error` and `row` of a rejected row.
+    async fn write_rejected_rows(
+        ctx: &Arc<QueryContext>,
+        stage_info: &StageInfo,
+        path: &str,
+    ) -> Result<()> {
+        let copy_status = ctx.get_copy_status();
+        let mut files = copy_status
+            .files
+            .iter()
+            .filter(|entry| !entry.value().rejected_rows.is_empty())
+            .map(|entry| (entry.key().clone(), entry.value().rejected_rows.clone()))
+            .collect::<Vec<_>>();
+        if files.is_empty() {
+            return Ok(());
+        }
+        files.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut body = Vec::new();
+        let mut num_rows = 0;
+        for (file, rows) in &files {
+            for row in rows {
+                serde_json::to_writer(
+                    &mut body,
+                    &serde_json::json!({
+                        "file": file,
+                        "line": row.line + 1,
+                        "error": row.error,
+                        "row": row.row,
+                    }),
+                )?;
+                body.push(b'\n');
+                num_rows += 1;
+            }
+        }
+
+        let location = format!("{path}{}.ndjson", ctx.get_id());
+        StageTable::get_op(stage_info)?
+            .write(&location, body)
+            .await?;
+        info!("copy: wrote {} rejected rows to {}", num_rows, location);
+        Ok(())
+    }
+
     pub async fn purge_files_immediately(
         ctx: Arc<QueryContext>,
         files: Vec<String>,
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::hash_map::Entry;
+use std::collections::BTreeSet;
 use std::collections::HashMap;
 use std::collections::VecDeque;
 use std::fmt::Debug;
@@ -797,6 +798,25 @@ impl RunningGraph {
         plans_profile
     }
 
+    /// Names of the plans that have processors scheduled or being processed right now.
+    pub fn running_plan_names(&self) -> Vec<String> {
+        let mut plan_names = BTreeSet::new();
+
+        for node in self.0.graph.node_weights() {
+            let state = node.state.lock().unwrap_or_else(PoisonError::into_inner);
+            if !matches!(*state, State::Processing) {
+                continue;
+            }
+
+            let profile = node.tracking_payload.profile.as_deref();
+            if let Some(plan_name) = profile.and_then(|profile| profile.plan_name.as_ref()) {
+                plan_names.insert(plan_name.clone());
+            }
+        }
+
+        plan_names.into_iter().collect()
+    }
+
     pub fn interrupt_running_nodes(&self) {
         unsafe {
             for node_index in self.0.graph.node_indices() {
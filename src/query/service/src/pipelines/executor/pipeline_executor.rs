@@ -272,6 +272,13 @@ impl PipelineExecutor {
         }
     }
 
+    pub fn running_plan_names(&self) -> Vec<String> {
+        match self {
+            PipelineExecutor::QueryPipelineExecutor(executor) => executor.running_plan_names(),
+            PipelineExecutor::QueriesPipelineExecutor(v) => v.graph.running_plan_names(),
+        }
+    }
+
     pub fn fetch_profiling(&self, collect_metrics: bool) -> HashMap<u32, PlanProfile> {
         match self {
             PipelineExecutor::QueryPipelineExecutor(executor) => {
@@ -449,6 +449,10 @@ impl QueryPipelineExecutor {
         self.graph.format_graph_nodes()
     }
 
+    pub fn running_plan_names(&self) -> Vec<String> {
+        self.graph.running_plan_names()
+    }
+
     pub fn fetch_plans_profile(&self, collect_metrics: bool) -> HashMap<u32, PlanProfile> {
         match collect_metrics {
             true => self
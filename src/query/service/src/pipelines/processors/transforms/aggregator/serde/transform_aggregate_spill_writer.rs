@@ -44,6 +44,7 @@ use crate::pipelines::processors::transforms::aggregator::HashTablePayload;
 use crate::pipelines::processors::transforms::group_by::HashMethodBounds;
 use crate::pipelines::processors::transforms::group_by::PartitionedHashMethod;
 use crate::sessions::QueryContext;
+use crate::spillers::charge_spill_quota;
 
 pub struct TransformAggregateSpillWriter<Method: HashMethodBounds> {
     ctx: Arc<QueryContext>,
@@ -247,6 +248,8 @@ pub fn agg_spilling_aggregate_payload<Method: HashMethodBounds>(
         });
     }
 
+    charge_spill_quota(&ctx, write_size as usize)?;
+
     Ok(Box::pin(async move {
         let instant = Instant::now();
 
@@ -347,6 +350,8 @@ pub fn spilling_aggregate_payload<Method: HashMethodBounds>(
         });
     }
 
+    charge_spill_quota(&ctx, write_size as usize)?;
+
     Ok(Box::pin(async move {
         let instant = Instant::now();
 
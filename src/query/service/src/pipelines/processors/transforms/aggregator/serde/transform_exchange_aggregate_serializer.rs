@@ -63,6 +63,7 @@ use crate::pipelines::processors::transforms::group_by::PartitionedHashMethod;
 use crate::servers::flight::v1::exchange::serde::serialize_block;
 use crate::servers::flight::v1::exchange::ExchangeShuffleMeta;
 use crate::sessions::QueryContext;
+use crate::spillers::charge_spill_quota;
 
 pub struct TransformExchangeAggregateSerializer<Method: HashMethodBounds> {
     ctx: Arc<QueryContext>,
@@ -286,6 +287,8 @@ fn agg_spilling_aggregate_payload(
         data_range_start_column_data.push(old_write_size);
     }
 
+    charge_spill_quota(&ctx, write_size as usize)?;
+
     Ok(Box::pin(async move {
         if !write_data.is_empty() {
             let instant = Instant::now();
@@ -409,6 +412,8 @@ fn spilling_aggregate_payload<Method: HashMethodBounds>(
         data_range_start_column_data.push(old_write_size);
     }
 
+    charge_spill_quota(&ctx, write_size as usize)?;
+
     Ok(Box::pin(async move {
         if !write_data.is_empty() {
             let instant = Instant::now();
@@ -67,6 +67,7 @@ use crate::pipelines::processors::transforms::group_by::PartitionedHashMethod;
 use crate::servers::flight::v1::exchange::serde::serialize_block;
 use crate::servers::flight::v1::exchange::ExchangeShuffleMeta;
 use crate::sessions::QueryContext;
+use crate::spillers::charge_spill_quota;
 
 pub struct TransformExchangeGroupBySerializer<Method: HashMethodBounds> {
     ctx: Arc<QueryContext>,
@@ -321,6 +322,8 @@ fn agg_spilling_group_by_payload(
         data_range_start_column_data.push(old_write_size);
     }
 
+    charge_spill_quota(&ctx, write_size as usize)?;
+
     Ok(Box::pin(async move {
         let instant = Instant::now();
 
@@ -442,6 +445,8 @@ fn spilling_group_by_payload<Method: HashMethodBounds>(
         data_range_start_column_data.push(old_write_size);
     }
 
+    charge_spill_quota(&ctx, write_size as usize)?;
+
     Ok(Box::pin(async move {
         let instant = Instant::now();
 
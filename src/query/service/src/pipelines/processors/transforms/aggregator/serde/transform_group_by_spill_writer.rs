@@ -43,6 +43,7 @@ use crate::pipelines::processors::transforms::aggregator::HashTablePayload;
 use crate::pipelines::processors::transforms::group_by::HashMethodBounds;
 use crate::pipelines::processors::transforms::group_by::PartitionedHashMethod;
 use crate::sessions::QueryContext;
+use crate::spillers::charge_spill_quota;
 
 pub struct TransformGroupBySpillWriter<Method: HashMethodBounds> {
     ctx: Arc<QueryContext>,
@@ -246,6 +247,8 @@ pub fn agg_spilling_group_by_payload<Method: HashMethodBounds>(
         });
     }
 
+    charge_spill_quota(&ctx, write_size as usize)?;
+
     Ok(Box::pin(async move {
         let instant = Instant::now();
 
@@ -342,6 +345,8 @@ pub fn spilling_group_by_payload<Method: HashMethodBounds>(
         });
     }
 
+    charge_spill_quota(&ctx, write_size as usize)?;
+
     Ok(Box::pin(async move {
         let instant = Instant::now();
 
@@ -19,6 +19,7 @@ pub(crate) mod range_join;
 mod transform_add_computed_columns;
 mod transform_add_const_columns;
 mod transform_add_internal_columns;
+mod transform_add_sequence_columns;
 mod transform_add_stream_columns;
 mod transform_async_function;
 mod transform_cache_scan;
@@ -45,6 +46,7 @@ pub use hash_join::*;
 pub use transform_add_computed_columns::TransformAddComputedColumns;
 pub use transform_add_const_columns::TransformAddConstColumns;
 pub use transform_add_internal_columns::TransformAddInternalColumns;
+pub use transform_add_sequence_columns::TransformAddSequenceColumns;
 pub use transform_add_stream_columns::TransformAddStreamColumns;
 pub use transform_async_function::TransformAsyncFunction;
 pub use transform_cache_scan::CacheSourceState;
@@ -75,6 +75,7 @@ where Self: Transform
         let func_ctx = ctx.get_function_context()?;
         let expression_transform = CompoundBlockOperator {
             ctx: func_ctx,
+            jit_exprs: vec![],
             operators: vec![BlockOperator::Map {
                 exprs,
                 projections: None,
@@ -0,0 +1,84 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::type_check::check_cast;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
+use databend_common_expression::FromData;
+use databend_common_expression::Value;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_pipeline_transforms::processors::AsyncTransform;
+
+use crate::pipelines::processors::transforms::transform_async_function::next_sequence_values;
+use crate::sessions::QueryContext;
+
+/// Appends the columns whose default is `nextval(<sequence>)`, e.g. AUTOINCREMENT columns,
+/// to the end of the block. The values of a block are leased with one meta request per column.
+pub struct TransformAddSequenceColumns {
+    ctx: Arc<QueryContext>,
+    // (sequence name, column type)
+    columns: Vec<(String, DataType)>,
+}
+
+impl TransformAddSequenceColumns {
+    pub fn new(ctx: Arc<QueryContext>, columns: Vec<(String, DataType)>) -> Self {
+        Self { ctx, columns }
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncTransform for TransformAddSequenceColumns {
+    const NAME: &'static str = "AddSequenceColumnsTransform";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, mut data_block: DataBlock) -> Result<DataBlock> {
+        let num_rows = data_block.num_rows();
+        let func_ctx = self.ctx.get_function_context()?;
+        for (sequence, data_type) in &self.columns {
+            let values = next_sequence_values(&self.ctx, sequence, num_rows as u64).await?;
+            let values = DataBlock::new(
+                vec![BlockEntry::new(
+                    DataType::Number(NumberDataType::UInt64),
+                    Value::Column(UInt64Type::from_data(values)),
+                )],
+                num_rows,
+            );
+            let cast = check_cast(
+                None,
+                false,
+                Expr::ColumnRef {
+                    span: None,
+                    id: 0,
+                    data_type: DataType::Number(NumberDataType::UInt64),
+                    display_name: sequence.clone(),
+                },
+                data_type,
+                &BUILTIN_FUNCTIONS,
+            )?;
+            let evaluator = Evaluator::new(&values, &func_ctx, &BUILTIN_FUNCTIONS);
+            let value = evaluator.run(&cast)?;
+            data_block.add_column(BlockEntry::new(data_type.clone(), value));
+        }
+        Ok(data_block)
+    }
+}
@@ -60,19 +60,8 @@ impl TransformAsyncFunction {
         data_type: &DataType,
     ) -> Result<()> {
         let count = data_block.num_rows() as u64;
-        let value = if count == 0 {
-            UInt64Type::from_data(vec![])
-        } else {
-            let tenant = self.ctx.get_tenant();
-            let catalog = self.ctx.get_default_catalog()?;
-            let req = GetSequenceNextValueReq {
-                ident: SequenceIdent::new(&tenant, sequence_name),
-                count,
-            };
-            let resp = catalog.get_sequence_next_value(req).await?;
-            let range = resp.start..resp.start + count;
-            UInt64Type::from_data(range.collect::<Vec<u64>>())
-        };
+        let value =
+            UInt64Type::from_data(next_sequence_values(&self.ctx, sequence_name, count).await?);
         let entry = BlockEntry {
             data_type: data_type.clone(),
             value: Value::Column(value),
@@ -83,6 +72,25 @@ impl TransformAsyncFunction {
     }
 }
 
+/// Lease `count` consecutive values of the sequence from the meta service at once.
+pub(crate) async fn next_sequence_values(
+    ctx: &QueryContext,
+    sequence_name: &str,
+    count: u64,
+) -> Result<Vec<u64>> {
+    if count == 0 {
+        return Ok(vec![]);
+    }
+    let tenant = ctx.get_tenant();
+    let catalog = ctx.get_default_catalog()?;
+    let req = GetSequenceNextValueReq {
+        ident: SequenceIdent::new(&tenant, sequence_name),
+        count,
+    };
+    let resp = catalog.get_sequence_next_value(req).await?;
+    Ok((resp.start..resp.start + count).collect())
+}
+
 #[async_trait::async_trait]
 impl AsyncTransform for TransformAsyncFunction {
     const NAME: &'static str = "AsyncFunction";
@@ -29,6 +29,7 @@ use databend_common_expression::ROW_VERSION_COL_NAME;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_pipeline_transforms::processors::Transform;
 use databend_common_pipeline_transforms::processors::Transformer;
+use databend_common_sql::default_expr_sequence;
 use databend_common_sql::evaluator::BlockOperator;
 use databend_common_sql::evaluator::CompoundBlockOperator;
 use databend_common_sql::parse_exprs;
@@ -67,6 +68,13 @@ pub fn build_expression_transform(
     for f in output_schema.fields().iter() {
         let expr = if !input_schema.has_field(f.name()) {
             if let Some(default_expr) = f.default_expr() {
+                if default_expr_sequence(default_expr).is_some() {
+                    return Err(ErrorCode::Unimplemented(format!(
+                        "AUTOINCREMENT column `{}` of table `{}` can not be filled here",
+                        f.name(),
+                        table.name()
+                    )));
+                }
                 let expr = parse_exprs(ctx.clone(), table.clone(), default_expr)?.remove(0);
                 check_cast(None, false, expr, f.data_type(), &BUILTIN_FUNCTIONS)?
             } else {
@@ -112,6 +120,7 @@ pub fn build_expression_transform(
     let func_ctx = ctx.get_function_context()?;
     Ok(CompoundBlockOperator {
         ctx: func_ctx,
+        jit_exprs: vec![],
         operators: vec![BlockOperator::Map {
             exprs,
             projections: None,
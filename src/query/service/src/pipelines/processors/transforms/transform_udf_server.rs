@@ -16,8 +16,7 @@ use std::sync::Arc;
 use std::time::Duration;
 use std::time::Instant;
 
-use backon::ExponentialBuilder;
-use backon::Retryable;
+use databend_common_base::base::RetryPolicy;
 use databend_common_base::runtime::profile::Profile;
 use databend_common_base::runtime::profile::ProfileStatisticsName;
 use databend_common_catalog::table_context::TableContext;
@@ -48,7 +47,7 @@ pub struct TransformUdfServer {
     connect_timeout: u64,
     request_timeout: u64,
     request_bacth_rows: u64,
-    retry_times: u64,
+    retry_policy: RetryPolicy,
 }
 
 impl TransformUdfServer {
@@ -58,6 +57,12 @@ impl TransformUdfServer {
         let request_timeout = settings.get_external_server_request_timeout_secs()?;
         let request_bacth_rows = settings.get_external_server_request_batch_rows()?;
         let retry_times = settings.get_external_server_request_retry_times()?;
+        let retry_policy = RetryPolicy::default()
+            .with_min_delay(Duration::from_millis(50))
+            .with_factor(2.0)
+            .with_max_delay(Duration::from_secs(30))
+            .with_max_attempts(retry_times as usize)
+            .with_deadline(ctx.get_query_deadline()?);
 
         let s = Self {
             ctx,
@@ -65,7 +70,7 @@ impl TransformUdfServer {
             connect_timeout,
             request_timeout,
             request_bacth_rows,
-            retry_times,
+            retry_policy,
         };
         Ok(s)
     }
@@ -217,20 +222,24 @@ impl AsyncTransform for TransformUdfServer {
                                 )
                             }
                         };
-                        let backoff = ExponentialBuilder::default()
-                            .with_min_delay(Duration::from_millis(50))
-                            .with_factor(2.0)
-                            .with_max_delay(Duration::from_secs(30))
-                            .with_max_times(self.retry_times as usize);
+                        let retry_policy = self.retry_policy.clone();
 
-                        f.retry(backoff).when(retry_on).notify(move |err, dur| {
-                            Profile::record_usize_profile(
-                                ProfileStatisticsName::ExternalServerRetryCount,
-                                1,
-                            );
-                            record_retry_external(name.clone(), error_kind(&err.message()));
-                            log::warn!("Retry udf error: {:?} after {:?}", err.message(), dur);
-                        })
+                        async move {
+                            retry_policy
+                                .retry(f, retry_on, move |err, dur| {
+                                    Profile::record_usize_profile(
+                                        ProfileStatisticsName::ExternalServerRetryCount,
+                                        1,
+                                    );
+                                    record_retry_external(name.clone(), error_kind(&err.message()));
+                                    log::warn!(
+                                        "Retry udf error: {:?} after {:?}",
+                                        err.message(),
+                                        dur
+                                    );
+                                })
+                                .await
+                        }
                     })
                 })
                 .collect();
@@ -42,6 +42,7 @@ use databend_common_sql::plans::WindowFuncFrameUnits;
 
 use super::frame_bound::FrameBound;
 use super::window_function::WindowFuncAggImpl;
+use super::window_function::WindowFuncLagLeadImpl;
 use super::window_function::WindowFunctionImpl;
 use super::WindowFunctionInfo;
 
@@ -560,6 +561,23 @@ impl<T: Number> TransformWindow<T> {
                 };
                 builder.push(ScalarRef::Number(NumberScalar::Float64(percent.into())));
             }
+            WindowFunctionImpl::LagLead(ll) if ll.ignore_null => {
+                let value = match self.get_lag_lead_value_ignoring_nulls(ll) {
+                    Some(value) => value,
+                    None => match &ll.default {
+                        LagLeadDefault::Null => Scalar::Null,
+                        LagLeadDefault::Index(col) => {
+                            let block =
+                                &self.blocks[self.current_row.block - self.first_block].block;
+                            let value = &block.get_by_offset(*col).value;
+                            value.index(self.current_row.row).unwrap().to_owned()
+                        }
+                    },
+                };
+
+                let builder = &mut self.blocks[self.current_row.block - self.first_block].builder;
+                builder.push(value.as_ref());
+            }
             WindowFunctionImpl::LagLead(ll) => {
                 let value = if self.frame_start == self.frame_end {
                     match &ll.default {
@@ -667,6 +685,43 @@ impl<T: Number> TransformWindow<T> {
         false
     }
 
+    /// Get the `offset`-th non-NULL value of the frame, counting from the row next to the
+    /// current row: backwards for `lag` and forwards for `lead`.
+    fn get_lag_lead_value_ignoring_nulls(&self, ll: &WindowFuncLagLeadImpl) -> Option<Scalar> {
+        if self.frame_start == self.frame_end {
+            return None;
+        }
+
+        let mut remaining = ll.offset;
+        let mut cur = if ll.is_lag {
+            self.goback_row(self.frame_end)
+        } else {
+            self.frame_start
+        };
+        loop {
+            let block = &self.blocks.get(cur.block - self.first_block).unwrap().block;
+            let value = block.get_by_offset(ll.arg).value.index(cur.row).unwrap();
+            if value != ScalarRef::Null {
+                remaining -= 1;
+                if remaining == 0 {
+                    return Some(value.to_owned());
+                }
+            }
+
+            if ll.is_lag {
+                if cur == self.frame_start {
+                    return None;
+                }
+                cur = self.goback_row(cur);
+            } else {
+                cur = self.advance_row(cur);
+                if cur >= self.frame_end {
+                    return None;
+                }
+            }
+        }
+    }
+
     #[inline]
     fn get_nth_value_by_ignoring_nulls(
         &self,
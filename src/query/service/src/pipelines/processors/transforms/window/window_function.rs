@@ -92,6 +92,9 @@ pub struct WindowFuncLagLeadImpl {
     pub arg: usize,
     pub default: LagLeadDefault,
     pub return_type: DataType,
+    pub is_lag: bool,
+    pub offset: u64,
+    pub ignore_null: bool,
 }
 
 #[derive(Clone)]
@@ -209,6 +212,9 @@ impl WindowFunctionInfo {
                     arg: new_arg,
                     default: new_default,
                     return_type: ll.return_type.clone(),
+                    is_lag: ll.is_lag,
+                    offset: ll.offset,
+                    ignore_null: ll.ignore_null,
                 })
             }
             WindowFunction::NthValue(func) => {
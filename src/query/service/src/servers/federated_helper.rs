@@ -19,7 +19,7 @@ use databend_common_expression::TableSchemaRef;
 use databend_common_expression::TableSchemaRefExt;
 use regex::Regex;
 
-pub type LazyBlockFunc = fn(&str) -> Option<(TableSchemaRef, DataBlock)>;
+pub type LazyBlockFunc<C> = fn(&C, &str) -> Option<(TableSchemaRef, DataBlock)>;
 
 pub struct FederatedHelper {}
 
@@ -40,13 +40,14 @@ impl FederatedHelper {
         None
     }
 
-    pub fn lazy_block_match_rule(
+    pub fn lazy_block_match_rule<C>(
+        ctx: &C,
         query: &str,
-        rules: &[(Regex, LazyBlockFunc)],
+        rules: &[(Regex, LazyBlockFunc<C>)],
     ) -> Option<(TableSchemaRef, DataBlock)> {
         for (regex, func) in rules.iter() {
             if regex.is_match(query) {
-                return match func(query) {
+                return match func(ctx, query) {
                     None => Some((TableSchemaRefExt::create(vec![]), DataBlock::empty())),
                     Some((schema, data_block)) => Some((schema, data_block)),
                 };
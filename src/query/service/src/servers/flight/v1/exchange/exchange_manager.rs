@@ -25,6 +25,7 @@ use arrow_flight::flight_service_client::FlightServiceClient;
 use arrow_flight::FlightData;
 use async_channel::Receiver;
 use databend_common_base::base::GlobalInstance;
+use databend_common_base::base::RetryPolicy;
 use databend_common_base::runtime::GlobalIORuntime;
 use databend_common_base::runtime::Thread;
 use databend_common_base::runtime::TrySpawn;
@@ -33,6 +34,7 @@ use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_grpc::ConnectionFactory;
+use databend_common_grpc::GrpcConnectionError;
 use databend_common_pipeline_core::basic_callback;
 use databend_common_pipeline_core::ExecutionInfo;
 use databend_common_sql::executor::PhysicalPlan;
@@ -75,6 +77,9 @@ use crate::servers::flight::FlightSender;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
 
+/// Retries of connecting to another node before the query fails.
+const FLIGHT_CONNECT_RETRY_TIMES: usize = 3;
+
 pub struct DataExchangeManager {
     queries_coordinator: ReentrantMutex<SyncUnsafeCell<HashMap<String, QueryCoordinator>>>,
 }
@@ -269,19 +274,40 @@ impl DataExchangeManager {
         let config = GlobalConfig::instance();
         let address = address.to_string();
         let task = async move {
-            match config.tls_query_cli_enabled() {
-                true => Ok(FlightClient::new(FlightServiceClient::new(
-                    ConnectionFactory::create_rpc_channel(
-                        address.to_owned(),
-                        None,
-                        Some(config.query.to_rpc_client_tls_config()),
-                    )
-                    .await?,
-                ))),
-                false => Ok(FlightClient::new(FlightServiceClient::new(
-                    ConnectionFactory::create_rpc_channel(address.to_owned(), None, None).await?,
-                ))),
-            }
+            let tls_config = match config.tls_query_cli_enabled() {
+                true => Some(config.query.to_rpc_client_tls_config()),
+                false => None,
+            };
+
+            let connect = || {
+                let address = address.clone();
+                let tls_config = tls_config.clone();
+                async move {
+                    let channel =
+                        ConnectionFactory::create_rpc_channel(address, None, tls_config).await?;
+                    Ok::<_, GrpcConnectionError>(FlightClient::new(FlightServiceClient::new(
+                        channel,
+                    )))
+                }
+            };
+
+            // Only connecting may fail transiently, an invalid address or tls config never recovers.
+            let client = RetryPolicy::default()
+                .with_max_attempts(FLIGHT_CONNECT_RETRY_TIMES)
+                .retry(
+                    connect,
+                    |err: &GrpcConnectionError| {
+                        matches!(err, GrpcConnectionError::CannotConnect { .. })
+                    },
+                    |err, dur| {
+                        warn!(
+                            "Failed to connect to {}, retry after {:?}: {:?}",
+                            address, dur, err
+                        );
+                    },
+                )
+                .await?;
+            Ok::<_, ErrorCode>(client)
         };
         if use_current_rt {
             task.await
@@ -26,6 +26,7 @@ use futures_util::future::select;
 use futures_util::future::Either;
 
 use crate::servers::flight::v1::packets::DataPacket;
+use crate::servers::flight::v1::packets::ProgressInfo;
 use crate::servers::flight::FlightExchange;
 use crate::sessions::QueryContext;
 
@@ -44,7 +45,7 @@ impl StatisticsReceiver {
         let mut exchange_handler = Vec::with_capacity(statistics_exchanges.len());
         let runtime = Runtime::with_worker_threads(2, Some(String::from("StatisticsReceiver")))?;
 
-        for (_source, exchange) in statistics_exchanges.into_iter() {
+        for (source, exchange) in statistics_exchanges.into_iter() {
             let rx = exchange.convert_to_receiver();
             exchange_handler.push(runtime.spawn({
                 let ctx = ctx.clone();
@@ -62,7 +63,7 @@ impl StatisticsReceiver {
                                 return Ok(());
                             }
                             Either::Left((Ok(false), recv)) => {
-                                match StatisticsReceiver::recv_data(&ctx, recv.await) {
+                                match StatisticsReceiver::recv_data(&ctx, &source, recv.await) {
                                     Ok(true) => {
                                         return Ok(());
                                     }
@@ -71,7 +72,11 @@ impl StatisticsReceiver {
                                         return Err(cause);
                                     }
                                     _ => loop {
-                                        match StatisticsReceiver::recv_data(&ctx, rx.recv().await) {
+                                        match StatisticsReceiver::recv_data(
+                                            &ctx,
+                                            &source,
+                                            rx.recv().await,
+                                        ) {
                                             Ok(true) => {
                                                 return Ok(());
                                             }
@@ -86,7 +91,7 @@ impl StatisticsReceiver {
                                 }
                             }
                             Either::Right((res, left)) => {
-                                match StatisticsReceiver::recv_data(&ctx, res) {
+                                match StatisticsReceiver::recv_data(&ctx, &source, res) {
                                     Ok(true) => {
                                         return Ok(());
                                     }
@@ -113,7 +118,11 @@ impl StatisticsReceiver {
         })
     }
 
-    fn recv_data(ctx: &Arc<QueryContext>, recv_data: Result<Option<DataPacket>>) -> Result<bool> {
+    fn recv_data(
+        ctx: &Arc<QueryContext>,
+        source: &str,
+        recv_data: Result<Option<DataPacket>>,
+    ) -> Result<bool> {
         match recv_data {
             Ok(None) => Ok(true),
             Err(transport_error) => Err(transport_error),
@@ -122,6 +131,9 @@ impl StatisticsReceiver {
             Ok(Some(DataPacket::FragmentData(_))) => unreachable!(),
             Ok(Some(DataPacket::SerializeProgress(progress))) => {
                 for progress_info in progress {
+                    if let ProgressInfo::ScanProgress(values) = &progress_info {
+                        ctx.add_remote_scan_progress(source, values);
+                    }
                     progress_info.inc(ctx);
                 }
 
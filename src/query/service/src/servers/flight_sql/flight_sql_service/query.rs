@@ -77,9 +77,39 @@ static DATA_HEADER_PROGRESS: LazyLock<Bytes> = LazyLock::new(|| {
     Bytes::copy_from_slice(fbb.finished_data())
 });
 
+/// Field metadata keys defined by the Flight SQL protocol for result set columns.
+const FLIGHT_SQL_DB_SCHEMA_NAME: &str = "ARROW:FLIGHT:SQL:DB_SCHEMA_NAME";
+const FLIGHT_SQL_TABLE_NAME: &str = "ARROW:FLIGHT:SQL:TABLE_NAME";
+/// Flight SQL has no standard key for the base column name.
+const FLIGHT_SQL_COLUMN_NAME: &str = "DATABEND:ORIGIN_COLUMN_NAME";
+
 impl FlightSqlServiceImpl {
-    pub(crate) fn schema_to_flight_data(data_schema: DataSchema) -> FlightData {
-        let arrow_schema = ArrowSchema::from(&data_schema);
+    /// Convert the result schema of plan to arrow, tagging each field with the
+    /// base table column it is read from.
+    pub(crate) fn plan_arrow_schema(plan: &Plan, data_schema: &DataSchema) -> ArrowSchema {
+        let arrow_schema = ArrowSchema::from(data_schema);
+        let mut column_origins = plan.column_origins().into_iter();
+        let fields = arrow_schema
+            .fields()
+            .iter()
+            .map(|field| {
+                let field = field.as_ref().clone();
+                match column_origins.next().flatten() {
+                    Some(origin) => {
+                        let mut metadata = field.metadata().clone();
+                        metadata.insert(FLIGHT_SQL_DB_SCHEMA_NAME.to_string(), origin.database);
+                        metadata.insert(FLIGHT_SQL_TABLE_NAME.to_string(), origin.table);
+                        metadata.insert(FLIGHT_SQL_COLUMN_NAME.to_string(), origin.column);
+                        field.with_metadata(metadata)
+                    }
+                    None => field,
+                }
+            })
+            .collect::<Vec<_>>();
+        ArrowSchema::new_with_metadata(fields, arrow_schema.metadata().clone())
+    }
+
+    pub(crate) fn schema_to_flight_data(arrow_schema: ArrowSchema) -> FlightData {
         let options = IpcWriteOptions::default();
         SchemaAsIpc::new(&arrow_schema, &options).into()
     }
@@ -177,7 +207,10 @@ impl FlightSqlServiceImpl {
         let is_finished_clone = is_finished.clone();
         let (sender, receiver) = tokio::sync::mpsc::channel(2);
         let _ = sender
-            .send(Ok(Self::schema_to_flight_data((*data_schema).clone())))
+            .send(Ok(Self::schema_to_flight_data(Self::plan_arrow_schema(
+                plan,
+                &data_schema,
+            ))))
             .await;
 
         let s1 = sender.clone();
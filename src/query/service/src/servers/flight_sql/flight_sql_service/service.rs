@@ -230,7 +230,8 @@ impl FlightSqlService for FlightSqlServiceImpl {
         info!("get_flight_info_prepared_statement with handle={handle}");
 
         let handle_plan_ref = self.statements.get(&handle).unwrap();
-        let schema = handle_plan_ref.value().0.schema().as_ref().into();
+        let plan = &handle_plan_ref.value().0;
+        let schema = Self::plan_arrow_schema(plan, &plan.schema());
         let loc = Location {
             uri: "grpc+tcp://127.0.0.1".to_string(),
         };
@@ -599,7 +600,7 @@ impl FlightSqlService for FlightSqlServiceImpl {
             "do_action_create_prepared_statement with handler={handle}, query={:?}, return schema={data_schema:?}",
             query.query
         );
-        let schema = (&*data_schema).into();
+        let schema = Self::plan_arrow_schema(&plan.0, &data_schema);
         self.statements.insert(handle, plan);
         let message = SchemaAsIpc::new(&schema, &IpcWriteOptions::default())
             .try_into()
@@ -42,6 +42,7 @@ use poem::get;
 use poem::post;
 use poem::web::Query;
 use poem::web::WithContentType;
+use poem::web::WithHeader;
 use poem::Body;
 use poem::Endpoint;
 use poem::EndpointExt;
@@ -59,6 +60,8 @@ use crate::sessions::QueryContext;
 use crate::sessions::SessionType;
 use crate::sessions::TableContext;
 
+const HEADER_CLICKHOUSE_TIMEZONE: &str = "X-ClickHouse-Timezone";
+
 // accept all clickhouse params, so they do not go to settings.
 #[derive(Serialize, Deserialize, Debug)]
 pub struct StatementHandlerParams {
@@ -106,8 +109,10 @@ async fn execute(
     format: ClickhouseFormatType,
     params: StatementHandlerParams,
     handle: Option<JoinHandle<()>>,
-) -> Result<WithContentType<Body>> {
+) -> Result<WithHeader<WithContentType<Body>>> {
     let format_typ = format.typ.clone();
+    // ClickHouse clients interpret DateTime values in the server timezone reported by this header.
+    let timezone = ctx.get_settings().get_timezone()?;
 
     // the reason of spawning new task to execute the interpreter:
     // (FIXME describe this in a more concise way)
@@ -209,10 +214,9 @@ async fn execute(
 
                 let stream =
                     stream.map_err(|err| std::io::Error::new(std::io::ErrorKind::Other, err));
-                Ok(
-                    Body::from_bytes_stream(stream)
-                        .with_content_type(format_typ.get_content_type()),
-                )
+                Ok(Body::from_bytes_stream(stream)
+                    .with_content_type(format_typ.get_content_type())
+                    .with_header(HEADER_CLICKHOUSE_TIMEZONE, timezone))
             }
         },
         None,
@@ -231,7 +235,7 @@ pub async fn clickhouse_handler_get(
     ctx: &HttpQueryContext,
     Query(params): Query<StatementHandlerParams>,
     headers: &HeaderMap,
-) -> PoemResult<WithContentType<Body>> {
+) -> PoemResult<WithHeader<WithContentType<Body>>> {
     let root = Span::root(func_path!(), SpanContext::random());
     async {
         let session = ctx.upgrade_session(SessionType::ClickHouseHttpHandler)?;
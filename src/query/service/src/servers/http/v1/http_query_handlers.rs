@@ -20,6 +20,7 @@ use databend_common_base::runtime::drop_guard;
 use databend_common_exception::ErrorCode;
 use databend_common_expression::DataSchemaRef;
 use databend_common_metrics::http::metrics_incr_http_response_errors_count;
+use databend_common_sql::ColumnOrigin;
 use fastrace::func_path;
 use fastrace::prelude::*;
 use highway::HighwayHash;
@@ -30,6 +31,7 @@ use log::warn;
 use poem::error::Error as PoemError;
 use poem::error::Result as PoemResult;
 use poem::get;
+use poem::middleware::Compression;
 use poem::middleware::CookieJarManager;
 use poem::post;
 use poem::put;
@@ -92,16 +94,24 @@ pub struct QueryStats {
 pub struct QueryResponseField {
     name: String,
     r#type: String,
+    /// Base table column of this result column, omitted for computed columns.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    origin: Option<ColumnOrigin>,
 }
 
 impl QueryResponseField {
-    pub fn from_schema(schema: DataSchemaRef) -> Vec<Self> {
+    pub fn from_schema(
+        schema: DataSchemaRef,
+        column_origins: Vec<Option<ColumnOrigin>>,
+    ) -> Vec<Self> {
+        let mut column_origins = column_origins.into_iter();
         schema
             .fields()
             .iter()
             .map(|f| Self {
                 name: f.name().to_string(),
                 r#type: f.data_type().wrapped_display(),
+                origin: column_origins.next().flatten(),
             })
             .collect()
     }
@@ -485,7 +495,9 @@ pub fn query_route() -> Route {
             endpoint
                 .with(MetricsMiddleware::new(path))
                 .with(HTTPSessionMiddleware::create(HttpHandlerKind::Query, kind))
-                .with(CookieJarManager::new()),
+                .with(CookieJarManager::new())
+                // compress the response payload if the client sends `Accept-Encoding`
+                .with(Compression::new()),
         );
     }
     route
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::SystemTime;
@@ -78,6 +79,15 @@ pub struct Progresses {
     pub write_progress: ProgressValues,
     pub result_progress: ProgressValues,
     pub total_scan: ProgressValues,
+    /// rows/bytes spilled by join, aggregate, group by and window partition.
+    #[serde(default)]
+    pub spill_progress: ProgressValues,
+    /// scan progress of each node of a distributed query, keyed by node id.
+    #[serde(default)]
+    pub node_scan_progress: BTreeMap<String, ProgressValues>,
+    /// plans of the coordinator pipeline with processors running right now.
+    #[serde(default)]
+    pub current_operators: Vec<String>,
 }
 
 impl Progresses {
@@ -87,6 +97,19 @@ impl Progresses {
             write_progress: ctx.get_write_progress_value(),
             result_progress: ctx.get_result_progress_value(),
             total_scan: ctx.get_total_scan_value(),
+            spill_progress: [
+                ctx.get_join_spill_progress_value(),
+                ctx.get_aggregate_spill_progress_value(),
+                ctx.get_group_by_spill_progress_value(),
+                ctx.get_window_partition_spill_progress_value(),
+            ]
+            .into_iter()
+            .fold(ProgressValues::default(), |acc, v| ProgressValues {
+                rows: acc.rows + v.rows,
+                bytes: acc.bytes + v.bytes,
+            }),
+            node_scan_progress: ctx.get_node_scan_progress(),
+            current_operators: ctx.get_running_operators(),
         }
     }
 }
@@ -360,7 +383,7 @@ impl ExecuteState {
         let has_result_set = plan.has_result_set();
         let schema = if has_result_set {
             // check has_result_set first for safety
-            QueryResponseField::from_schema(plan.schema())
+            QueryResponseField::from_schema(plan.schema(), plan.column_origins())
         } else {
             vec![]
         };
@@ -16,6 +16,9 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use std::sync::LazyLock;
 
+use chrono::Offset;
+use chrono::Utc;
+use chrono_tz::Tz;
 use databend_common_expression::types::StringType;
 use databend_common_expression::utils::FromData;
 use databend_common_expression::DataBlock;
@@ -30,11 +33,20 @@ use regex::Regex;
 use crate::servers::federated_helper::FederatedHelper;
 use crate::servers::federated_helper::LazyBlockFunc;
 
-pub struct MySQLFederated {}
+pub struct MySQLFederated {
+    /// The session timezone, reported as `@@time_zone` so that drivers convert
+    /// timestamps the same way the server formats them.
+    timezone: Tz,
+}
 
 impl MySQLFederated {
     pub fn create() -> Self {
-        MySQLFederated {}
+        MySQLFederated { timezone: Tz::UTC }
+    }
+
+    pub fn with_timezone(mut self, timezone: Tz) -> Self {
+        self.timezone = timezone;
+        self
     }
 
     // Build block for select function.
@@ -64,9 +76,28 @@ impl MySQLFederated {
         Some((schema, block))
     }
 
+    // SELECT TIMEDIFF(NOW(), UTC_TIMESTAMP())
+    // The offset of the session timezone, such as `08:00:00` or `-05:00:00`.
+    fn select_timediff_block(&self, _query: &str) -> Option<(TableSchemaRef, DataBlock)> {
+        let offset = Utc::now()
+            .with_timezone(&self.timezone)
+            .offset()
+            .fix()
+            .local_minus_utc();
+        let sign = if offset < 0 { "-" } else { "" };
+        let offset = offset.unsigned_abs();
+        let value = format!(
+            "{sign}{:02}:{:02}:{:02}",
+            offset / 3600,
+            offset % 3600 / 60,
+            offset % 60
+        );
+        Self::select_function_block("TIMEDIFF(NOW(), UTC_TIMESTAMP())", &value)
+    }
+
     // SELECT @@aa, @@bb as cc, @dd...
     // Block is built by the variables.
-    fn select_variable_data_block(query: &str) -> Option<(TableSchemaRef, DataBlock)> {
+    fn select_variable_data_block(&self, query: &str) -> Option<(TableSchemaRef, DataBlock)> {
         let mut default_map = HashMap::new();
         // DBeaver.
         default_map.insert("tx_isolation", "REPEATABLE-READ");
@@ -74,7 +105,7 @@ impl MySQLFederated {
         default_map.insert("transaction_isolation", "REPEATABLE-READ");
         default_map.insert("session.transaction_isolation", "REPEATABLE-READ");
         default_map.insert("session.transaction_read_only", "0");
-        default_map.insert("time_zone", "UTC");
+        default_map.insert("time_zone", self.timezone.name());
         default_map.insert("system_time_zone", "UTC");
         // 128M
         default_map.insert("max_allowed_packet", "134217728");
@@ -124,7 +155,7 @@ impl MySQLFederated {
 
     // Check SELECT @@variable, @@variable
     fn federated_select_variable_check(&self, query: &str) -> Option<(TableSchemaRef, DataBlock)> {
-        static SELECT_VARIABLES_LAZY_RULES: LazyLock<Vec<(Regex, LazyBlockFunc)>> =
+        static SELECT_VARIABLES_LAZY_RULES: LazyLock<Vec<(Regex, LazyBlockFunc<MySQLFederated>)>> =
             LazyLock::new(|| {
                 vec![
                     (
@@ -135,10 +166,16 @@ impl MySQLFederated {
                         Regex::new("(?i)^(/\\* mysql-connector-java(.*))").unwrap(),
                         MySQLFederated::select_variable_data_block,
                     ),
+                    (
+                        // SELECT TIMEDIFF(NOW(), UTC_TIMESTAMP());
+                        Regex::new("(?i)^(SELECT TIMEDIFF\\(NOW\\(\\), UTC_TIMESTAMP\\(\\)\\))")
+                            .unwrap(),
+                        MySQLFederated::select_timediff_block,
+                    ),
                 ]
             });
 
-        FederatedHelper::lazy_block_match_rule(query, &SELECT_VARIABLES_LAZY_RULES)
+        FederatedHelper::lazy_block_match_rule(self, query, &SELECT_VARIABLES_LAZY_RULES)
     }
 
     // Check SHOW VARIABLES LIKE.
@@ -195,11 +232,6 @@ impl MySQLFederated {
             // https://github.com/datafuselabs/databend/issues/5853
             (Regex::new("(?i)^(SHOW COLLATION)").unwrap(), None),
             (Regex::new("(?i)^(SHOW CHARSET)").unwrap(), None),
-            (
-                // SELECT TIMEDIFF(NOW(), UTC_TIMESTAMP());
-                Regex::new("(?i)^(SELECT TIMEDIFF\\(NOW\\(\\), UTC_TIMESTAMP\\(\\)\\))").unwrap(),
-                MySQLFederated::select_function_block("TIMEDIFF(NOW(), UTC_TIMESTAMP())", "00:00:00"),
-            ),
             // mysqldump.
             (Regex::new("(?i)^(SET SESSION(.*))").unwrap(), None),
             (Regex::new("(?i)^(SET SQL_QUOTE_SHOW_CREATE(.*))").unwrap(), None),
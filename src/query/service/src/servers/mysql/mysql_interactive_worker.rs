@@ -12,10 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 use std::time::Instant;
 
+use chrono_tz::Tz;
+use databend_common_ast::ast::QuotedString;
+use databend_common_ast::parser::token::TokenKind;
+use databend_common_ast::parser::tokenize_sql;
 use databend_common_base::base::convert_byte_size;
 use databend_common_base::base::convert_number_size;
 use databend_common_base::base::tokio::io::AsyncWrite;
@@ -39,11 +44,16 @@ use futures_util::StreamExt;
 use log::error;
 use log::info;
 use opensrv_mysql::AsyncMysqlShim;
+use opensrv_mysql::Column;
+use opensrv_mysql::ColumnFlags;
+use opensrv_mysql::ColumnType;
 use opensrv_mysql::ErrorKind;
 use opensrv_mysql::InitWriter;
 use opensrv_mysql::ParamParser;
+use opensrv_mysql::ParamValue;
 use opensrv_mysql::QueryResultWriter;
 use opensrv_mysql::StatementMetaWriter;
+use opensrv_mysql::ValueInner;
 use rand::RngCore;
 use uuid::Uuid;
 
@@ -61,8 +71,18 @@ use crate::sessions::Session;
 use crate::sessions::TableContext;
 use crate::stream::DataBlockStream;
 
+/// A statement prepared by COM_STMT_PREPARE, the parameters are bound by replacing the
+/// placeholders with literals on each COM_STMT_EXECUTE.
+struct PreparedStatement {
+    query: String,
+    /// Byte ranges of the `?` placeholders in `query`, in order.
+    placeholders: Vec<(usize, usize)>,
+}
+
 struct InteractiveWorkerBase {
     session: Arc<Session>,
+    prepared_statements: HashMap<u32, PreparedStatement>,
+    next_statement_id: u32,
 }
 
 pub struct InteractiveWorker {
@@ -174,7 +194,15 @@ impl<W: AsyncWrite + Send + Sync + Unpin> AsyncMysqlShim<W> for InteractiveWorke
             ));
         }
 
-        self.base.do_execute(id, param, writer).await
+        match self.base.bind_statement(id, param) {
+            Ok(query) => self.run_query(&query, writer, true).await,
+            Err(cause) => {
+                writer
+                    .error(ErrorKind::ER_UNKNOWN_ERROR, cause.to_string().as_bytes())
+                    .await?;
+                Ok(())
+            }
+        }
     }
 
     /// https://dev.mysql.com/doc/internals/en/com-stmt-close.html
@@ -190,55 +218,7 @@ impl<W: AsyncWrite + Send + Sync + Unpin> AsyncMysqlShim<W> for InteractiveWorke
         query: &'a str,
         writer: QueryResultWriter<'a, W>,
     ) -> Result<()> {
-        let query_id = Uuid::new_v4().to_string();
-        let root = Span::root(func_path!(), SpanContext::random())
-            .with_properties(|| self.base.session.to_fastrace_properties());
-
-        let mut tracking_payload = ThreadTracker::new_tracking_payload();
-        tracking_payload.query_id = Some(query_id.clone());
-        let _guard = ThreadTracker::tracking(tracking_payload);
-
-        ThreadTracker::tracking_future(async {
-            if self.base.session.is_aborting() {
-                writer
-                    .error(
-                        ErrorKind::ER_ABORTING_CONNECTION,
-                        "Aborting this connection. because we are try aborting server.".as_bytes(),
-                    )
-                    .await?;
-
-                return Err(ErrorCode::AbortedSession(
-                    "Aborting this connection. because we are try aborting server.",
-                ));
-            }
-
-            let mut writer = DFQueryResultWriter::create(writer, self.base.session.clone());
-            if !self.keep_alive_task_started {
-                self.start_keep_alive().await
-            }
-
-            let instant = Instant::now();
-            let query_result = self
-                .base
-                .do_query(query_id, query)
-                .await
-                .map_err(|err| err.display_with_sql(query));
-
-            let format = self.base.session.get_format_settings();
-
-            let mut write_result = writer.write(query_result, &format).await;
-
-            if let Err(cause) = write_result {
-                self.base.session.txn_mgr().lock().set_fail();
-                let suffix = format!("(while in query {})", query);
-                write_result = Err(cause.add_message_back(suffix));
-            }
-            observe_mysql_process_request_duration(instant.elapsed());
-
-            write_result
-        })
-        .in_span(root)
-        .await
+        self.run_query(query, writer, false).await
     }
 
     #[async_backtrace::framed]
@@ -297,36 +277,91 @@ impl InteractiveWorkerBase {
     #[async_backtrace::framed]
     async fn do_prepare<W: AsyncWrite + Unpin>(
         &mut self,
-        _: &str,
+        query: &str,
         writer: StatementMetaWriter<'_, W>,
     ) -> Result<()> {
-        writer
-            .error(
-                ErrorKind::ER_UNKNOWN_ERROR,
-                "Prepare is not support in Databend.".as_bytes(),
-            )
-            .await?;
+        let placeholders = match tokenize_sql(query) {
+            Ok(tokens) => tokens
+                .iter()
+                .filter(|token| token.kind == TokenKind::Placeholder)
+                .map(|token| (token.span.start(), token.span.end()))
+                .collect::<Vec<_>>(),
+            Err(cause) => {
+                let cause = ErrorCode::from(cause);
+                writer
+                    .error(ErrorKind::ER_PARSE_ERROR, cause.to_string().as_bytes())
+                    .await?;
+                return Ok(());
+            }
+        };
+        if placeholders.len() > u16::MAX as usize {
+            writer
+                .error(
+                    ErrorKind::ER_PS_MANY_PARAM,
+                    "Prepared statement contains too many placeholders".as_bytes(),
+                )
+                .await?;
+            return Ok(());
+        }
+
+        // The parameter types are not inferred from the statement, clients send the
+        // type of each parameter on execute anyway. The result set columns are only
+        // known after the statement is planned with its parameters, they are described
+        // by the result set of each execute.
+        let params = placeholders
+            .iter()
+            .map(|_| Column {
+                table: String::new(),
+                column: "?".to_string(),
+                coltype: ColumnType::MYSQL_TYPE_VAR_STRING,
+                colflags: ColumnFlags::empty(),
+            })
+            .collect::<Vec<_>>();
+        let columns: Vec<Column> = vec![];
+
+        self.next_statement_id += 1;
+        let id = self.next_statement_id;
+        self.prepared_statements.insert(id, PreparedStatement {
+            query: query.to_string(),
+            placeholders,
+        });
+        writer.reply(id, &params, &columns).await?;
         Ok(())
     }
 
-    #[async_backtrace::framed]
-    async fn do_execute<W: AsyncWrite + Unpin>(
-        &mut self,
-        _: u32,
-        _: ParamParser<'_>,
-        writer: QueryResultWriter<'_, W>,
-    ) -> Result<()> {
-        writer
-            .error(
-                ErrorKind::ER_UNKNOWN_ERROR,
-                "Execute is not support in Databend.".as_bytes(),
-            )
-            .await?;
-        Ok(())
+    /// Build the query of a prepared statement with its parameters inlined as literals.
+    fn bind_statement(&self, id: u32, params: ParamParser<'_>) -> Result<String> {
+        let Some(statement) = self.prepared_statements.get(&id) else {
+            return Err(ErrorCode::BadArguments(format!(
+                "Unknown prepared statement handler ({}) given to EXECUTE",
+                id
+            )));
+        };
+
+        let params = params.into_iter().collect::<Vec<_>>();
+        if params.len() != statement.placeholders.len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Prepared statement expects {} parameters, but got {}",
+                statement.placeholders.len(),
+                params.len()
+            )));
+        }
+
+        let mut query = String::with_capacity(statement.query.len());
+        let mut last = 0;
+        for ((start, end), param) in statement.placeholders.iter().zip(params) {
+            query.push_str(&statement.query[last..*start]);
+            query.push_str(&param_to_sql(param)?);
+            last = *end;
+        }
+        query.push_str(&statement.query[last..]);
+        Ok(query)
     }
 
     #[async_backtrace::framed]
-    async fn do_close(&mut self, _: u32) {}
+    async fn do_close(&mut self, id: u32) {
+        self.prepared_statements.remove(&id);
+    }
 
     // Check the query is a federated or driver setup command.
     // Here we fake some values for the command which Databend not supported.
@@ -340,7 +375,14 @@ impl InteractiveWorkerBase {
         {
             return None;
         }
-        let federated = MySQLFederated::create();
+        let timezone = self
+            .session
+            .get_settings()
+            .get_timezone()
+            .ok()
+            .and_then(|tz| tz.parse::<Tz>().ok())
+            .unwrap_or(Tz::UTC);
+        let federated = MySQLFederated::create().with_timezone(timezone);
         federated.check(query)
     }
 
@@ -390,7 +432,8 @@ impl InteractiveWorkerBase {
                         has_result_set,
                         schema,
                         query.to_string(),
-                    ),
+                    )
+                    .with_column_origins(plan.column_origins()),
                     Some(format),
                 ))
             }
@@ -461,6 +504,69 @@ impl InteractiveWorkerBase {
 }
 
 impl InteractiveWorker {
+    /// Run a text query, or a prepared statement with its parameters bound if `binary`
+    /// is set, whose rows are then sent in the binary protocol.
+    #[async_backtrace::framed]
+    async fn run_query<W: AsyncWrite + Send + Sync + Unpin>(
+        &mut self,
+        query: &str,
+        writer: QueryResultWriter<'_, W>,
+        binary: bool,
+    ) -> Result<()> {
+        let query_id = Uuid::new_v4().to_string();
+        let root = Span::root(func_path!(), SpanContext::random())
+            .with_properties(|| self.base.session.to_fastrace_properties());
+
+        let mut tracking_payload = ThreadTracker::new_tracking_payload();
+        tracking_payload.query_id = Some(query_id.clone());
+        let _guard = ThreadTracker::tracking(tracking_payload);
+
+        ThreadTracker::tracking_future(async {
+            if self.base.session.is_aborting() {
+                writer
+                    .error(
+                        ErrorKind::ER_ABORTING_CONNECTION,
+                        "Aborting this connection. because we are try aborting server.".as_bytes(),
+                    )
+                    .await?;
+
+                return Err(ErrorCode::AbortedSession(
+                    "Aborting this connection. because we are try aborting server.",
+                ));
+            }
+
+            let mut writer = DFQueryResultWriter::create(writer, self.base.session.clone());
+            if binary {
+                writer = writer.with_binary_protocol();
+            }
+            if !self.keep_alive_task_started {
+                self.start_keep_alive().await
+            }
+
+            let instant = Instant::now();
+            let query_result = self
+                .base
+                .do_query(query_id, query)
+                .await
+                .map_err(|err| err.display_with_sql(query));
+
+            let format = self.base.session.get_format_settings();
+
+            let mut write_result = writer.write(query_result, &format).await;
+
+            if let Err(cause) = write_result {
+                self.base.session.txn_mgr().lock().set_fail();
+                let suffix = format!("(while in query {})", query);
+                write_result = Err(cause.add_message_back(suffix));
+            }
+            observe_mysql_process_request_duration(instant.elapsed());
+
+            write_result
+        })
+        .in_span(root)
+        .await
+    }
+
     pub fn create(session: Arc<Session>, client_addr: String) -> InteractiveWorker {
         let mut bs = vec![0u8; 20];
         let mut rng = rand::thread_rng();
@@ -475,7 +581,11 @@ impl InteractiveWorker {
         }
 
         InteractiveWorker {
-            base: InteractiveWorkerBase { session },
+            base: InteractiveWorkerBase {
+                session,
+                prepared_statements: HashMap::new(),
+                next_statement_id: 0,
+            },
             salt: scramble,
             version: format!("{}-{}", MYSQL_VERSION, *DATABEND_COMMIT_VERSION),
             client_addr,
@@ -540,3 +650,85 @@ impl ProgressReporter for ContextProgressReporter {
         progress.rows as u64
     }
 }
+
+/// Render a parameter of COM_STMT_EXECUTE as a SQL literal.
+fn param_to_sql(param: ParamValue<'_>) -> Result<String> {
+    Ok(match param.value.into_inner() {
+        ValueInner::NULL => "NULL".to_string(),
+        ValueInner::Int(v) => v.to_string(),
+        ValueInner::UInt(v) => v.to_string(),
+        ValueInner::Double(v) if v.is_finite() => format!("{:?}", v),
+        ValueInner::Double(v) => format!("'{}'::DOUBLE", v),
+        ValueInner::Bytes(bytes) => match param.coltype {
+            ColumnType::MYSQL_TYPE_DECIMAL | ColumnType::MYSQL_TYPE_NEWDECIMAL => {
+                let text = std::str::from_utf8(bytes).unwrap_or_default();
+                if text.is_empty()
+                    || !text
+                        .chars()
+                        .all(|c| c.is_ascii_digit() || matches!(c, '.' | '-' | '+' | 'e' | 'E'))
+                {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Invalid decimal parameter: {:?}",
+                        String::from_utf8_lossy(bytes)
+                    )));
+                }
+                text.to_string()
+            }
+            _ => match std::str::from_utf8(bytes) {
+                Ok(text) => QuotedString(text, '\'').to_string(),
+                Err(_) => format!("FROM_HEX('{}')", hex::encode(bytes)),
+            },
+        },
+        ValueInner::Date(bytes) | ValueInner::Datetime(bytes) => {
+            format!("'{}'", decode_binary_datetime(bytes)?)
+        }
+        ValueInner::Time(bytes) => format!("'{}'", decode_binary_time(bytes)?),
+    })
+}
+
+/// A DATE, DATETIME or TIMESTAMP parameter in the binary protocol is 0, 4, 7 or 11 bytes:
+/// year (2 bytes), month, day, hour, minute, second and microseconds (4 bytes).
+fn decode_binary_datetime(bytes: &[u8]) -> Result<String> {
+    let (year, month, day) = match bytes.len() {
+        0 => (0, 0, 0),
+        4 | 7 | 11 => (u16::from_le_bytes([bytes[0], bytes[1]]), bytes[2], bytes[3]),
+        len => {
+            return Err(ErrorCode::BadArguments(format!(
+                "Invalid length {} of a datetime parameter",
+                len
+            )));
+        }
+    };
+    let mut text = format!("{:04}-{:02}-{:02}", year, month, day);
+    if bytes.len() >= 7 {
+        text.push_str(&format!(" {:02}:{:02}:{:02}", bytes[4], bytes[5], bytes[6]));
+    }
+    if bytes.len() == 11 {
+        let micros = u32::from_le_bytes([bytes[7], bytes[8], bytes[9], bytes[10]]);
+        text.push_str(&format!(".{:06}", micros));
+    }
+    Ok(text)
+}
+
+/// A TIME parameter in the binary protocol is 0, 8 or 12 bytes: sign, days (4 bytes),
+/// hour, minute, second and microseconds (4 bytes).
+fn decode_binary_time(bytes: &[u8]) -> Result<String> {
+    if bytes.is_empty() {
+        return Ok("00:00:00".to_string());
+    }
+    if bytes.len() != 8 && bytes.len() != 12 {
+        return Err(ErrorCode::BadArguments(format!(
+            "Invalid length {} of a time parameter",
+            bytes.len()
+        )));
+    }
+    let sign = if bytes[0] == 1 { "-" } else { "" };
+    let days = u32::from_le_bytes([bytes[1], bytes[2], bytes[3], bytes[4]]);
+    let hours = days as u64 * 24 + bytes[5] as u64;
+    let mut text = format!("{}{:02}:{:02}:{:02}", sign, hours, bytes[6], bytes[7]);
+    if bytes.len() == 12 {
+        let micros = u32::from_le_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]);
+        text.push_str(&format!(".{:06}", micros));
+    }
+    Ok(text)
+}
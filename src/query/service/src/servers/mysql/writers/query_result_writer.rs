@@ -17,6 +17,7 @@ use std::sync::Arc;
 use databend_common_base::base::tokio::io::AsyncWrite;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::date_helper::DateConverter;
 use databend_common_expression::types::number::NumberScalar;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::NumberDataType;
@@ -27,6 +28,7 @@ use databend_common_expression::ScalarRef;
 use databend_common_expression::SendableDataBlockStream;
 use databend_common_formats::field_encoder::FieldEncoderValues;
 use databend_common_io::prelude::FormatSettings;
+use databend_common_sql::ColumnOrigin;
 use futures_util::StreamExt;
 use log::error;
 use opensrv_mysql::*;
@@ -47,6 +49,7 @@ pub struct QueryResult {
     extra_info: Option<Box<dyn ProgressReporter + Send>>,
     has_result_set: bool,
     schema: DataSchemaRef,
+    column_origins: Vec<Option<ColumnOrigin>>,
     sql: String,
 }
 
@@ -63,14 +66,23 @@ impl QueryResult {
             extra_info,
             has_result_set,
             schema,
+            column_origins: vec![],
             sql,
         }
     }
+
+    /// Attach the base table origin of each result column, reported to clients in
+    /// the column definition packets.
+    pub fn with_column_origins(mut self, column_origins: Vec<Option<ColumnOrigin>>) -> Self {
+        self.column_origins = column_origins;
+        self
+    }
 }
 
 pub struct DFQueryResultWriter<'a, W: AsyncWrite + Send + Unpin> {
     inner: Option<QueryResultWriter<'a, W>>,
     session: Arc<Session>,
+    binary: bool,
 }
 
 fn write_field<W: AsyncWrite + Unpin>(
@@ -94,9 +106,18 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
         DFQueryResultWriter::<'a, W> {
             inner: Some(inner),
             session,
+            binary: false,
         }
     }
 
+    /// The rows are sent as binary protocol rows, used for the results of prepared
+    /// statements. Values whose text form is not valid in the binary protocol, such
+    /// as floats, dates and timestamps, are then written natively.
+    pub fn with_binary_protocol(mut self) -> Self {
+        self.binary = true;
+        self
+    }
+
     #[async_backtrace::framed]
     pub async fn write(
         &mut self,
@@ -198,21 +219,56 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
             }
         }
 
-        fn make_column_from_field(field: &DataField) -> Result<Column> {
+        fn make_column_from_field(
+            field: &DataField,
+            origin: Option<&ColumnOrigin>,
+            binary: bool,
+        ) -> Result<Column> {
+            // Binary protocol clients decode integers by the width and the signedness
+            // of the column.
+            let colflags = match field.data_type().remove_nullable() {
+                DataType::Number(num_ty) if binary && !num_ty.is_signed() => {
+                    ColumnFlags::UNSIGNED_FLAG
+                }
+                _ => ColumnFlags::empty(),
+            };
+            // TODO: opensrv-mysql 0.7 always writes empty schema, org_table and org_name
+            // in the column definition packet, and `Column` has no fields for them. Set
+            // them from `origin` once the dependency exposes them, until then only the
+            // table name of the origin reaches MySQL clients.
             convert_field_type(field).map(|column_type| Column {
-                table: "".to_string(),
+                table: origin.map(|o| o.table.clone()).unwrap_or_default(),
                 column: field.name().to_string(),
                 coltype: column_type,
-                colflags: ColumnFlags::empty(),
+                colflags,
             })
         }
 
-        fn convert_schema(schema: &DataSchemaRef) -> Result<Vec<Column>> {
-            schema.fields().iter().map(make_column_from_field).collect()
+        fn convert_schema(
+            schema: &DataSchemaRef,
+            column_origins: &[Option<ColumnOrigin>],
+            binary: bool,
+        ) -> Result<Vec<Column>> {
+            schema
+                .fields()
+                .iter()
+                .enumerate()
+                .map(|(i, field)| {
+                    make_column_from_field(
+                        field,
+                        column_origins.get(i).and_then(|o| o.as_ref()),
+                        binary,
+                    )
+                })
+                .collect()
         }
 
-        let _tz = format.timezone;
-        match convert_schema(&query_result.schema) {
+        let tz = format.timezone;
+        match convert_schema(
+            &query_result.schema,
+            &query_result.column_origins,
+            self.binary,
+        ) {
             Err(error) => self.err(&error, dataset_writer).await,
             Ok(columns) => {
                 let mut row_writer = dataset_writer.start(&columns).await?;
@@ -283,6 +339,12 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
                                     NumberScalar::Int64(v) => {
                                         row_writer.write_col(v)?;
                                     }
+                                    NumberScalar::Float32(v) if self.binary => {
+                                        row_writer.write_col(v.0)?;
+                                    }
+                                    NumberScalar::Float64(v) if self.binary => {
+                                        row_writer.write_col(v.0)?;
+                                    }
                                     _ => {
                                         write_field(
                                             &mut row_writer,
@@ -293,6 +355,12 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
                                         )?;
                                     }
                                 },
+                                ScalarRef::Date(v) if self.binary => {
+                                    row_writer.write_col(v.to_date(tz))?;
+                                }
+                                ScalarRef::Timestamp(v) if self.binary => {
+                                    row_writer.write_col(v.to_timestamp(tz).naive_local())?;
+                                }
                                 ScalarRef::Bitmap(_) => {
                                     let bitmap_result = "<bitmap binary>".as_bytes();
                                     row_writer.write_col(bitmap_result)?;
@@ -15,6 +15,7 @@
 use std::any::Any;
 use std::cmp::min;
 use std::collections::hash_map::Entry;
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::collections::VecDeque;
@@ -335,10 +336,50 @@ impl QueryContext {
         self.shared.created_time
     }
 
+    /// The instant after which the query exceeds `max_execute_time_in_seconds`, if limited.
+    pub fn get_query_deadline(&self) -> Result<Option<Instant>> {
+        let max_execute_secs = self.get_settings().get_max_execute_time_in_seconds()?;
+        if max_execute_secs == 0 {
+            return Ok(None);
+        }
+
+        let elapsed = self.shared.created_time.elapsed().unwrap_or_default();
+        let remaining = Duration::from_secs(max_execute_secs).saturating_sub(elapsed);
+        Ok(Some(Instant::now() + remaining))
+    }
+
     pub fn set_finish_time(&self, time: SystemTime) {
         *self.shared.finish_time.write() = Some(time)
     }
 
+    /// Account `size` bytes about to be spilled, failing once `max_spill_bytes_per_query` is exceeded.
+    /// The counter lives in the shared query state, so it only covers the spills of this node.
+    pub fn add_remote_scan_progress(&self, node: &str, values: &ProgressValues) {
+        self.shared.add_remote_scan_progress(node, values)
+    }
+
+    pub fn get_node_scan_progress(&self) -> BTreeMap<String, ProgressValues> {
+        self.shared.get_node_scan_progress()
+    }
+
+    pub fn get_running_operators(&self) -> Vec<String> {
+        self.shared.get_running_operators()
+    }
+
+    pub fn add_spilled_bytes(&self, size: usize) -> Result<()> {
+        let limit = self.get_settings().get_max_spill_bytes_per_query()?;
+        let spilled = self.shared.spilled_bytes.fetch_add(size, Ordering::SeqCst) + size;
+        if limit != 0 && spilled > limit {
+            return Err(ErrorCode::SpillBytesLimitExceeded(format!(
+                "Query {} spilled {} bytes, exceeding max_spill_bytes_per_query {}",
+                self.get_id(),
+                spilled,
+                limit
+            )));
+        }
+        Ok(())
+    }
+
     pub fn evict_table_from_cache(&self, catalog: &str, database: &str, table: &str) -> Result<()> {
         self.shared.evict_table_from_cache(catalog, database, table)
     }
@@ -744,6 +785,7 @@ impl TableContext for QueryContext {
         let enable_strict_datetime_parser = settings.get_enable_strict_datetime_parser()?;
         let query_config = &GlobalConfig::instance().query;
         let random_function_seed = settings.get_random_function_seed()?;
+        let enable_expression_jit = settings.get_enable_expression_jit()?;
 
         Ok(FunctionContext {
             tz,
@@ -763,6 +805,7 @@ impl TableContext for QueryContext {
             enable_dst_hour_fix,
             enable_strict_datetime_parser,
             random_function_seed,
+            enable_expression_jit,
         })
     }
 
@@ -1317,10 +1360,11 @@ impl TableContext for QueryContext {
                     is_select: true,
                     default_values: None,
                     copy_into_location_options: Default::default(),
+                    is_partitioned_unload: false,
                 };
                 OrcTable::try_create(info).await
             }
-            FileFormatParams::NdJson(..) => {
+            FileFormatParams::NdJson(..) | FileFormatParams::Avro(..) => {
                 let schema = Arc::new(TableSchema::new(vec![TableField::new(
                     "_$1", // TODO: this name should be in visible
                     TableDataType::Variant,
@@ -1334,6 +1378,7 @@ impl TableContext for QueryContext {
                     is_select: true,
                     default_values: None,
                     copy_into_location_options: Default::default(),
+                    is_partitioned_unload: false,
                 };
                 StageTable::try_create(info)
             }
@@ -1369,6 +1414,7 @@ impl TableContext for QueryContext {
                     is_select: true,
                     default_values: None,
                     copy_into_location_options: Default::default(),
+                    is_partitioned_unload: false,
                 };
                 StageTable::try_create(info)
             }
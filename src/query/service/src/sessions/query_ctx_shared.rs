@@ -13,8 +13,10 @@
 // limitations under the License.
 
 use std::collections::hash_map::Entry;
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::Weak;
@@ -24,6 +26,7 @@ use std::time::SystemTime;
 use dashmap::DashMap;
 use databend_common_base::base::short_sql;
 use databend_common_base::base::Progress;
+use databend_common_base::base::ProgressValues;
 use databend_common_base::runtime::drop_guard;
 use databend_common_base::runtime::Runtime;
 use databend_common_catalog::catalog::Catalog;
@@ -73,6 +76,9 @@ pub struct QueryContextShared {
     pub(in crate::sessions) total_scan_values: Arc<Progress>,
     /// scan_progress for scan metrics of datablocks (uncompressed)
     pub(in crate::sessions) scan_progress: Arc<Progress>,
+    /// scan progress reported by each remote node of a distributed query, already
+    /// counted in scan_progress.
+    pub(in crate::sessions) remote_scan_progress: Arc<RwLock<BTreeMap<String, ProgressValues>>>,
     /// write_progress for write/commit metrics of datablocks (uncompressed)
     pub(in crate::sessions) write_progress: Arc<Progress>,
     /// Record how many bytes/rows have been spilled in join.
@@ -83,6 +89,8 @@ pub struct QueryContextShared {
     pub(in crate::sessions) group_by_spill_progress: Arc<Progress>,
     /// Record how many bytes/rows have been spilled in window partition
     pub(in crate::sessions) window_partition_spill_progress: Arc<Progress>,
+    /// Record how many bytes have been written by spillers, bounded by max_spill_bytes_per_query.
+    pub(in crate::sessions) spilled_bytes: Arc<AtomicUsize>,
     /// result_progress for metrics of result datablocks (uncompressed)
     pub(in crate::sessions) result_progress: Arc<Progress>,
     pub(in crate::sessions) error: Arc<Mutex<Option<ErrorCode<ContextError>>>>,
@@ -154,6 +162,7 @@ impl QueryContextShared {
             init_query_id: Arc::new(RwLock::new(Uuid::new_v4().to_string())),
             total_scan_values: Arc::new(Progress::create()),
             scan_progress: Arc::new(Progress::create()),
+            remote_scan_progress: Arc::new(RwLock::new(BTreeMap::new())),
             result_progress: Arc::new(Progress::create()),
             write_progress: Arc::new(Progress::create()),
             error: Arc::new(Mutex::new(None)),
@@ -186,6 +195,7 @@ impl QueryContextShared {
             agg_spill_progress: Arc::new(Progress::create()),
             group_by_spill_progress: Arc::new(Progress::create()),
             window_partition_spill_progress: Arc::new(Progress::create()),
+            spilled_bytes: Arc::new(AtomicUsize::new(0)),
             query_cache_metrics: DataCacheMetrics::new(),
             query_profiles: Arc::new(RwLock::new(HashMap::new())),
             runtime_filters: Default::default(),
@@ -616,6 +626,35 @@ impl QueryContextShared {
         self.query_profiles.read().values().cloned().collect()
     }
 
+    pub fn get_running_operators(&self) -> Vec<String> {
+        match self.executor.read().upgrade() {
+            Some(executor) => executor.running_plan_names(),
+            None => vec![],
+        }
+    }
+
+    pub fn add_remote_scan_progress(&self, node: &str, values: &ProgressValues) {
+        let mut remote_scan_progress = self.remote_scan_progress.write();
+        let node_progress = remote_scan_progress.entry(node.to_string()).or_default();
+        node_progress.rows += values.rows;
+        node_progress.bytes += values.bytes;
+    }
+
+    /// Scan progress of each node of the query, the local node is credited with what the
+    /// remote nodes have not reported.
+    pub fn get_node_scan_progress(&self) -> BTreeMap<String, ProgressValues> {
+        let mut node_scan_progress = self.remote_scan_progress.read().clone();
+        let remote_rows = node_scan_progress.values().map(|v| v.rows).sum::<usize>();
+        let remote_bytes = node_scan_progress.values().map(|v| v.bytes).sum::<usize>();
+
+        let scan_progress = self.scan_progress.get_values();
+        node_scan_progress.insert(self.cluster_cache.local_id.clone(), ProgressValues {
+            rows: scan_progress.rows.saturating_sub(remote_rows),
+            bytes: scan_progress.bytes.saturating_sub(remote_bytes),
+        });
+        node_scan_progress
+    }
+
     pub fn add_query_profiles(&self, profiles: &HashMap<u32, PlanProfile>) {
         let mut merged_profiles = self.query_profiles.write();
 
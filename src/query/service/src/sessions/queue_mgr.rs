@@ -80,12 +80,17 @@ pub(crate) struct Inner<Data: QueueData> {
 pub struct QueueManager<Data: QueueData> {
     semaphore: Arc<Semaphore>,
     queue: Mutex<HashMap<Data::Key, Inner<Data>>>,
+    // 0 means the queue length is unlimited.
+    max_queued: usize,
 }
 
 impl<Data: QueueData> QueueManager<Data> {
-    pub fn init(permits: usize) -> Result<()> {
-        info!("queue manager permits: {:?}", permits);
-        GlobalInstance::set(Self::create(permits));
+    pub fn init(permits: usize, max_queued: usize) -> Result<()> {
+        info!(
+            "queue manager permits: {:?}, max queued: {:?}",
+            permits, max_queued
+        );
+        GlobalInstance::set(Self::create(permits, max_queued));
         Ok(())
     }
 
@@ -93,7 +98,7 @@ impl<Data: QueueData> QueueManager<Data> {
         GlobalInstance::get::<Arc<Self>>()
     }
 
-    pub fn create(mut permits: usize) -> Arc<QueueManager<Data>> {
+    pub fn create(mut permits: usize, max_queued: usize) -> Arc<QueueManager<Data>> {
         if permits == 0 {
             permits = usize::MAX >> 4;
         }
@@ -101,6 +106,7 @@ impl<Data: QueueData> QueueManager<Data> {
         Arc::new(QueueManager {
             queue: Mutex::new(HashMap::new()),
             semaphore: Arc::new(Semaphore::new(permits)),
+            max_queued,
         })
     }
 
@@ -138,6 +144,18 @@ impl<Data: QueueData> QueueManager<Data> {
                 self.length()
             );
 
+            // Reject the query directly if it has to wait but the queue is full.
+            if self.max_queued > 0
+                && self.semaphore.available_permits() == 0
+                && self.length() >= self.max_queued
+            {
+                incr_session_queue_acquire_error_count();
+                return Err(ErrorCode::TooManyQueuedQueries(format!(
+                    "too many queued queries, the max queued queries is {}",
+                    self.max_queued
+                )));
+            }
+
             let timeout = data.timeout();
             let future = AcquireQueueFuture::create(
                 Arc::new(data),
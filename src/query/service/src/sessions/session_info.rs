@@ -54,6 +54,15 @@ impl Session {
             memory_usage,
             data_metrics: Self::query_data_metrics(session_ctx),
             scan_progress_value: Self::query_scan_progress_value(session_ctx),
+            total_scan_value: Self::query_total_scan_value(session_ctx),
+            spill_bytes: Self::query_spill_bytes(session_ctx),
+            running_operators: shared_query_context
+                .as_ref()
+                .map(|qry_ctx| qry_ctx.get_running_operators())
+                .unwrap_or_default(),
+            node_scan_progress: shared_query_context
+                .as_ref()
+                .map(|qry_ctx| qry_ctx.get_node_scan_progress()),
             mysql_connection_id: self.mysql_connection_id,
             created_time: Self::query_created_time(session_ctx),
             status_info: shared_query_context
@@ -105,6 +114,30 @@ impl Session {
             .map(|context_shared| context_shared.scan_progress.get_values())
     }
 
+    fn query_total_scan_value(status: &SessionContext) -> Option<ProgressValues> {
+        status
+            .get_query_context_shared()
+            .as_ref()
+            .map(|context_shared| context_shared.total_scan_values.get_values())
+    }
+
+    fn query_spill_bytes(status: &SessionContext) -> Option<usize> {
+        status
+            .get_query_context_shared()
+            .as_ref()
+            .map(|context_shared| {
+                [
+                    &context_shared.join_spill_progress,
+                    &context_shared.agg_spill_progress,
+                    &context_shared.group_by_spill_progress,
+                    &context_shared.window_partition_spill_progress,
+                ]
+                .iter()
+                .map(|progress| progress.get_values().bytes)
+                .sum()
+            })
+    }
+
     fn query_created_time(status: &SessionContext) -> SystemTime {
         match status.get_query_context_shared() {
             None => SystemTime::now(),
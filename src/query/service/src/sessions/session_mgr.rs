@@ -182,6 +182,13 @@ impl SessionManager {
         sessions.get(id).and_then(|weak_ptr| weak_ptr.upgrade())
     }
 
+    pub fn get_session_by_query_id(&self, query_id: &str) -> Option<Arc<Session>> {
+        self.active_sessions_snapshot()
+            .into_iter()
+            .filter_map(|weak_ptr| weak_ptr.upgrade())
+            .find(|session| session.get_current_query_id().as_deref() == Some(query_id))
+    }
+
     pub fn get_id_by_mysql_conn_id(&self, mysql_conn_id: &Option<u32>) -> Option<String> {
         let sessions = self.mysql_conn_map.read();
         sessions.get(mysql_conn_id).cloned()
@@ -15,6 +15,7 @@
 use std::io::Write;
 use std::sync::Arc;
 
+use arrow_ipc::CompressionType;
 use buf_list::BufList;
 use buf_list::Cursor;
 use bytes::Buf;
@@ -22,12 +23,13 @@ use databend_common_base::base::Alignment;
 use databend_common_base::base::DmaWriteBuf;
 use databend_common_exception::Result;
 use databend_common_expression::arrow::read_column;
-use databend_common_expression::arrow::write_column;
+use databend_common_expression::arrow::write_column_with_options;
 use databend_common_expression::converts::arrow::table_schema_to_arrow_schema;
 use databend_common_expression::infer_table_schema;
 use databend_common_expression::DataBlock;
 use databend_common_expression::DataField;
 use databend_common_expression::DataSchema;
+use databend_common_settings::SpillCompression;
 use opendal::Buffer;
 use parquet::arrow::arrow_reader::ParquetRecordBatchReader;
 use parquet::arrow::ArrowWriter;
@@ -39,6 +41,28 @@ use parquet::file::reader::ChunkReader;
 use parquet::file::reader::Length;
 use parquet::format::FileMetaData;
 
+/// Arrow spill files of version 1 are readable by every release: the buffers are
+/// LZ4 compressed and no column is dictionary encoded.
+pub(super) const SPILL_FORMAT_VERSION_V1: u64 = 1;
+
+/// The buffer compression and dictionary encoding of arrow spill files of `version`.
+/// Versions after 1 apply the compression and dictionary encoding chosen by the settings.
+pub(super) fn arrow_spill_encoding(
+    version: u64,
+    compression: SpillCompression,
+    dictionary: bool,
+) -> (Option<CompressionType>, bool) {
+    if version <= SPILL_FORMAT_VERSION_V1 {
+        return (Some(CompressionType::LZ4_FRAME), false);
+    }
+    let compression = match compression {
+        SpillCompression::None => None,
+        SpillCompression::Lz4 => Some(CompressionType::LZ4_FRAME),
+        SpillCompression::Zstd => Some(CompressionType::ZSTD),
+    };
+    (compression, dictionary)
+}
+
 #[derive(Clone)]
 pub enum Layout {
     ArrowIpc(Box<[usize]>),
@@ -47,15 +71,25 @@ pub enum Layout {
 
 pub(super) struct BlocksEncoder {
     pub(super) use_parquet: bool,
+    pub(super) arrow_compression: Option<CompressionType>,
+    pub(super) arrow_dictionary: bool,
     pub(super) buf: DmaWriteBuf,
     pub(super) offsets: Vec<usize>,
     pub(super) columns_layout: Vec<Layout>,
 }
 
 impl BlocksEncoder {
-    pub(super) fn new(use_parquet: bool, align: Alignment, chunk: usize) -> Self {
+    pub(super) fn new(
+        use_parquet: bool,
+        arrow_compression: Option<CompressionType>,
+        arrow_dictionary: bool,
+        align: Alignment,
+        chunk: usize,
+    ) -> Self {
         Self {
             use_parquet,
+            arrow_compression,
+            arrow_dictionary,
             buf: DmaWriteBuf::new(align, chunk),
             offsets: vec![0],
             columns_layout: Vec::new(),
@@ -81,7 +115,13 @@ impl BlocksEncoder {
                     let column = entry
                         .value
                         .convert_to_full_column(&entry.data_type, block.num_rows());
-                    write_column(&column, &mut self.buf).unwrap();
+                    write_column_with_options(
+                        &column,
+                        &mut self.buf,
+                        self.arrow_compression,
+                        self.arrow_dictionary,
+                    )
+                    .unwrap();
                     self.size()
                 }))
                 .map_windows(|x: &[_; 2]| x[1] - x[0])
@@ -207,7 +247,9 @@ impl ChunkReader for Reader {
 
 #[cfg(test)]
 mod tests {
+    use arrow_ipc::reader::FileReaderBuilder;
     use bytes::Bytes;
+    use databend_common_expression::arrow::deserialize_column;
     use databend_common_expression::block_debug::assert_block_value_eq;
     use databend_common_expression::types::Int64Type;
     use databend_common_expression::types::StringType;
@@ -245,4 +287,66 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_serde_arrow_compression() -> Result<()> {
+        let column = StringType::from_data(vec!["SM CASE", "a", "", "SM CASE"]);
+        for compression in [
+            None,
+            Some(CompressionType::LZ4_FRAME),
+            Some(CompressionType::ZSTD),
+        ] {
+            for dictionary in [false, true] {
+                let mut data = Vec::new();
+                write_column_with_options(&column, &mut data, compression, dictionary)?;
+                let got = deserialize_column(&data)?;
+                assert_eq!(column, got);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_arrow_spill_encoding() {
+        // version 1 ignores the settings, so that every reader understands the files
+        assert_eq!(
+            arrow_spill_encoding(SPILL_FORMAT_VERSION_V1, SpillCompression::Zstd, true),
+            (Some(CompressionType::LZ4_FRAME), false)
+        );
+        assert_eq!(
+            arrow_spill_encoding(2, SpillCompression::Zstd, true),
+            (Some(CompressionType::ZSTD), true)
+        );
+        assert_eq!(
+            arrow_spill_encoding(2, SpillCompression::None, false),
+            (None, false)
+        );
+    }
+
+    #[test]
+    fn test_serde_arrow_dictionary() -> Result<()> {
+        let columns = [
+            StringType::from_data(vec!["SM CASE", "a", "SM CASE", "a", "SM CASE"]),
+            StringType::from_opt_data(vec![Some("a"), None, Some("a"), None, Some("")]),
+            // too many distinct values, written as a plain string column
+            StringType::from_data(vec!["a", "b", "c"]),
+        ];
+        for (column, encoded) in columns.into_iter().zip([true, true, false]) {
+            let mut data = Vec::new();
+            write_column_with_options(&column, &mut data, None, true)?;
+
+            let reader = FileReaderBuilder::new().build(std::io::Cursor::new(&data))?;
+            let is_dictionary = matches!(
+                reader.schema().field(0).data_type(),
+                arrow_schema::DataType::Dictionary(_, _)
+            );
+            assert_eq!(encoded, is_dictionary);
+
+            let got = deserialize_column(&data)?;
+            assert_eq!(column, got);
+        }
+
+        Ok(())
+    }
 }
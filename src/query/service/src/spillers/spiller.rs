@@ -20,6 +20,7 @@ use std::ops::Range;
 use std::sync::Arc;
 use std::time::Instant;
 
+use arrow_ipc::CompressionType;
 use bytes::Bytes;
 use databend_common_base::base::dma_buffer_as_vec;
 use databend_common_base::base::dma_read_file_range;
@@ -92,6 +93,8 @@ pub struct Spiller {
     // for dio disabled
     local_operator: Option<Operator>,
     use_parquet: bool,
+    arrow_compression: Option<CompressionType>,
+    arrow_dictionary: bool,
     _spiller_type: SpillerType,
     pub join_spilling_partition_bits: usize,
     /// 1 partition -> N partition files
@@ -117,6 +120,12 @@ impl Spiller {
             use_parquet,
         } = config;
 
+        let (arrow_compression, arrow_dictionary) = arrow_spill_encoding(
+            settings.get_spilling_file_format_version()?,
+            settings.get_spilling_arrow_compression()?,
+            settings.get_enable_spilling_arrow_dictionary()?,
+        );
+
         let (temp_dir, local_operator) = match disk_spill {
             Some(SpillerDiskConfig {
                 temp_dir,
@@ -132,6 +141,8 @@ impl Spiller {
             temp_dir,
             local_operator,
             use_parquet,
+            arrow_compression,
+            arrow_dictionary,
             _spiller_type: spiller_type,
             join_spilling_partition_bits: settings.get_join_spilling_partition_bits()?,
             partition_location: Default::default(),
@@ -383,6 +394,8 @@ impl Spiller {
     }
 
     async fn write_encodes(&mut self, size: usize, buf: DmaWriteBuf) -> Result<Location> {
+        charge_spill_quota(&self.ctx, size)?;
+
         let location = match &self.temp_dir {
             None => None,
             Some(disk) => disk.new_file_with_size(size)?.map(Location::Local),
@@ -426,7 +439,13 @@ impl Spiller {
             .as_ref()
             .map(|dir| dir.block_alignment())
             .unwrap_or(Alignment::MIN);
-        BlocksEncoder::new(self.use_parquet, align, 8 * 1024 * 1024)
+        BlocksEncoder::new(
+            self.use_parquet,
+            self.arrow_compression,
+            self.arrow_dictionary,
+            align,
+            8 * 1024 * 1024,
+        )
     }
 
     pub(crate) fn spilled_files(&self) -> Vec<Location> {
@@ -450,6 +469,12 @@ pub enum Location {
     Local(TempPath),
 }
 
+/// Charge `bytes` to the spill quota of the query before they are written, fails once
+/// `max_spill_bytes_per_query` is exceeded. Every spill path goes through here.
+pub fn charge_spill_quota(ctx: &QueryContext, bytes: usize) -> Result<()> {
+    ctx.add_spilled_bytes(bytes)
+}
+
 fn record_write_profile(location: &Location, start: &Instant, write_bytes: usize) {
     match location {
         Location::Remote(_) => {
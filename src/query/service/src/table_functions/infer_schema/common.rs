@@ -0,0 +1,125 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use databend_common_meta_app::principal::StageFileCompression;
+use opendal::Operator;
+
+/// Only the head of the row based files is sampled, inferring from a whole file could be
+/// arbitrarily expensive.
+pub(super) const MAX_SAMPLE_BYTES: u64 = 1024 * 1024;
+pub(super) const MAX_SAMPLE_ROWS: usize = 1000;
+
+/// Reads the head of a row based file, returns the bytes and whether the whole file is read.
+pub(super) async fn read_sample(
+    operator: &Operator,
+    path: &str,
+    file_size: u64,
+    compression: StageFileCompression,
+    format: &str,
+) -> Result<(Vec<u8>, bool)> {
+    if !matches!(
+        compression,
+        StageFileCompression::None | StageFileCompression::Auto
+    ) {
+        return Err(ErrorCode::BadArguments(format!(
+            "infer_schema does not support compressed {format} files"
+        )));
+    }
+
+    let read_len = file_size.min(MAX_SAMPLE_BYTES);
+    let buffer = operator.read_with(path).range(0..read_len).await?.to_vec();
+    Ok((buffer, read_len == file_size))
+}
+
+pub(super) fn sample_too_small_error(path: &str) -> ErrorCode {
+    ErrorCode::BadBytes(format!(
+        "infer_schema failed to sample {path}: the first row is larger than {MAX_SAMPLE_BYTES} bytes"
+    ))
+}
+
+/// Unifies two inferred types, numbers widen to the type the other one can be cast to
+/// without loss (or Float64), other conflicts become `fallback`.
+pub(super) fn merge_type(
+    a: TableDataType,
+    b: TableDataType,
+    fallback: &TableDataType,
+) -> TableDataType {
+    match (a, b) {
+        (a, b) if a == b => a,
+        (TableDataType::Null, ty) | (ty, TableDataType::Null) => ty,
+        (TableDataType::Number(a), TableDataType::Number(b)) => {
+            TableDataType::Number(if a.can_lossless_cast_to(b) {
+                b
+            } else if b.can_lossless_cast_to(a) {
+                a
+            } else {
+                NumberDataType::Float64
+            })
+        }
+        _ => fallback.clone(),
+    }
+}
+
+/// Unifies the schemas inferred from the sampled files by column name. The columns are
+/// ordered by their first occurrence, a column missing from some of the files is nullable.
+/// The row based readers infer `Null` for the columns only holding nulls, so a later file
+/// can still decide their type.
+pub(super) fn merge_schemas(schemas: Vec<TableSchema>, fallback: &TableDataType) -> TableSchema {
+    let num_schemas = schemas.len();
+    let mut fields: Vec<(String, TableDataType, bool, usize)> = vec![];
+    let mut positions: HashMap<String, usize> = HashMap::new();
+    for schema in schemas {
+        for field in schema.fields() {
+            let nullable = field.is_nullable_or_null();
+            let data_type = field.data_type().remove_nullable();
+            match positions.get(field.name()) {
+                Some(pos) => {
+                    let (_, prev, prev_nullable, count) = &mut fields[*pos];
+                    *prev = merge_type(prev.clone(), data_type, fallback);
+                    *prev_nullable |= nullable;
+                    *count += 1;
+                }
+                None => {
+                    positions.insert(field.name().clone(), fields.len());
+                    fields.push((field.name().clone(), data_type, nullable, 1));
+                }
+            }
+        }
+    }
+
+    let fields = fields
+        .into_iter()
+        .map(|(name, data_type, nullable, count)| {
+            // Columns that only hold nulls in all the samples fall back to string.
+            let data_type = match data_type {
+                TableDataType::Null => TableDataType::String,
+                data_type => data_type,
+            };
+            if nullable || count < num_schemas {
+                TableField::new(&name, data_type.wrap_nullable())
+            } else {
+                TableField::new(&name, data_type)
+            }
+        })
+        .collect::<Vec<_>>();
+    TableSchema::new(fields)
+}
@@ -0,0 +1,176 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use csv_core::ReadRecordResult;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use databend_common_formats::RecordDelimiter;
+use databend_common_meta_app::principal::CsvFileFormatParams;
+use opendal::Operator;
+
+use super::common::merge_type;
+use super::common::read_sample;
+use super::common::sample_too_small_error;
+use super::common::MAX_SAMPLE_ROWS;
+
+pub async fn read_csv_schema(
+    operator: &Operator,
+    path: &str,
+    file_size: u64,
+    params: &CsvFileFormatParams,
+) -> Result<TableSchema> {
+    let (buffer, complete) =
+        read_sample(operator, path, file_size, params.compression, "CSV").await?;
+
+    let headers = params.headers as usize;
+    let mut records = split_records(params, &buffer, complete, MAX_SAMPLE_ROWS + headers)?;
+    if records.is_empty() && !complete {
+        return Err(sample_too_small_error(path));
+    }
+
+    let names = if headers > 0 && !records.is_empty() {
+        let names = records.remove(0);
+        records.drain(..(headers - 1).min(records.len()));
+        names
+    } else {
+        vec![]
+    };
+
+    let num_columns = records
+        .iter()
+        .map(|r| r.len())
+        .chain(std::iter::once(names.len()))
+        .max()
+        .unwrap_or(0);
+    let mut types: Vec<Option<TableDataType>> = vec![None; num_columns];
+    let mut nullable = vec![false; num_columns];
+    for record in &records {
+        for (i, ty) in types.iter_mut().enumerate() {
+            // Rows with fewer fields leave the trailing columns empty.
+            let Some(value) = record.get(i) else {
+                nullable[i] = true;
+                continue;
+            };
+            match infer_value_type(value, &params.null_display) {
+                None => nullable[i] = true,
+                Some(value_ty) => {
+                    *ty = Some(match ty.take() {
+                        None => value_ty,
+                        Some(prev) => merge_type(prev, value_ty, &TableDataType::String),
+                    })
+                }
+            }
+        }
+    }
+
+    let fields = types
+        .into_iter()
+        .zip(nullable)
+        .enumerate()
+        .map(|(i, (data_type, nullable))| {
+            let name = match names.get(i) {
+                Some(name) if !name.is_empty() => name.clone(),
+                _ => format!("c{}", i + 1),
+            };
+            match data_type {
+                Some(data_type) if !nullable => TableField::new(&name, data_type),
+                data_type => TableField::new(
+                    &name,
+                    data_type.unwrap_or(TableDataType::Null).wrap_nullable(),
+                ),
+            }
+        })
+        .collect::<Vec<_>>();
+    Ok(TableSchema::new(fields))
+}
+
+/// Splits the sampled bytes into at most `max_records` records, a record cut off by the
+/// sampling range is dropped.
+fn split_records(
+    params: &CsvFileFormatParams,
+    buffer: &[u8],
+    complete: bool,
+    max_records: usize,
+) -> Result<Vec<Vec<String>>> {
+    let escape = if params.escape.is_empty() {
+        None
+    } else {
+        Some(params.escape.as_bytes()[0])
+    };
+    let mut reader = csv_core::ReaderBuilder::new()
+        .delimiter(params.field_delimiter.as_bytes()[0])
+        .quote(params.quote.as_bytes()[0])
+        .escape(escape)
+        .terminator(match params.record_delimiter.as_str().try_into()? {
+            RecordDelimiter::Crlf => csv_core::Terminator::CRLF,
+            RecordDelimiter::Any(v) => csv_core::Terminator::Any(v),
+        })
+        .build();
+
+    let mut records = vec![];
+    let mut input = buffer;
+    let mut output = vec![0u8; buffer.len() + 1];
+    let mut ends = vec![0usize; 128];
+    let (mut n_out, mut n_end) = (0, 0);
+    while records.len() < max_records {
+        let (result, n_in, out, end) =
+            reader.read_record(input, &mut output[n_out..], &mut ends[n_end..]);
+        input = &input[n_in..];
+        n_out += out;
+        n_end += end;
+        match result {
+            // An empty input tells the reader the file ends, which must not happen
+            // for a truncated sample.
+            ReadRecordResult::InputEmpty if !complete => break,
+            ReadRecordResult::InputEmpty => {}
+            ReadRecordResult::OutputFull => output.resize(output.len() * 2, 0),
+            ReadRecordResult::OutputEndsFull => ends.resize(ends.len() * 2, 0),
+            ReadRecordResult::Record => {
+                let mut start = 0;
+                let mut record = Vec::with_capacity(n_end);
+                for end in &ends[..n_end] {
+                    record.push(String::from_utf8_lossy(&output[start..*end]).into_owned());
+                    start = *end;
+                }
+                records.push(record);
+                n_out = 0;
+                n_end = 0;
+            }
+            ReadRecordResult::End => break,
+        }
+    }
+    Ok(records)
+}
+
+fn infer_value_type(value: &str, null_display: &str) -> Option<TableDataType> {
+    if value.is_empty() || value == null_display {
+        return None;
+    }
+    Some(
+        if value.eq_ignore_ascii_case("true") || value.eq_ignore_ascii_case("false") {
+            TableDataType::Boolean
+        } else if value.parse::<i64>().is_ok() {
+            TableDataType::Number(NumberDataType::Int64)
+        } else if value.parse::<u64>().is_ok() {
+            TableDataType::Number(NumberDataType::UInt64)
+        } else if value.bytes().any(|b| b.is_ascii_digit()) && value.parse::<f64>().is_ok() {
+            TableDataType::Number(NumberDataType::Float64)
+        } else {
+            TableDataType::String
+        },
+    )
+}
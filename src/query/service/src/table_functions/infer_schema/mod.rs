@@ -12,8 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod common;
+mod csv;
 mod infer_schema_table;
-mod parquet;
+mod ndjson;
+mod source;
 mod table_args;
 
 pub use infer_schema_table::InferSchemaTable;
@@ -0,0 +1,129 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use databend_common_meta_app::principal::NdJsonFileFormatParams;
+use opendal::Operator;
+use serde_json::Value;
+
+use super::common::merge_type;
+use super::common::read_sample;
+use super::common::sample_too_small_error;
+use super::common::MAX_SAMPLE_ROWS;
+
+struct InferredField {
+    name: String,
+    data_type: Option<TableDataType>,
+    nullable: bool,
+    rows: usize,
+}
+
+pub async fn read_ndjson_schema(
+    operator: &Operator,
+    path: &str,
+    file_size: u64,
+    params: &NdJsonFileFormatParams,
+) -> Result<TableSchema> {
+    let (buffer, complete) =
+        read_sample(operator, path, file_size, params.compression, "NDJSON").await?;
+    let mut lines = buffer.split(|b| *b == b'\n').collect::<Vec<_>>();
+    if !complete {
+        if lines.len() == 1 {
+            return Err(sample_too_small_error(path));
+        }
+        // The last line may be cut off by the sampling range.
+        lines.pop();
+    }
+
+    let mut fields: Vec<InferredField> = vec![];
+    let mut positions: HashMap<String, usize> = HashMap::new();
+    let mut rows = 0;
+    for line in lines {
+        let line = line.trim_ascii();
+        if line.is_empty() {
+            continue;
+        }
+        if rows >= MAX_SAMPLE_ROWS {
+            break;
+        }
+        let value: Value = serde_json::from_slice(line).map_err(|e| {
+            ErrorCode::BadBytes(format!(
+                "infer_schema failed to parse row {} of {path}: {e}",
+                rows + 1
+            ))
+        })?;
+        let Value::Object(object) = value else {
+            return Err(ErrorCode::BadBytes(format!(
+                "infer_schema expects each row of {path} to be a JSON object"
+            )));
+        };
+        rows += 1;
+
+        for (key, value) in object {
+            let pos = *positions.entry(key.clone()).or_insert_with(|| {
+                fields.push(InferredField {
+                    name: key,
+                    data_type: None,
+                    nullable: false,
+                    rows: 0,
+                });
+                fields.len() - 1
+            });
+            let field = &mut fields[pos];
+            field.rows += 1;
+            match infer_value_type(&value) {
+                None => field.nullable = true,
+                Some(ty) => {
+                    field.data_type = Some(match field.data_type.take() {
+                        None => ty,
+                        Some(prev) => merge_type(prev, ty, &TableDataType::Variant),
+                    })
+                }
+            }
+        }
+    }
+
+    let fields = fields
+        .into_iter()
+        .map(|field| {
+            // Keys missing from some rows are nullable as well.
+            let data_type = field.data_type.unwrap_or(TableDataType::Null);
+            if field.nullable || field.rows < rows {
+                TableField::new(&field.name, data_type.wrap_nullable())
+            } else {
+                TableField::new(&field.name, data_type)
+            }
+        })
+        .collect::<Vec<_>>();
+    Ok(TableSchema::new(fields))
+}
+
+fn infer_value_type(value: &Value) -> Option<TableDataType> {
+    match value {
+        Value::Null => None,
+        Value::Bool(_) => Some(TableDataType::Boolean),
+        Value::Number(n) if n.is_i64() => Some(TableDataType::Number(NumberDataType::Int64)),
+        Value::Number(n) if n.is_u64() => Some(TableDataType::Number(NumberDataType::UInt64)),
+        Value::Number(_) => Some(TableDataType::Number(NumberDataType::Float64)),
+        Value::String(_) => Some(TableDataType::String),
+        Value::Array(_) | Value::Object(_) => Some(TableDataType::Variant),
+    }
+}
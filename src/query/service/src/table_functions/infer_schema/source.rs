@@ -25,8 +25,9 @@ use databend_common_expression::types::StringType;
 use databend_common_expression::types::UInt64Type;
 use databend_common_expression::DataBlock;
 use databend_common_expression::FromData;
+use databend_common_expression::TableDataType;
 use databend_common_expression::TableSchema;
-use databend_common_meta_app::principal::StageFileFormatType;
+use databend_common_meta_app::principal::FileFormatParams;
 use databend_common_meta_app::principal::StageType;
 use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::ProcessorPtr;
@@ -36,24 +37,28 @@ use databend_common_sql::binder::resolve_file_location;
 use databend_common_storage::init_stage_operator;
 use databend_common_storage::read_parquet_schema_async_rs;
 use databend_common_storage::StageFilesInfo;
+use databend_common_storages_orc::OrcTable;
 use opendal::Scheme;
 
+use crate::table_functions::infer_schema::common::merge_schemas;
+use crate::table_functions::infer_schema::csv::read_csv_schema;
 use crate::table_functions::infer_schema::infer_schema_table::INFER_SCHEMA;
+use crate::table_functions::infer_schema::ndjson::read_ndjson_schema;
 use crate::table_functions::infer_schema::table_args::InferSchemaArgsParsed;
 
-pub(crate) struct ParquetInferSchemaSource {
+pub(crate) struct InferSchemaSource {
     is_finished: bool,
     ctx: Arc<dyn TableContext>,
     args_parsed: InferSchemaArgsParsed,
 }
 
-impl ParquetInferSchemaSource {
+impl InferSchemaSource {
     pub fn create(
         ctx: Arc<dyn TableContext>,
         output: Arc<OutputPort>,
         args_parsed: InferSchemaArgsParsed,
     ) -> Result<ProcessorPtr> {
-        AsyncSourcer::create(ctx.clone(), output, ParquetInferSchemaSource {
+        AsyncSourcer::create(ctx.clone(), output, InferSchemaSource {
             is_finished: false,
             ctx,
             args_parsed,
@@ -62,7 +67,7 @@ impl ParquetInferSchemaSource {
 }
 
 #[async_trait::async_trait]
-impl AsyncSource for ParquetInferSchemaSource {
+impl AsyncSource for InferSchemaSource {
     const NAME: &'static str = INFER_SCHEMA;
 
     #[async_backtrace::framed]
@@ -118,28 +123,56 @@ impl AsyncSource for ParquetInferSchemaSource {
         };
         let operator = init_stage_operator(&stage_info)?;
 
-        let first_file = files_info.first_file(&operator).await?;
         let file_format_params = match &self.args_parsed.file_format {
             Some(f) => self.ctx.get_file_format(f).await?,
             None => stage_info.file_format_params.clone(),
         };
-        let schema = match file_format_params.get_type() {
-            StageFileFormatType::Parquet => {
-                let arrow_schema = read_parquet_schema_async_rs(
-                    &operator,
-                    &first_file.path,
-                    Some(first_file.size),
-                )
-                .await?;
-                TableSchema::try_from(&arrow_schema)?
-            }
+        let fallback = match &file_format_params {
+            FileFormatParams::Parquet(_)
+            | FileFormatParams::Orc(_)
+            | FileFormatParams::NdJson(_) => TableDataType::Variant,
+            FileFormatParams::Csv(_) => TableDataType::String,
             _ => {
                 return Err(ErrorCode::BadArguments(
-                    "infer_schema is currently limited to format Parquet",
+                    "infer_schema is currently limited to format Parquet, ORC, NDJSON and CSV",
                 ));
             }
         };
 
+        let mut files = files_info
+            .list(&operator, 1, Some(self.args_parsed.max_file_count))
+            .await?;
+        if files.is_empty() {
+            return Err(ErrorCode::BadArguments("no file found"));
+        }
+        // Keep the order of the merged columns stable between runs.
+        files.sort_by(|a, b| a.path.cmp(&b.path));
+
+        let mut schemas = Vec::with_capacity(files.len());
+        for file in files {
+            let schema = match &file_format_params {
+                FileFormatParams::Parquet(_) => {
+                    let arrow_schema =
+                        read_parquet_schema_async_rs(&operator, &file.path, Some(file.size))
+                            .await?;
+                    TableSchema::try_from(&arrow_schema)?
+                }
+                FileFormatParams::Orc(_) => {
+                    let arrow_schema = OrcTable::prepare_metas(file, operator.clone()).await?;
+                    TableSchema::try_from(arrow_schema.as_ref())?
+                }
+                FileFormatParams::NdJson(params) => {
+                    read_ndjson_schema(&operator, &file.path, file.size, params).await?
+                }
+                FileFormatParams::Csv(params) => {
+                    read_csv_schema(&operator, &file.path, file.size, params).await?
+                }
+                _ => unreachable!("checked above"),
+            };
+            schemas.push(schema);
+        }
+        let schema = merge_schemas(schemas, &fallback);
+
         let mut names: Vec<String> = vec![];
         let mut types: Vec<String> = vec![];
         let mut nulls: Vec<bool> = vec![];
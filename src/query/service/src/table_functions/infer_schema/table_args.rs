@@ -24,8 +24,12 @@ pub(crate) struct InferSchemaArgsParsed {
     pub(crate) connection_name: Option<String>,
     pub(crate) file_format: Option<String>,
     pub(crate) files_info: StageFilesInfo,
+    pub(crate) max_file_count: usize,
 }
 
+/// Number of files sampled when `max_file_count` is not given.
+const DEFAULT_MAX_FILE_COUNT: usize = 10;
+
 impl InferSchemaArgsParsed {
     pub(crate) fn parse(table_args: &TableArgs) -> Result<Self> {
         let args = table_args.expect_all_named("infer_schema")?;
@@ -33,6 +37,7 @@ impl InferSchemaArgsParsed {
         let mut location = None;
         let mut connection_name = None;
         let mut file_format = None;
+        let mut max_file_count = DEFAULT_MAX_FILE_COUNT;
         let mut files_info = StageFilesInfo {
             path: "".to_string(),
             files: None,
@@ -53,6 +58,16 @@ impl InferSchemaArgsParsed {
                 "file_format" => {
                     file_format = Some(string_value(v)?);
                 }
+                "max_file_count" => {
+                    max_file_count = match v.get_i64() {
+                        Some(n) if n > 0 => n as usize,
+                        _ => {
+                            return Err(ErrorCode::BadArguments(
+                                "max_file_count of infer_schema must be a positive integer",
+                            ));
+                        }
+                    };
+                }
                 _ => {
                     return Err(ErrorCode::BadArguments(format!(
                         "unknown param {} for infer_schema",
@@ -70,6 +85,7 @@ impl InferSchemaArgsParsed {
             connection_name,
             file_format,
             files_info,
+            max_file_count,
         })
     }
 }
@@ -14,6 +14,7 @@
 
 use std::any::Any;
 use std::mem::discriminant;
+use std::mem::size_of;
 use std::sync::Arc;
 
 use chrono::DateTime;
@@ -21,6 +22,7 @@ use databend_common_catalog::plan::DataSourcePlan;
 use databend_common_catalog::plan::PartStatistics;
 use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::TableStatistics;
 use databend_common_catalog::table_args::TableArgs;
 use databend_common_catalog::table_function::TableFunction;
 use databend_common_exception::ErrorCode;
@@ -47,6 +49,7 @@ use databend_common_pipeline_sources::SyncSourcer;
 use databend_common_sql::validate_function_arg;
 use databend_common_storages_factory::Table;
 use databend_common_storages_fuse::TableContext;
+use databend_storages_common_table_meta::table::ChangeType;
 use itertools::Itertools;
 
 pub struct RangeTable {
@@ -128,6 +131,39 @@ impl RangeTable {
     }
 }
 
+impl RangeTable {
+    /// Number of rows the series generates, `generate_series` includes the end.
+    fn num_rows(&self) -> Result<u64> {
+        let start = get_i64_number(&self.start)?;
+        let end = get_i64_number(&self.end)?;
+        let step = get_i64_number(&self.step)?;
+        Ok(series_len(
+            start,
+            end,
+            step,
+            self.name() == "generate_series",
+        ))
+    }
+}
+
+fn series_len(start: i64, end: i64, step: i64, inclusive: bool) -> u64 {
+    if step == 0 {
+        return 0;
+    }
+    let (start, end, step) = (start as i128, end as i128, step as i128);
+    let span = if step > 0 { end - start } else { start - end };
+    let step = step.abs();
+    if span < 0 {
+        return 0;
+    }
+    let len = if inclusive {
+        span / step + 1
+    } else {
+        (span + step - 1) / step
+    };
+    len as u64
+}
+
 impl TableFunction for RangeTable {
     fn function_name(&self) -> &str {
         self.name()
@@ -156,8 +192,32 @@ impl Table for RangeTable {
         _push_downs: Option<PushDownInfo>,
         _dry_run: bool,
     ) -> Result<(PartStatistics, Partitions)> {
-        // dummy statistics
-        Ok((PartStatistics::default_exact(), Partitions::default()))
+        let num_rows = self.num_rows()?;
+        let statistics = PartStatistics::new_exact(
+            num_rows as usize,
+            num_rows as usize * size_of::<i64>(),
+            1,
+            1,
+        );
+        Ok((statistics, Partitions::default()))
+    }
+
+    async fn table_statistics(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _require_fresh: bool,
+        _change_type: Option<ChangeType>,
+    ) -> Result<Option<TableStatistics>> {
+        // Let the optimizer estimate joins against the generated series.
+        let num_rows = self.num_rows()?;
+        Ok(Some(TableStatistics {
+            num_rows: Some(num_rows),
+            data_size: Some(num_rows * 8),
+            data_size_compressed: None,
+            index_size: None,
+            number_of_blocks: None,
+            number_of_segments: None,
+        }))
     }
 
     fn table_args(&self) -> Option<TableArgs> {
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
 use chrono::Utc;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
@@ -116,6 +118,7 @@ impl<'a> BlockWriter<'a> {
             location.1,
             block,
             bloom_columns_map,
+            BTreeMap::new(),
         )?;
         if let Some(bloom_index) = maybe_bloom_index {
             let index_block = bloom_index.serialize_to_data_block()?;
@@ -14,3 +14,4 @@
 
 mod database_catalog;
 mod immutable_catalogs;
+mod stale_meta_cache;
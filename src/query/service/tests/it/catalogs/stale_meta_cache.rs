@@ -0,0 +1,144 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::tenant::Tenant;
+use databend_query::catalogs::default::StaleMetaCache;
+
+#[test]
+fn test_stale_meta_cache_fallback() -> Result<()> {
+    let tenant = Tenant::new_literal("test");
+    let cache = StaleMetaCache::create(Duration::from_secs(60));
+
+    let table_info = TableInfo {
+        name: "t1".to_string(),
+        ..Default::default()
+    };
+    cache.put_table(&tenant, "db1", &table_info);
+
+    // Served from the cache when the meta service is unreachable.
+    let unavailable = ErrorCode::MetaServiceError("connection refused");
+    let cached = cache.get_table(unavailable, &tenant, "db1", "t1")?;
+    assert_eq!(cached.name, "t1");
+
+    // Unknown entries keep the original error.
+    let unavailable = ErrorCode::MetaServiceError("connection refused");
+    let err = cache
+        .get_table(unavailable, &tenant, "db1", "t2")
+        .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::META_SERVICE_ERROR);
+
+    // Errors not caused by the meta service are never hidden.
+    let unknown = ErrorCode::UnknownTable("t1");
+    let err = cache.get_table(unknown, &tenant, "db1", "t1").unwrap_err();
+    assert_eq!(err.code(), ErrorCode::UNKNOWN_TABLE);
+
+    // Writes are rejected with a clear error.
+    let err = cache.reject_write(ErrorCode::MetaServiceError("connection refused"));
+    assert_eq!(err.code(), ErrorCode::META_SERVICE_UNAVAILABLE);
+    let err = cache.reject_write(ErrorCode::TableVersionMismatched("t1"));
+    assert_eq!(err.code(), ErrorCode::TABLE_VERSION_MISMATCHED);
+
+    Ok(())
+}
+
+#[test]
+fn test_stale_meta_cache_expired() -> Result<()> {
+    let tenant = Tenant::new_literal("test");
+    let cache = StaleMetaCache::create(Duration::from_millis(10));
+
+    let table_info = TableInfo {
+        name: "t1".to_string(),
+        ..Default::default()
+    };
+    cache.put_table(&tenant, "db1", &table_info);
+    std::thread::sleep(Duration::from_millis(50));
+
+    let unavailable = ErrorCode::MetaServiceError("connection refused");
+    let err = cache
+        .get_table(unavailable, &tenant, "db1", "t1")
+        .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::META_SERVICE_ERROR);
+
+    Ok(())
+}
+
+#[test]
+fn test_stale_meta_cache_invalidate_and_evict() -> Result<()> {
+    let tenant = Tenant::new_literal("test");
+    let cache = StaleMetaCache::create_with_capacity(Duration::from_secs(60), 8, 2);
+
+    for name in ["t1", "t2"] {
+        let table_info = TableInfo {
+            name: name.to_string(),
+            ..Default::default()
+        };
+        cache.put_table(&tenant, "db1", &table_info);
+    }
+
+    // A dropped or renamed table is not served any more.
+    cache.invalidate_table(&tenant, "db1", "t1");
+    let unavailable = ErrorCode::MetaServiceError("connection refused");
+    assert!(cache.get_table(unavailable, &tenant, "db1", "t1").is_err());
+
+    // Dropping the database forgets its tables.
+    cache.invalidate_database(&tenant, "db1");
+    let unavailable = ErrorCode::MetaServiceError("connection refused");
+    assert!(cache.get_table(unavailable, &tenant, "db1", "t2").is_err());
+
+    // The least recently used table is evicted once the capacity is reached.
+    for name in ["t3", "t4", "t5"] {
+        let table_info = TableInfo {
+            name: name.to_string(),
+            ..Default::default()
+        };
+        cache.put_table(&tenant, "db1", &table_info);
+    }
+    let unavailable = ErrorCode::MetaServiceError("connection refused");
+    assert!(cache.get_table(unavailable, &tenant, "db1", "t3").is_err());
+    let unavailable = ErrorCode::MetaServiceError("connection refused");
+    assert_eq!(
+        cache.get_table(unavailable, &tenant, "db1", "t5")?.name,
+        "t5"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_stale_meta_cache_refresh_changed_table() -> Result<()> {
+    let tenant = Tenant::new_literal("test");
+    let cache = StaleMetaCache::create(Duration::from_secs(60));
+
+    for seq in [1, 1, 2] {
+        let table_info = TableInfo {
+            ident: TableIdent::new(1, seq),
+            name: "t1".to_string(),
+            ..Default::default()
+        };
+        cache.put_table(&tenant, "db1", &table_info);
+    }
+
+    // A new version of the table replaces the cached one.
+    let unavailable = ErrorCode::MetaServiceError("connection refused");
+    let cached = cache.get_table(unavailable, &tenant, "db1", "t1")?;
+    assert_eq!(cached.ident, TableIdent::new(1, 2));
+
+    Ok(())
+}
@@ -242,6 +242,35 @@ async fn test_settings() -> PoemResult<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_timezone() -> PoemResult<()> {
+    let _fixture = TestFixture::setup().await.unwrap();
+
+    let server = Server::new().await;
+
+    let sql = "select to_timestamp(0)";
+    let response = server
+        .endpoint
+        .get_response(
+            QueryBuilder::new(sql)
+                .settings(HashMap::from([(
+                    "timezone".to_string(),
+                    "Asia/Shanghai".to_string(),
+                )]))
+                .build(),
+        )
+        .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers().get("X-ClickHouse-Timezone").unwrap(),
+        "Asia/Shanghai"
+    );
+    let body = response.into_body().into_string().await.unwrap();
+    assert_eq!(&body, "1970-01-01 08:00:00.000000\n");
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_federated() -> PoemResult<()> {
     let _fixture = TestFixture::setup().await.unwrap();
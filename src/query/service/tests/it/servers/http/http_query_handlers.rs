@@ -731,6 +731,37 @@ async fn test_insert() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn test_column_origin() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let (status, result) = post_sql("create table t_origin(a int, b string)", 3).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result);
+
+    let (status, result) = post_sql("select b as c, a + 1 as d, a from t_origin", 3).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result);
+    assert_eq!(
+        serde_json::to_value(&result.schema)?,
+        json!([
+            {
+                "name": "c",
+                "type": "Nullable(String)",
+                "origin": {"database": "default", "table": "t_origin", "column": "b"}
+            },
+            {"name": "d", "type": "Nullable(Int64)"},
+            {
+                "name": "a",
+                "type": "Nullable(Int32)",
+                "origin": {"database": "default", "table": "t_origin", "column": "a"}
+            },
+        ])
+    );
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "current_thread")]
 async fn test_query_log() -> Result<()> {
     let _fixture = TestFixture::setup().await?;
@@ -1143,6 +1174,7 @@ async fn test_auth_jwt_with_create_user() -> Result<()> {
             tenant_id: None,
             role: Some("account_admin".to_string()),
             ensure_user: Some(EnsureUser::default()),
+            ..Default::default()
         },
     };
 
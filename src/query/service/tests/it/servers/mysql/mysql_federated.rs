@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use chrono_tz::Tz;
 use databend_common_exception::Result;
 use databend_common_expression::block_debug::assert_blocks_eq;
 use databend_query::servers::MySQLFederated;
@@ -65,5 +66,39 @@ fn test_mysql_federated() -> Result<()> {
         }
     }
 
+    // session timezone
+    {
+        let federated = MySQLFederated::create().with_timezone(Tz::Asia__Shanghai);
+        let result = federated.check("select @@time_zone, @@system_time_zone");
+        assert!(result.is_some());
+
+        if let Some((_, block)) = result {
+            let expect = vec![
+                "+-----------------+----------+",
+                "| Column 0        | Column 1 |",
+                "+-----------------+----------+",
+                "| 'Asia/Shanghai' | 'UTC'    |",
+                "+-----------------+----------+",
+            ];
+
+            assert_blocks_eq(expect, &[block]);
+        }
+
+        let result = federated.check("SELECT TIMEDIFF(NOW(), UTC_TIMESTAMP())");
+        assert!(result.is_some());
+
+        if let Some((_, block)) = result {
+            let expect = vec![
+                "+------------+",
+                "| Column 0   |",
+                "+------------+",
+                "| '08:00:00' |",
+                "+------------+",
+            ];
+
+            assert_blocks_eq(expect, &[block]);
+        }
+    }
+
     Ok(())
 }
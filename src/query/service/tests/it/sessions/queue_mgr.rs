@@ -61,7 +61,7 @@ async fn test_passed_acquire() -> Result<()> {
         + 5;
 
     let barrier = Arc::new(tokio::sync::Barrier::new(test_count));
-    let queue = QueueManager::<TestData<true>>::create(1);
+    let queue = QueueManager::<TestData<true>>::create(1, 0);
     let mut join_handles = Vec::with_capacity(test_count);
 
     let instant = Instant::now();
@@ -100,7 +100,7 @@ async fn test_serial_acquire() -> Result<()> {
         + 5;
 
     let barrier = Arc::new(tokio::sync::Barrier::new(test_count));
-    let queue = QueueManager::<TestData>::create(1);
+    let queue = QueueManager::<TestData>::create(1, 0);
     let mut join_handles = Vec::with_capacity(test_count);
 
     let instant = Instant::now();
@@ -139,7 +139,7 @@ async fn test_concurrent_acquire() -> Result<()> {
         + 5;
 
     let barrier = Arc::new(tokio::sync::Barrier::new(test_count));
-    let queue = QueueManager::<TestData>::create(2);
+    let queue = QueueManager::<TestData>::create(2, 0);
     let mut join_handles = Vec::with_capacity(test_count);
 
     let instant = Instant::now();
@@ -181,7 +181,7 @@ async fn test_list_acquire() -> Result<()> {
         + 5;
 
     let barrier = Arc::new(tokio::sync::Barrier::new(test_count));
-    let queue = QueueManager::<TestData>::create(1);
+    let queue = QueueManager::<TestData>::create(1, 0);
     let mut join_handles = Vec::with_capacity(test_count);
 
     for index in 0..test_count {
@@ -206,6 +206,34 @@ async fn test_list_acquire() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_max_queued_acquire() -> Result<()> {
+    let queue = QueueManager::<TestData>::create(1, 1);
+
+    let _running = queue.acquire(TestData("TestData0".to_string())).await?;
+    let waiting = {
+        let queue = queue.clone();
+        databend_common_base::runtime::spawn(async move {
+            let _guard = queue.acquire(TestData("TestData1".to_string())).await?;
+            Result::<()>::Ok(())
+        })
+    };
+
+    tokio::time::sleep(Duration::from_secs(1)).await;
+    assert_eq!(queue.length(), 1);
+
+    let rejected = queue.acquire(TestData("TestData2".to_string())).await;
+    assert_eq!(
+        rejected.err().map(|e| e.code()),
+        Some(ErrorCode::TOO_MANY_QUEUED_QUERIES)
+    );
+    assert_eq!(queue.length(), 1);
+
+    queue.remove("TestData1".to_string());
+    let _ = waiting.await;
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_heavy_actions() -> Result<()> {
     struct Query {
@@ -0,0 +1,49 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_query::interpreters::MetricsHistory;
+use databend_query::test_kits::*;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_metrics_history_write() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let tenant = fixture.default_tenant();
+
+    let written = MetricsHistory::write(tenant.tenant_name(), "node1").await?;
+    assert!(written > 0);
+
+    // the custom metrics of system.metrics are sampled too
+    let expected = vec![
+        "+----------+",
+        "| Column 0 |",
+        "+----------+",
+        "| 1        |",
+        "+----------+",
+    ];
+    expects_ok(
+        "custom_metrics_are_sampled",
+        fixture
+            .execute_query(
+                "select count(*) from system_history.metrics \
+                 where node = 'node1' and metric = 'query_memory_usage_bytes' and kind = 'counter'",
+            )
+            .await,
+        expected,
+    )
+    .await?;
+
+    Ok(())
+}
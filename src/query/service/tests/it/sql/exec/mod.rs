@@ -171,3 +171,5 @@ pub async fn test_snapshot_consistency() -> Result<()> {
 }
 
 mod get_table_bind_test;
+mod metrics_history;
+mod query_audit;
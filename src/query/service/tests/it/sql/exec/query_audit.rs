@@ -0,0 +1,89 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::any::Any;
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_query::interpreters::QueryAudit;
+use databend_query::interpreters::QueryAuditRecord;
+use databend_query::test_kits::*;
+
+fn record(tenant: &str, query_id: &str, query_text: &str) -> QueryAuditRecord {
+    QueryAuditRecord {
+        tenant: tenant.to_string(),
+        webhook_url: "".to_string(),
+        event_time: 1_700_000_000_000_000,
+        query_id: query_id.to_string(),
+        user_name: "root".to_string(),
+        client_address: "127.0.0.1".to_string(),
+        current_database: "default".to_string(),
+        query_kind: "Query".to_string(),
+        query_text: query_text.to_string(),
+        status: "Finish".to_string(),
+        exception_code: 0,
+        exception_text: "".to_string(),
+        written_rows: 0,
+        result_rows: 1,
+        query_duration_ms: 3,
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_query_audit_flush() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let tenant = fixture.default_tenant();
+
+    QueryAudit::append(record(tenant.tenant_name(), "q1", "SELECT ?"));
+    QueryAudit::append(record(tenant.tenant_name(), "q2", "SELECT 'it''s'"));
+    QueryAudit::flush().await?;
+
+    let expected = vec![
+        "+----------+",
+        "| Column 0 |",
+        "+----------+",
+        "| 2        |",
+        "+----------+",
+    ];
+    expects_ok(
+        "audit_records_are_written",
+        fixture
+            .execute_query("select count(*) from system_history.query_audit")
+            .await,
+        expected,
+    )
+    .await?;
+
+    // the query text is kept as is, quotes included
+    let expected = vec![
+        "+----------+",
+        "| Column 0 |",
+        "+----------+",
+        "| 1        |",
+        "+----------+",
+    ];
+    expects_ok(
+        "audit_query_text_is_escaped",
+        fixture
+            .execute_query(
+                "select count(*) from system_history.query_audit \
+                 where query_id = 'q2' and query_text = 'SELECT ''it''''s''' \
+                 and event_time = '2023-11-14 22:13:20'",
+            )
+            .await,
+        expected,
+    )
+    .await?;
+
+    Ok(())
+}
@@ -16,6 +16,8 @@ use std::collections::HashSet;
 
 use databend_common_base::base::tokio;
 use databend_common_base::base::OrderedFloat;
+use databend_common_catalog::lock::LockTableOption;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::Float64Type;
 use databend_common_expression::types::Int32Type;
@@ -299,3 +301,44 @@ async fn test_fuse_table_optimize_alter_table() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_table_alter_column_when_locked() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    fixture.create_normal_table().await?;
+
+    let db_name = fixture.default_db_name();
+    let tbl_name = fixture.default_table_name();
+
+    // Hold the table lock, as a running recluster or compact does.
+    let lock_ctx = fixture.new_query_ctx().await?;
+    let guard = lock_ctx
+        .clone()
+        .acquire_table_lock(
+            &fixture.default_catalog_name(),
+            &db_name,
+            &tbl_name,
+            &LockTableOption::LockNoRetry,
+        )
+        .await?;
+    assert!(guard.is_some());
+
+    for query in [
+        format!("alter table {db_name}.{tbl_name} add column c int"),
+        format!("alter table {db_name}.{tbl_name} drop column t"),
+    ] {
+        let err = fixture.execute_command(&query).await.unwrap_err();
+        assert_eq!(err.code(), ErrorCode::TABLE_ALREADY_LOCKED);
+        assert!(
+            err.message()
+                .contains(&format!("held by query '{}'", lock_ctx.get_id())),
+            "unexpected error: {}",
+            err.message()
+        );
+    }
+
+    drop(guard);
+
+    Ok(())
+}
@@ -45,8 +45,11 @@ use databend_query::storages::fuse::io::MetaReaders;
 use databend_query::storages::fuse::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
 use databend_query::storages::fuse::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use databend_query::test_kits::*;
+use databend_storages_common_cache::CacheAccessor;
+use databend_storages_common_cache::CachedObject;
 use databend_storages_common_cache::LoadParams;
 use databend_storages_common_table_meta::meta::BlockMeta;
+use databend_storages_common_table_meta::meta::SegmentInfo;
 use databend_storages_common_table_meta::meta::TableSnapshot;
 use databend_storages_common_table_meta::meta::Versioned;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
@@ -71,6 +74,7 @@ async fn apply_block_pruning(
         push_down,
         bloom_index_cols,
         None,
+        false,
         FuseStorageFormat::Parquet,
     )?
     .read_pruning(segment_locs)
@@ -266,3 +270,130 @@ async fn test_block_pruner() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_prefetch_segments_with_limit() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    fixture.create_default_database().await?;
+
+    let test_tbl_name = "test_prefetch_segments";
+    let test_schema = TableSchemaRefExt::create(vec![TableField::new(
+        "a",
+        TableDataType::Number(NumberDataType::UInt64),
+    )]);
+
+    // one block of one row per segment
+    let num_segments = 200;
+    let create_table_plan = CreateTablePlan {
+        catalog: "default".to_owned(),
+        create_option: CreateOption::Create,
+        tenant: fixture.default_tenant(),
+        database: fixture.default_db_name(),
+        table: test_tbl_name.to_string(),
+        schema: test_schema.clone(),
+        engine: Engine::Fuse,
+        engine_options: Default::default(),
+        storage_params: None,
+        options: [
+            (FUSE_OPT_KEY_ROW_PER_BLOCK.to_owned(), "1".to_owned()),
+            (FUSE_OPT_KEY_BLOCK_PER_SEGMENT.to_owned(), "1".to_owned()),
+            (OPT_KEY_DATABASE_ID.to_owned(), "1".to_owned()),
+        ]
+        .into(),
+        field_comments: vec![],
+        as_select: None,
+        cluster_key: None,
+        inverted_indexes: None,
+    };
+
+    let interpreter = CreateTableInterpreter::try_create(ctx.clone(), create_table_plan)?;
+    let _ = interpreter.execute(ctx.clone()).await?;
+
+    let catalog = ctx.get_catalog("default").await?;
+    let table = catalog
+        .get_table(
+            &fixture.default_tenant(),
+            fixture.default_db_name().as_str(),
+            test_tbl_name,
+        )
+        .await?;
+
+    let blocks = (0..num_segments)
+        .map(|idx| DataBlock::new_from_columns(vec![UInt64Type::from_data(vec![idx as u64])]))
+        .collect::<Vec<_>>();
+    fixture
+        .append_commit_blocks(table.clone(), blocks, false, true)
+        .await?;
+
+    let table = catalog
+        .get_table(
+            &fixture.default_tenant(),
+            fixture.default_db_name().as_str(),
+            test_tbl_name,
+        )
+        .await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let snapshot = fuse_table.read_table_snapshot().await?.unwrap();
+    assert_eq!(num_segments, snapshot.segments.len());
+
+    // Segments are cached when they are written, evict them to start with a cold cache.
+    let segment_cache = SegmentInfo::cache().expect("segment cache must be enabled");
+    for (path, _) in &snapshot.segments {
+        segment_cache.evict(path);
+    }
+
+    // 10 pruning batches of 20 segments, each batch stops after its first chunk
+    // since a single row is enough for the limit.
+    let settings = ctx.get_settings();
+    settings.set_setting("enable_prefetch_segments".to_string(), "1".to_string())?;
+    settings.set_setting("max_storage_io_requests".to_string(), "10".to_string())?;
+    let push_down = Some(PushDownInfo {
+        limit: Some(1),
+        ..Default::default()
+    });
+    let blocks = apply_block_pruning(
+        snapshot.clone(),
+        table.get_table_info().schema(),
+        &push_down,
+        ctx.clone(),
+        fuse_table.get_operator(),
+        fuse_table.bloom_index_cols(),
+    )
+    .await?;
+    assert!(!blocks.is_empty());
+
+    let fetched = snapshot
+        .segments
+        .iter()
+        .filter(|(path, _)| segment_cache.contains_key(path))
+        .count();
+    assert!(fetched > 0);
+    assert!(
+        fetched < num_segments,
+        "fetched {} of {} segments",
+        fetched,
+        num_segments
+    );
+
+    // Without a limit every segment is fetched.
+    let blocks = apply_block_pruning(
+        snapshot.clone(),
+        table.get_table_info().schema(),
+        &None,
+        ctx.clone(),
+        fuse_table.get_operator(),
+        fuse_table.bloom_index_cols(),
+    )
+    .await?;
+    assert_eq!(num_segments, blocks.len());
+    let fetched = snapshot
+        .segments
+        .iter()
+        .filter(|(path, _)| segment_cache.contains_key(path))
+        .count();
+    assert_eq!(num_segments, fetched);
+
+    Ok(())
+}
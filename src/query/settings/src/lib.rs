@@ -24,3 +24,4 @@ pub use settings_default::ReplaceIntoShuffleStrategy;
 pub use settings_default::SettingMode;
 pub use settings_default::SettingRange;
 pub use settings_getter_setter::FlightCompression;
+pub use settings_getter_setter::SpillCompression;
@@ -23,6 +23,7 @@ use databend_common_exception::Result;
 use databend_common_meta_app::principal::UserSettingValue;
 use once_cell::sync::OnceCell;
 
+use super::settings_getter_setter::SpillCompression;
 use super::settings_getter_setter::SpillFileFormat;
 
 static DEFAULT_SETTINGS: OnceCell<Arc<DefaultSettings>> = OnceCell::new();
@@ -317,12 +318,36 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::String(SpillFileFormat::range())),
                 }),
+                ("spilling_file_format_version", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Set the version of arrow spilling files. Version 1 is readable by every release, version 2 enables spilling_arrow_compression and enable_spilling_arrow_dictionary. Only raise it once every node runs a release that reads version 2.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(1..=2)),
+                }),
+                ("spilling_arrow_compression", DefaultSettingValue {
+                    value: UserSettingValue::String("lz4".to_string()),
+                    desc: "Set the buffer compression of arrow spilling files, needs spilling_file_format_version 2.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::String(SpillCompression::range())),
+                }),
+                ("enable_spilling_arrow_dictionary", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables dictionary encoding of low cardinality string columns in arrow spilling files, needs spilling_file_format_version 2.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("spilling_to_disk_vacuum_unknown_temp_dirs_limit", DefaultSettingValue {
                     value: UserSettingValue::UInt64(u64::MAX),
                     desc: "Set the maximum number of directories to clean up. If there are some temporary dirs when another query is unexpectedly interrupted, which needs to be cleaned up after this query.",
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=u64::MAX)),
                 }),
+                ("max_spill_bytes_per_query", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the maximum amount of bytes that join, window, sort and aggregation can spill to local disk or storage in one query on each node, 0 is unlimited. The bytes spilled by other nodes of a cluster query are not counted.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
                 ("enable_merge_into_row_fetch", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enable merge into row fetch optimization.",
@@ -419,6 +444,12 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("enable_table_data_cache", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables reading table data from the data cache, disable it to force fresh reads from storage.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("query_result_cache_max_bytes", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1048576), // 1MB
                     desc: "Sets the maximum byte size of cache for a single query result.",
@@ -585,6 +616,12 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("small_query_max_scan_rows", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Executes a query locally on the coordinator, skipping distributed planning, when every table scan is estimated to read at most this many rows. 0 disables the fast path.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
                 ("enable_experimental_merge_into", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables the experimental feature for 'MERGE INTO'.",
@@ -627,6 +664,24 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("enable_auto_analyze", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables analyze table after write(copy/insert/replace-into/merge-into) if the table statistics are stale.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("auto_analyze_ratio", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(20),
+                    desc: "Sets the percentage of changed rows since the last analyze, above which the table statistics are stale and enable_auto_analyze re-analyzes the table.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=100)),
+                }),
+                ("enable_auto_analyze_sync", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Runs the auto analyze before the write statement returns instead of in the background, mostly for tests.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("enable_compact_after_write", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables compact after write(copy/insert/replace-into/merge-into), need more memory.",
@@ -684,6 +739,12 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=u64::MAX)),
                 }),
+                ("enable_clustering_history_depth", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Records the average overlaps and depth of the table in system.clustering_history after each recluster, which reads the metadata of all segments.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("default_order_by_null", DefaultSettingValue {
                     value: UserSettingValue::String("nulls_last".to_string()),
                     desc: "Set numeric default_order_by_null mode",
@@ -929,6 +990,12 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("enable_expression_jit", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables compiling filter and projection expressions over non-nullable numeric columns into native code.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("dynamic_sample_time_budget_ms", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Time budget for dynamic sample in milliseconds",
@@ -941,6 +1008,30 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(1..=1024*1024)),
                 }),
+                ("enable_query_text_redaction", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Replaces literals with ? in the query text recorded in system.query_log, its file and OTLP sinks, and the process list.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("enable_query_audit", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Records every statement of the tenant into system_history.query_audit, and posts it to query_audit_webhook_url if set.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("query_audit_webhook_url", DefaultSettingValue {
+                    value: UserSettingValue::String("".to_string()),
+                    desc: "The URL the query audit records are posted to as JSON lines, empty disables posting.",
+                    mode: SettingMode::Both,
+                    range: None,
+                }),
+                ("enable_prefetch_segments", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables fetching the segments to prune concurrently in small chunks, no more segment is fetched once the pushed down limit is reached. Speeds up the first query on tables with many segments.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
             ]);
 
             Ok(Arc::new(DefaultSettings {
@@ -68,6 +68,39 @@ impl FromStr for SpillFileFormat {
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SpillCompression {
+    None,
+    Lz4,
+    Zstd,
+}
+
+impl SpillCompression {
+    pub fn range() -> Vec<String> {
+        ["none", "lz4", "zstd"]
+            .iter()
+            .copied()
+            .map(String::from)
+            .collect()
+    }
+}
+
+impl FromStr for SpillCompression {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(Self::None),
+            "lz4" => Ok(Self::Lz4),
+            "zstd" => Ok(Self::Zstd),
+            _ => Err(ErrorCode::InvalidConfig(format!(
+                "invalid SpillCompression: {:?}",
+                s
+            ))),
+        }
+    }
+}
+
 impl Settings {
     // Get u64 value, we don't get from the metasrv.
     fn try_get_u64(&self, key: &str) -> Result<u64> {
@@ -173,6 +206,10 @@ impl Settings {
         Ok(self.try_get_u64("enable_auto_fix_missing_bloom_index")? != 0)
     }
 
+    pub fn get_enable_prefetch_segments(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_prefetch_segments")? != 0)
+    }
+
     // Get max_block_size.
     pub fn get_max_block_size(&self) -> Result<u64> {
         self.try_get_u64("max_block_size")
@@ -331,10 +368,26 @@ impl Settings {
         self.try_get_string("spilling_file_format")?.parse()
     }
 
+    pub fn get_spilling_file_format_version(&self) -> Result<u64> {
+        self.try_get_u64("spilling_file_format_version")
+    }
+
+    pub fn get_spilling_arrow_compression(&self) -> Result<SpillCompression> {
+        self.try_get_string("spilling_arrow_compression")?.parse()
+    }
+
+    pub fn get_enable_spilling_arrow_dictionary(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_spilling_arrow_dictionary")? != 0)
+    }
+
     pub fn get_spilling_to_disk_vacuum_unknown_temp_dirs_limit(&self) -> Result<usize> {
         Ok(self.try_get_u64("spilling_to_disk_vacuum_unknown_temp_dirs_limit")? as usize)
     }
 
+    pub fn get_max_spill_bytes_per_query(&self) -> Result<usize> {
+        Ok(self.try_get_u64("max_spill_bytes_per_query")? as usize)
+    }
+
     pub fn get_inlist_to_join_threshold(&self) -> Result<usize> {
         Ok(self.try_get_u64("inlist_to_join_threshold")? as usize)
     }
@@ -412,6 +465,10 @@ impl Settings {
         Ok(self.try_get_u64("enable_query_result_cache")? != 0)
     }
 
+    pub fn get_enable_table_data_cache(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_table_data_cache")? != 0)
+    }
+
     pub fn get_query_result_cache_max_bytes(&self) -> Result<usize> {
         Ok(self.try_get_u64("query_result_cache_max_bytes")? as usize)
     }
@@ -545,6 +602,10 @@ impl Settings {
         Ok(self.try_get_u64("enable_distributed_copy_into")? != 0)
     }
 
+    pub fn get_small_query_max_scan_rows(&self) -> Result<u64> {
+        self.try_get_u64("small_query_max_scan_rows")
+    }
+
     pub fn get_enable_experimental_merge_into(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_experimental_merge_into")? != 0)
     }
@@ -614,6 +675,10 @@ impl Settings {
         self.try_get_u64("recluster_timeout_secs")
     }
 
+    pub fn get_enable_clustering_history_depth(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_clustering_history_depth")? != 0)
+    }
+
     pub fn set_recluster_block_size(&self, val: u64) -> Result<()> {
         self.try_set_u64("recluster_block_size", val)
     }
@@ -794,6 +859,10 @@ impl Settings {
         Ok(self.try_get_u64("random_function_seed")? == 1)
     }
 
+    pub fn get_enable_expression_jit(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_expression_jit")? != 0)
+    }
+
     pub fn get_dynamic_sample_time_budget_ms(&self) -> Result<u64> {
         self.try_get_u64("dynamic_sample_time_budget_ms")
     }
@@ -809,4 +878,28 @@ impl Settings {
     pub fn set_short_sql_max_length(&self, val: u64) -> Result<()> {
         self.try_set_u64("short_sql_max_length", val)
     }
+
+    pub fn get_enable_query_text_redaction(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_query_text_redaction")? != 0)
+    }
+
+    pub fn get_enable_query_audit(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_query_audit")? != 0)
+    }
+
+    pub fn get_query_audit_webhook_url(&self) -> Result<String> {
+        self.try_get_string("query_audit_webhook_url")
+    }
+
+    pub fn get_enable_auto_analyze(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_auto_analyze")? != 0)
+    }
+
+    pub fn get_auto_analyze_ratio(&self) -> Result<u64> {
+        self.try_get_u64("auto_analyze_ratio")
+    }
+
+    pub fn get_enable_auto_analyze_sync(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_auto_analyze_sync")? != 0)
+    }
 }
@@ -23,6 +23,8 @@ use databend_common_expression::Evaluator;
 use databend_common_expression::Expr;
 use databend_common_expression::FieldIndex;
 use databend_common_expression::FunctionContext;
+use databend_common_expression::JitExpr;
+use databend_common_expression::Value;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_pipeline_core::processors::InputPort;
 use databend_common_pipeline_core::processors::OutputPort;
@@ -47,7 +49,18 @@ pub enum BlockOperator {
 }
 
 impl BlockOperator {
-    pub fn execute(&self, func_ctx: &FunctionContext, mut input: DataBlock) -> Result<DataBlock> {
+    pub fn execute(&self, func_ctx: &FunctionContext, input: DataBlock) -> Result<DataBlock> {
+        self.execute_with_jit(func_ctx, input, &[])
+    }
+
+    /// Same as `execute`, the map expressions with a compiled [`JitExpr`] at the same
+    /// position in `jit_exprs` are evaluated by it if possible.
+    pub fn execute_with_jit(
+        &self,
+        func_ctx: &FunctionContext,
+        mut input: DataBlock,
+        jit_exprs: &[Option<JitExpr>],
+    ) -> Result<DataBlock> {
         if input.is_empty() {
             return Ok(input);
         }
@@ -67,9 +80,19 @@ impl BlockOperator {
                         None => Ok(input),
                     }
                 } else {
-                    for expr in exprs {
-                        let evaluator = Evaluator::new(&input, func_ctx, &BUILTIN_FUNCTIONS);
-                        let result = evaluator.run(expr)?;
+                    for (i, expr) in exprs.iter().enumerate() {
+                        let jit_result = jit_exprs
+                            .get(i)
+                            .and_then(Option::as_ref)
+                            .and_then(|jit_expr| jit_expr.eval(&input));
+                        let result = match jit_result {
+                            Some(column) => Value::Column(column),
+                            None => {
+                                let evaluator =
+                                    Evaluator::new(&input, func_ctx, &BUILTIN_FUNCTIONS);
+                                evaluator.run(expr)?
+                            }
+                        };
                         let col = BlockEntry::new(expr.data_type().clone(), result);
 
                         input.add_column(col);
@@ -97,6 +120,8 @@ impl BlockOperator {
 pub struct CompoundBlockOperator {
     pub operators: Vec<BlockOperator>,
     pub ctx: FunctionContext,
+    /// Compiled map expressions of each operator, empty unless `enable_expression_jit`.
+    pub jit_exprs: Vec<Vec<Option<JitExpr>>>,
 }
 
 impl CompoundBlockOperator {
@@ -106,7 +131,12 @@ impl CompoundBlockOperator {
         input_num_columns: usize,
     ) -> Self {
         let operators = Self::compact_map(operators, input_num_columns);
-        Self { operators, ctx }
+        let jit_exprs = Self::compile_jit_exprs(&operators, &ctx);
+        Self {
+            operators,
+            ctx,
+            jit_exprs,
+        }
     }
 
     pub fn create(
@@ -117,7 +147,29 @@ impl CompoundBlockOperator {
         operators: Vec<BlockOperator>,
     ) -> Box<dyn Processor> {
         let operators = Self::compact_map(operators, input_num_columns);
-        Transformer::<Self>::create(input_port, output_port, Self { operators, ctx })
+        let jit_exprs = Self::compile_jit_exprs(&operators, &ctx);
+        Transformer::<Self>::create(input_port, output_port, Self {
+            operators,
+            ctx,
+            jit_exprs,
+        })
+    }
+
+    fn compile_jit_exprs(
+        operators: &[BlockOperator],
+        ctx: &FunctionContext,
+    ) -> Vec<Vec<Option<JitExpr>>> {
+        if !ctx.enable_expression_jit {
+            return vec![];
+        }
+
+        operators
+            .iter()
+            .map(|op| match op {
+                BlockOperator::Map { exprs, .. } => exprs.iter().map(JitExpr::compile).collect(),
+                BlockOperator::Project { .. } => vec![],
+            })
+            .collect()
     }
 
     pub fn compact_map(
@@ -159,7 +211,11 @@ impl Transform for CompoundBlockOperator {
     fn transform(&mut self, data_block: DataBlock) -> Result<DataBlock> {
         self.operators
             .iter()
-            .try_fold(data_block, |input, op| op.execute(&self.ctx, input))
+            .enumerate()
+            .try_fold(data_block, |input, (i, op)| {
+                let jit_exprs = self.jit_exprs.get(i).map(Vec::as_slice).unwrap_or(&[]);
+                op.execute_with_jit(&self.ctx, input, jit_exprs)
+            })
     }
 
     fn name(&self) -> String {
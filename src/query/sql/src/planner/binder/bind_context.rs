@@ -37,11 +37,13 @@ use itertools::Itertools;
 use super::AggregateInfo;
 use super::INTERNAL_COLUMN_FACTORY;
 use crate::binder::column_binding::ColumnBinding;
+use crate::binder::column_binding::ColumnOrigin;
 use crate::binder::window::WindowInfo;
 use crate::binder::ColumnBindingBuilder;
 use crate::normalize_identifier;
 use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
+use crate::ColumnEntry;
 use crate::ColumnSet;
 use crate::IndexType;
 use crate::MetadataRef;
@@ -474,6 +476,31 @@ impl BindContext {
         DataSchemaRefExt::create(fields)
     }
 
+    /// Return the base table column of each output column, in the same order as
+    /// `output_schema`. Columns computed by expressions have no origin.
+    pub fn column_origins(&self, metadata: &MetadataRef) -> Vec<Option<ColumnOrigin>> {
+        let metadata = metadata.read();
+        self.columns
+            .iter()
+            .map(|column_binding| {
+                if column_binding.index >= metadata.columns().len() {
+                    return None;
+                }
+                match metadata.column(column_binding.index) {
+                    ColumnEntry::BaseTableColumn(column) if column.path_indices.is_none() => {
+                        let table = metadata.table(column.table_index);
+                        Some(ColumnOrigin {
+                            database: table.database().to_string(),
+                            table: table.name().to_string(),
+                            column: column.column_name.clone(),
+                        })
+                    }
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+
     fn get_internal_column_table_index(
         column_binding: &InternalColumnBinding,
         metadata: MetadataRef,
@@ -33,6 +33,8 @@ use databend_common_ast::ast::PivotValues;
 use databend_common_ast::ast::SelectStmt;
 use databend_common_ast::ast::SelectTarget;
 use databend_common_ast::ast::TableReference;
+use databend_common_ast::ast::Window;
+use databend_common_ast::ast::WindowSpec;
 use databend_common_ast::Span;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -104,7 +106,7 @@ impl Binder {
             self.name_resolution_ctx.unquoted_ident_case_sensitive,
         )
         .with_subquery_executor(self.subquery_executor.clone());
-        let new_stmt = rewriter.rewrite(stmt)?;
+        let new_stmt = rewriter.rewrite(stmt, order_by)?;
         let stmt = new_stmt.as_ref().unwrap_or(stmt);
 
         // Try put window definitions into bind context.
@@ -382,12 +384,95 @@ impl<'a> SelectRewriter<'a> {
         self
     }
 
-    fn rewrite(&mut self, stmt: &SelectStmt) -> Result<Option<SelectStmt>> {
+    fn rewrite(
+        &mut self,
+        stmt: &SelectStmt,
+        order_by: &[OrderByExpr],
+    ) -> Result<Option<SelectStmt>> {
         self.rewrite_pivot(stmt)?;
         self.rewrite_unpivot(stmt)?;
+        self.rewrite_distinct_on(stmt, order_by)?;
         Ok(self.new_stmt.take())
     }
 
+    // Rewrite `SELECT DISTINCT ON (k) ... ORDER BY k, ts DESC` to
+    // `SELECT ... QUALIFY row_number() OVER (PARTITION BY k ORDER BY ts DESC) = 1 ORDER BY k, ts DESC`.
+    // The `row_number() = 1` filter is pushed down into the partition sort as a
+    // per-partition top 1 (see `RulePushDownFilterWindowTopN`), so only the first row
+    // of each group is kept instead of sorting and numbering the whole input.
+    fn rewrite_distinct_on(&mut self, stmt: &SelectStmt, order_by: &[OrderByExpr]) -> Result<()> {
+        let Some(distinct_on) = &stmt.distinct_on else {
+            return Ok(());
+        };
+        if stmt.qualify.is_some() {
+            return Err(ErrorCode::SemanticError(
+                "DISTINCT ON cannot be used together with QUALIFY",
+            )
+            .set_span(stmt.span));
+        }
+        if order_by.iter().any(|order| {
+            matches!(order.expr, Expr::Literal {
+                value: Literal::UInt64(_),
+                ..
+            })
+        }) {
+            return Err(ErrorCode::SemanticError(
+                "ORDER BY position is not supported with DISTINCT ON",
+            )
+            .set_span(stmt.span));
+        }
+        // Same as PostgreSQL, the leading `ORDER BY` items decide the groups,
+        // so they must be the `DISTINCT ON` expressions.
+        if order_by
+            .iter()
+            .zip(distinct_on.iter())
+            .any(|(order, expr)| order.expr.to_string() != expr.to_string())
+        {
+            return Err(ErrorCode::SemanticError(
+                "SELECT DISTINCT ON expressions must match initial ORDER BY expressions",
+            )
+            .set_span(stmt.span));
+        }
+
+        let row_number = Expr::FunctionCall {
+            span: stmt.span,
+            func: FunctionCall {
+                distinct: false,
+                name: Identifier::from_name(stmt.span, "row_number"),
+                args: vec![],
+                params: vec![],
+                window: Some(Window::WindowSpec(WindowSpec {
+                    existing_window_name: None,
+                    partition_by: distinct_on.clone(),
+                    order_by: order_by.iter().skip(distinct_on.len()).cloned().collect(),
+                    window_frame: None,
+                })),
+                lambda: None,
+            },
+        };
+        let qualify = Expr::BinaryOp {
+            span: stmt.span,
+            op: BinaryOperator::Eq,
+            left: Box::new(row_number),
+            right: Box::new(Expr::Literal {
+                span: None,
+                value: Literal::UInt64(1),
+            }),
+        };
+
+        if let Some(ref mut new_stmt) = self.new_stmt {
+            new_stmt.distinct_on = None;
+            new_stmt.qualify = Some(qualify);
+        } else {
+            self.new_stmt = Some(SelectStmt {
+                distinct_on: None,
+                qualify: Some(qualify),
+                ..stmt.clone()
+            });
+        }
+        Ok(())
+    }
+
     fn rewrite_pivot(&mut self, stmt: &SelectStmt) -> Result<()> {
         if stmt.from.len() != 1 || stmt.from[0].pivot().is_none() {
             return Ok(());
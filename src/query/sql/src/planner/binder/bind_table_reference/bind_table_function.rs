@@ -78,6 +78,7 @@ impl Binder {
                 span: *span,
                 hints: None,
                 distinct: false,
+                distinct_on: None,
                 top_n: None,
                 select_list: vec![SelectTarget::AliasedExpr {
                     expr: Box::new(databend_common_ast::ast::Expr::FunctionCall {
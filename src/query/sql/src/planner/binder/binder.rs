@@ -584,17 +584,11 @@ impl<'a> Binder {
             // Dynamic Table
             Statement::CreateDynamicTable(stmt) => self.bind_create_dynamic_table(stmt).await?,
 
-            Statement::CreatePipe(_) => {
-                todo!()
-            }
-            Statement::DescribePipe(_) => {
-                todo!()
-            }
-            Statement::AlterPipe(_) => {
-                todo!()
-            }
-            Statement::DropPipe(_) => {
-                todo!()
+            Statement::CreatePipe(_)
+            | Statement::DescribePipe(_)
+            | Statement::AlterPipe(_)
+            | Statement::DropPipe(_) => {
+                return Err(ErrorCode::Unimplemented("Pipe is not supported yet"));
             }
             Statement::CreateNotification(stmt) => {
                 self.bind_create_notification(stmt).await?
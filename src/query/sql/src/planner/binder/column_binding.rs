@@ -40,6 +40,15 @@ pub struct ColumnBinding {
     pub virtual_computed_expr: Option<String>,
 }
 
+/// The base table column a result column is read from, exposed to clients
+/// as result set metadata.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, Eq, PartialEq)]
+pub struct ColumnOrigin {
+    pub database: String,
+    pub table: String,
+    pub column: String,
+}
+
 const DUMMY_INDEX: usize = usize::MAX;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
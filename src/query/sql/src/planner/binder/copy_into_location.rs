@@ -15,11 +15,14 @@
 use databend_common_ast::ast::quote::display_ident;
 use databend_common_ast::ast::CopyIntoLocationSource;
 use databend_common_ast::ast::CopyIntoLocationStmt;
+use databend_common_ast::ast::Expr;
 use databend_common_ast::ast::Statement;
 use databend_common_ast::parser::parse_sql;
 use databend_common_ast::parser::tokenize_sql;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::UNLOAD_PARTITION_COL_NAME;
+use databend_common_meta_app::principal::FileFormatParams;
 use databend_common_storage::init_stage_operator;
 use opendal::ErrorKind;
 
@@ -51,6 +54,16 @@ impl<'a> Binder {
                 "include_query_id=false can only be set when use_raw_path=true",
             ));
         }
+        if stmt.options.iceberg_metadata && stmt.options.use_raw_path {
+            return Err(ErrorCode::InvalidArgument(
+                "iceberg_metadata=true can not be set when use_raw_path=true",
+            ));
+        }
+        if stmt.partition_by.is_some() && (stmt.options.single || stmt.options.iceberg_metadata) {
+            return Err(ErrorCode::InvalidArgument(
+                "partition by can not be used when single=true or iceberg_metadata=true",
+            ));
+        }
 
         let query = match &stmt.src {
             CopyIntoLocationSource::Table(table) => {
@@ -67,35 +80,35 @@ impl<'a> Binder {
 
                 let quoted_ident_case_sensitive =
                     self.ctx.get_settings().get_quoted_ident_case_sensitive()?;
-                let subquery = format!(
-                    "SELECT * FROM {}.{}.{}{with_options_str}",
+                let from = format!(
+                    "{}.{}.{}{with_options_str}",
                     display_ident(&catalog_name, quoted_ident_case_sensitive, self.dialect),
                     display_ident(&database_name, quoted_ident_case_sensitive, self.dialect),
                     display_ident(&table_name, quoted_ident_case_sensitive, self.dialect),
                 );
-                let tokens = tokenize_sql(&subquery)?;
-                let sub_stmt_msg = parse_sql(&tokens, self.dialect)?;
-                let sub_stmt = sub_stmt_msg.0;
-                match &sub_stmt {
-                    Statement::Query(query) => {
+                let subquery = match &stmt.partition_by {
+                    Some(partition_by) => partitioned_query(partition_by, &from),
+                    None => format!("SELECT * FROM {from}"),
+                };
+                self.bind_copy_source_sql(bind_context, &subquery).await
+            }
+            CopyIntoLocationSource::Query(query) => {
+                self.init_cte(bind_context, &stmt.with)?;
+                match &stmt.partition_by {
+                    Some(partition_by) => {
+                        let subquery = partitioned_query(partition_by, &format!("({query})"));
+                        self.bind_copy_source_sql(bind_context, &subquery).await
+                    }
+                    None => {
                         self.bind_statement(bind_context, &Statement::Query(query.clone()))
                             .await
                     }
-                    _ => {
-                        return Err(ErrorCode::SyntaxException(
-                            "COPY INTO <location> FROM <non-query> is invalid",
-                        ));
-                    }
                 }
             }
-            CopyIntoLocationSource::Query(query) => {
-                self.init_cte(bind_context, &stmt.with)?;
-                self.bind_statement(bind_context, &Statement::Query(query.clone()))
-                    .await
-            }
         }?;
 
-        let (mut stage_info, path) = resolve_file_location(self.ctx.as_ref(), &stmt.dst).await?;
+        let (mut stage_info, mut path) =
+            resolve_file_location(self.ctx.as_ref(), &stmt.dst).await?;
 
         if stmt.options.use_raw_path {
             if path.ends_with("/") {
@@ -120,11 +133,72 @@ impl<'a> Binder {
             stage_info.file_format_params = self.try_resolve_file_format(&stmt.file_format).await?;
         }
 
+        let options = stmt.options.clone();
+        if options.iceberg_metadata {
+            if !matches!(stage_info.file_format_params, FileFormatParams::Parquet(_)) {
+                return Err(ErrorCode::InvalidArgument(
+                    "iceberg_metadata=true can only be set when the file format is parquet",
+                ));
+            }
+            // The unload path is the root of the exported iceberg table.
+            if !path.ends_with('/') {
+                path.push('/');
+            }
+            // Fail fast before writing any data file, the commit of the metadata checks it again.
+            let op = init_stage_operator(&stage_info)?;
+            let version_hint = format!("{path}metadata/version-hint.text");
+            match op.stat(&version_hint).await {
+                Ok(_) => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "iceberg table already exists at '{path}'"
+                    )));
+                }
+                Err(e) => {
+                    if e.kind() != ErrorKind::NotFound {
+                        return Err(e.into());
+                    }
+                }
+            }
+        }
+        if stmt.partition_by.is_some() {
+            if !matches!(stage_info.file_format_params, FileFormatParams::Parquet(_)) {
+                return Err(ErrorCode::InvalidArgument(
+                    "partition by can only be used when the file format is parquet for now",
+                ));
+            }
+        }
+
         Ok(Plan::CopyIntoLocation(CopyIntoLocationPlan {
             stage: Box::new(stage_info),
             path,
             from: Box::new(query),
-            options: stmt.options.clone(),
+            options,
+            partitioned: stmt.partition_by.is_some(),
         }))
     }
+
+    #[async_backtrace::framed]
+    async fn bind_copy_source_sql(
+        &mut self,
+        bind_context: &mut BindContext,
+        sql: &str,
+    ) -> Result<Plan> {
+        let tokens = tokenize_sql(sql)?;
+        let sub_stmt_msg = parse_sql(&tokens, self.dialect)?;
+        let sub_stmt = sub_stmt_msg.0;
+        match &sub_stmt {
+            Statement::Query(_) => self.bind_statement(bind_context, &sub_stmt).await,
+            _ => Err(ErrorCode::SyntaxException(
+                "COPY INTO <location> FROM <non-query> is invalid",
+            )),
+        }
+    }
+}
+
+/// The partition path of each row is appended as the last column,
+/// the unload writer splits the rows by it and drops it from the files.
+fn partitioned_query(partition_by: &Expr, from: &str) -> String {
+    format!(
+        "SELECT *, CAST(({partition_by}) AS STRING NULL) AS `{UNLOAD_PARTITION_COL_NAME}` FROM {from}"
+    )
 }
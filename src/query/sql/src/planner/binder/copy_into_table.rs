@@ -205,6 +205,7 @@ impl<'a> Binder {
                 is_select: false,
                 default_values,
                 copy_into_location_options: Default::default(),
+                is_partitioned_unload: false,
             },
             values_consts: vec![],
             required_source_schema: required_values_schema.clone(),
@@ -365,6 +366,7 @@ impl<'a> Binder {
                 is_select: false,
                 default_values: Some(default_values),
                 copy_into_location_options: Default::default(),
+                is_partitioned_unload: false,
             },
             write_mode,
             query: None,
@@ -511,6 +513,30 @@ impl<'a> Binder {
             )));
         }
 
+        if let Some(path) = &stmt.rejected_rows_path {
+            if stage.copy_options.on_error != OnErrorMode::Continue {
+                return Err(ErrorCode::InvalidArgument(
+                    "REJECTED_ROWS_PATH requires ON_ERROR = CONTINUE",
+                ));
+            }
+            if !matches!(
+                stage.file_format_params,
+                FileFormatParams::Csv(_) | FileFormatParams::Tsv(_) | FileFormatParams::NdJson(_)
+            ) {
+                return Err(ErrorCode::InvalidArgument(format!(
+                    "REJECTED_ROWS_PATH is only supported for CSV, TSV and NDJSON files, but got {}",
+                    stage.file_format_params.get_type()
+                )));
+            }
+            let path = path.trim_matches('/');
+            if path.is_empty() {
+                return Err(ErrorCode::InvalidArgument(
+                    "REJECTED_ROWS_PATH must not be empty",
+                ));
+            }
+            stage.copy_options.rejected_rows_path = Some(format!("{path}/"));
+        }
+
         Ok(())
     }
 
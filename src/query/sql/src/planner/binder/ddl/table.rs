@@ -71,6 +71,7 @@ use databend_common_expression::types::DataType;
 use databend_common_expression::ComputedExpr;
 use databend_common_expression::DataField;
 use databend_common_expression::DataSchemaRefExt;
+use databend_common_expression::ScalarRef;
 use databend_common_expression::TableDataType;
 use databend_common_expression::TableField;
 use databend_common_expression::TableSchema;
@@ -108,6 +109,7 @@ use crate::parse_computed_expr_to_string;
 use crate::parse_default_expr_to_string;
 use crate::planner::semantic::normalize_identifier;
 use crate::planner::semantic::resolve_type_name;
+use crate::planner::semantic::resolve_type_name_by_str;
 use crate::planner::semantic::IdentifierNormalizer;
 use crate::plans::AddColumnOption;
 use crate::plans::AddTableColumnPlan;
@@ -1408,6 +1410,11 @@ impl Binder {
                         "can't add a stored computed column".to_string(),
                     ));
                 }
+                ColumnExpr::AutoIncrement => {
+                    return Err(ErrorCode::SemanticError(
+                        "can't add an AUTOINCREMENT column".to_string(),
+                    ));
+                }
             }
         }
         let comment = column.comment.clone().unwrap_or_default();
@@ -1436,6 +1443,20 @@ impl Binder {
                             parse_default_expr_to_string(self.ctx.clone(), &field, default_expr)?;
                         field = field.with_default_expr(Some(expr));
                     }
+                    ColumnExpr::AutoIncrement => {
+                        if !DataType::from(&schema_data_type)
+                            .remove_nullable()
+                            .is_integer()
+                        {
+                            return Err(ErrorCode::SemanticError(format!(
+                                "AUTOINCREMENT column `{}` must be of an integer type",
+                                name
+                            )));
+                        }
+                        // The values come from a sequence of its own, created along with the table.
+                        let sequence = format!("autoincrement_{}", Uuid::new_v4().simple());
+                        field = field.with_default_expr(Some(format!("nextval({sequence})")));
+                    }
                     _ => has_computed = true,
                 }
             }
@@ -1571,6 +1592,48 @@ impl Binder {
                     Ok((table.schema(), table.field_comments().clone(), None))
                 }
             }
+            CreateTableSource::Template(query) => {
+                let Some(executor) = &self.subquery_executor else {
+                    return Err(ErrorCode::Internal(
+                        "Subquery executor of USING TEMPLATE is not set",
+                    ));
+                };
+                let blocks = executor
+                    .execute_query_with_sql_string(&query.to_string())
+                    .await?;
+                let mut fields = vec![];
+                for block in blocks {
+                    if block.num_columns() < 3 {
+                        return Err(ErrorCode::SemanticError(
+                            "The query of USING TEMPLATE must return the column name, type and nullable",
+                        ));
+                    }
+                    let columns = block.columns();
+                    for row in 0..block.num_rows() {
+                        let name = columns[0].value.index(row).unwrap();
+                        let data_type = columns[1].value.index(row).unwrap();
+                        let nullable = columns[2].value.index(row).unwrap();
+                        let (
+                            ScalarRef::String(name),
+                            ScalarRef::String(data_type),
+                            ScalarRef::Boolean(nullable),
+                        ) = (name, data_type, nullable)
+                        else {
+                            return Err(ErrorCode::SemanticError(
+                                "The query of USING TEMPLATE must return (STRING, STRING, BOOLEAN) rows",
+                            ));
+                        };
+                        let data_type = resolve_type_name_by_str(data_type, !nullable)?;
+                        fields.push(TableField::new(name, data_type));
+                    }
+                }
+                if fields.is_empty() {
+                    return Err(ErrorCode::SemanticError(
+                        "The query of USING TEMPLATE returns no column",
+                    ));
+                }
+                Ok((TableSchemaRefExt::create(fields), vec![], None))
+            }
         }
     }
 
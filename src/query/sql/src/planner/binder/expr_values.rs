@@ -172,6 +172,7 @@ impl BindContext {
         let mut expression_transform = CompoundBlockOperator {
             operators,
             ctx: func_ctx,
+            jit_exprs: vec![],
         };
         let res = expression_transform.transform(one_row_chunk)?;
         let scalars: Vec<Scalar> = res
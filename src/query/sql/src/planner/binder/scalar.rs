@@ -20,6 +20,7 @@ use databend_common_ast::parser::parse_expr;
 use databend_common_ast::parser::tokenize_sql;
 use databend_common_ast::parser::Dialect;
 use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
 use databend_common_expression::DataField;
@@ -30,6 +31,7 @@ use indexmap::IndexMap;
 
 use crate::binder::wrap_cast;
 use crate::binder::CteInfo;
+use crate::default_expr_sequence;
 use crate::planner::binder::BindContext;
 use crate::planner::semantic::NameResolutionContext;
 use crate::planner::semantic::TypeChecker;
@@ -103,6 +105,12 @@ impl<'a> ScalarBinder<'a> {
         schema: &DataSchema,
     ) -> Result<databend_common_expression::Expr> {
         if let Some(default_expr) = field.default_expr() {
+            if default_expr_sequence(default_expr).is_some() {
+                return Err(ErrorCode::SemanticError(format!(
+                    "AUTOINCREMENT column `{}` can not be filled with its default here, leave it out of the column list instead",
+                    field.name()
+                )));
+            }
             let tokens = tokenize_sql(default_expr)?;
             let ast = parse_expr(&tokens, self.dialect)?;
             let (mut scalar, _) = self.bind(&ast)?;
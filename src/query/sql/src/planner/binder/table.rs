@@ -589,7 +589,8 @@ impl Binder {
                     _ => Err(ErrorCode::InvalidArgument(format!(
                         "TimeTravelPoint for 'Timestamp' must resolve to a constant timestamp value. \
                         Provided expression '{}' is not a constant timestamp. \
-                        Ensure the expression is a constant and of type timestamp",
+                        Ensure the expression is a constant and of type timestamp, \
+                        FOR SYSTEM_TIME AS OF does not accept a per-row expression",
                         expr
                     ))),
                 }
@@ -14,9 +14,12 @@
 
 use std::sync::Arc;
 
+use databend_common_ast::ast::ColumnID;
+use databend_common_ast::ast::ColumnRef;
 use databend_common_ast::ast::Expr as AExpr;
 use databend_common_ast::parser::parse_comma_separated_exprs;
 use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::parser::Dialect;
 use databend_common_catalog::catalog::CATALOG_DEFAULT;
 use databend_common_catalog::plan::Filters;
 use databend_common_catalog::table::Table;
@@ -684,10 +687,51 @@ impl Table for DummyTable {
     }
 }
 
+/// Returns the sequence name if the default expr is `nextval(<sequence>)`, which is how
+/// AUTOINCREMENT columns are stored.
+///
+/// Such a default can not be evaluated as a constant, the values are leased from the
+/// meta service for each block when the column is filled.
+pub fn default_expr_sequence(default_expr: &str) -> Option<String> {
+    let tokens = tokenize_sql(default_expr).ok()?;
+    let mut asts = parse_comma_separated_exprs(&tokens, Dialect::PostgreSQL).ok()?;
+    if asts.len() != 1 {
+        return None;
+    }
+    match asts.remove(0) {
+        AExpr::FunctionCall { func, .. }
+            if func.name.name.eq_ignore_ascii_case("nextval") && func.args.len() == 1 =>
+        {
+            match &func.args[0] {
+                AExpr::ColumnRef {
+                    column:
+                        ColumnRef {
+                            database: None,
+                            table: None,
+                            column: ColumnID::Name(ident),
+                        },
+                    ..
+                } => Some(ident.name.clone()),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
 pub fn field_default_value(ctx: Arc<dyn TableContext>, field: &TableField) -> Result<Scalar> {
     let data_type = field.data_type();
     let data_type = DataType::from(data_type);
 
+    // AUTOINCREMENT columns only exist since the table is created, so no block misses them.
+    if field
+        .default_expr()
+        .and_then(|expr| default_expr_sequence(expr))
+        .is_some()
+    {
+        return Ok(Scalar::default_value(&data_type));
+    }
+
     match field.default_expr() {
         Some(default_expr) => {
             let table: Arc<dyn Table> = Arc::new(DummyTable::default());
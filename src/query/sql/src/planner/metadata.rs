@@ -74,6 +74,8 @@ pub struct Metadata {
     table_row_id_index: HashMap<IndexType, IndexType>,
     agg_indexes: HashMap<String, Vec<(u64, String, SExpr)>>,
     max_column_position: usize, // for CSV
+    /// Whether the query is executed locally on the coordinator because it is small enough.
+    small_query: bool,
 }
 
 impl Metadata {
@@ -465,6 +467,14 @@ impl Metadata {
     pub fn get_max_column_position(&self) -> usize {
         self.max_column_position
     }
+
+    pub fn set_small_query(&mut self, small_query: bool) {
+        self.small_query = small_query
+    }
+
+    pub fn is_small_query(&self) -> bool {
+        self.small_query
+    }
 }
 
 #[derive(Clone)]
@@ -56,3 +56,4 @@ pub use rule::DEFAULT_REWRITE_RULES;
 pub use s_expr::get_udf_names;
 pub use s_expr::SExpr;
 pub use util::contains_local_table_scan;
+pub use util::is_small_query;
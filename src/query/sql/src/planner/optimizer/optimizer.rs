@@ -41,6 +41,7 @@ use crate::optimizer::join::SingleToInnerOptimizer;
 use crate::optimizer::rule::TransformResult;
 use crate::optimizer::statistics::CollectStatisticsOptimizer;
 use crate::optimizer::util::contains_local_table_scan;
+use crate::optimizer::util::is_small_query;
 use crate::optimizer::RuleFactory;
 use crate::optimizer::RuleID;
 use crate::optimizer::SExpr;
@@ -280,11 +281,13 @@ pub async fn optimize(mut opt_ctx: OptimizerContext, plan: Plan) -> Result<Plan>
             path,
             from,
             options,
+            partitioned,
         }) => Ok(Plan::CopyIntoLocation(CopyIntoLocationPlan {
             stage,
             path,
             from: Box::new(Box::pin(optimize(opt_ctx, *from)).await?),
             options,
+            partitioned,
         })),
         Plan::CopyIntoTable(mut plan) if !plan.no_file_to_copy => {
             plan.enable_distributed = opt_ctx.enable_distributed_optimization
@@ -402,6 +405,9 @@ pub async fn optimize_query(opt_ctx: &mut OptimizerContext, mut s_expr: SExpr) -
     // Run post rewrite rules
     s_expr = RecursiveOptimizer::new(&[RuleID::SplitAggregate], opt_ctx).run(&s_expr)?;
 
+    // Execute small queries locally on the coordinator, filters have been pushed down to scans.
+    disable_distributed_for_small_query(opt_ctx, &s_expr)?;
+
     // Cost based optimization
     let mut dphyp_optimized = false;
     if opt_ctx.enable_dphyp && opt_ctx.enable_join_reorder {
@@ -456,6 +462,25 @@ pub async fn optimize_query(opt_ctx: &mut OptimizerContext, mut s_expr: SExpr) -
     Ok(s_expr)
 }
 
+fn disable_distributed_for_small_query(
+    opt_ctx: &mut OptimizerContext,
+    s_expr: &SExpr,
+) -> Result<()> {
+    if !opt_ctx.enable_distributed_optimization {
+        return Ok(());
+    }
+    let max_rows = opt_ctx
+        .table_ctx
+        .get_settings()
+        .get_small_query_max_scan_rows()?;
+    if max_rows > 0 && is_small_query(s_expr, max_rows)? {
+        opt_ctx.enable_distributed_optimization = false;
+        opt_ctx.metadata.write().set_small_query(true);
+        info!("Disable distributed optimization due to small query.");
+    }
+    Ok(())
+}
+
 // TODO(leiysky): reuse the optimization logic with `optimize_query`
 async fn get_optimized_memo(opt_ctx: &mut OptimizerContext, mut s_expr: SExpr) -> Result<Memo> {
     if contains_local_table_scan(&s_expr, &opt_ctx.metadata) {
@@ -484,6 +509,8 @@ async fn get_optimized_memo(opt_ctx: &mut OptimizerContext, mut s_expr: SExpr) -
     // Run post rewrite rules
     s_expr = RecursiveOptimizer::new(&[RuleID::SplitAggregate], opt_ctx).run(&s_expr)?;
 
+    disable_distributed_for_small_query(opt_ctx, &s_expr)?;
+
     // Cost based optimization
     let mut dphyp_optimized = false;
     if opt_ctx.enable_dphyp && opt_ctx.enable_join_reorder {
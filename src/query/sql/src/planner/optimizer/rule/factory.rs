@@ -24,6 +24,7 @@ use super::rewrite::RuleFoldCountAggregate;
 use super::rewrite::RuleMergeEvalScalar;
 use super::rewrite::RuleMergeFilter;
 use super::rewrite::RuleNormalizeScalarFilter;
+use super::rewrite::RulePushDownAggregateUnion;
 use super::rewrite::RulePushDownFilterAggregate;
 use super::rewrite::RulePushDownFilterEvalScalar;
 use super::rewrite::RulePushDownFilterJoin;
@@ -67,6 +68,9 @@ impl RuleFactory {
                 ctx.enable_distributed_optimization,
             ))),
             RuleID::PushDownFilterUnion => Ok(Box::new(RulePushDownFilterUnion::new())),
+            RuleID::PushDownAggregateUnion => {
+                Ok(Box::new(RulePushDownAggregateUnion::new(ctx.metadata)))
+            }
             RuleID::PushDownFilterEvalScalar => Ok(Box::new(RulePushDownFilterEvalScalar::new())),
             RuleID::PushDownFilterJoin => Ok(Box::new(RulePushDownFilterJoin::new(ctx.metadata))),
             RuleID::PushDownFilterScan => Ok(Box::new(RulePushDownFilterScan::new(ctx.metadata))),
@@ -24,6 +24,7 @@ mod rule_fold_count_aggregate;
 mod rule_merge_eval_scalar;
 mod rule_merge_filter;
 mod rule_normalize_scalar;
+mod rule_push_down_aggregate_union;
 mod rule_push_down_filter_aggregate;
 mod rule_push_down_filter_eval_scalar;
 mod rule_push_down_filter_join;
@@ -58,6 +59,7 @@ pub use rule_fold_count_aggregate::RuleFoldCountAggregate;
 pub use rule_merge_eval_scalar::RuleMergeEvalScalar;
 pub use rule_merge_filter::RuleMergeFilter;
 pub use rule_normalize_scalar::RuleNormalizeScalarFilter;
+pub use rule_push_down_aggregate_union::RulePushDownAggregateUnion;
 pub use rule_push_down_filter_aggregate::RulePushDownFilterAggregate;
 pub use rule_push_down_filter_eval_scalar::RulePushDownFilterEvalScalar;
 pub use rule_push_down_filter_join::try_push_down_filter_join;
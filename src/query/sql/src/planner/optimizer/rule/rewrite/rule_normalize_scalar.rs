@@ -25,13 +25,128 @@ use crate::optimizer::RuleID;
 use crate::optimizer::SExpr;
 use crate::plans::ConstantExpr;
 use crate::plans::Filter;
+use crate::plans::FunctionCall;
 use crate::plans::RelOp;
 use crate::plans::ScalarExpr;
 
 fn normalize_predicates(predicates: Vec<ScalarExpr>) -> Vec<ScalarExpr> {
-    [remove_true_predicate, normalize_falsy_predicate]
-        .into_iter()
-        .fold(predicates, |acc, f| f(acc))
+    [
+        simplify_boolean_predicate,
+        remove_true_predicate,
+        normalize_falsy_predicate,
+    ]
+    .into_iter()
+    .fold(predicates, |acc, f| f(acc))
+}
+
+fn simplify_boolean_predicate(predicates: Vec<ScalarExpr>) -> Vec<ScalarExpr> {
+    predicates.into_iter().map(simplify_boolean_expr).collect()
+}
+
+fn is_false(expr: &ScalarExpr) -> bool {
+    matches!(
+        expr,
+        ScalarExpr::ConstantExpr(ConstantExpr {
+            value: Scalar::Boolean(false),
+            ..
+        })
+    )
+}
+
+fn boolean_constant(value: bool) -> ScalarExpr {
+    ConstantExpr {
+        span: None,
+        value: Scalar::Boolean(value),
+    }
+    .into()
+}
+
+/// Simplify the boolean operators nested in a predicate bottom-up:
+/// - `x AND TRUE` and `x OR FALSE` => `x`
+/// - `x AND FALSE` => `FALSE`, `x OR TRUE` => `TRUE`
+/// - `NOT TRUE` => `FALSE`, `NOT FALSE` => `TRUE`, `NOT NOT x` => `x`
+/// - `is_true(TRUE)` => `TRUE`, `is_true(FALSE)` => `FALSE`
+///
+/// These hold with three-valued logic too, e.g. `NULL AND TRUE` is `NULL`.
+fn simplify_boolean_expr(expr: ScalarExpr) -> ScalarExpr {
+    let ScalarExpr::FunctionCall(FunctionCall {
+        span,
+        func_name,
+        params,
+        arguments,
+    }) = expr
+    else {
+        return expr;
+    };
+
+    match func_name.as_str() {
+        "and" | "or" if arguments.len() == 2 => {
+            let mut arguments = arguments.into_iter().map(simplify_boolean_expr);
+            let left = arguments.next().unwrap();
+            let right = arguments.next().unwrap();
+            if func_name == "and" {
+                if is_false(&left) || is_false(&right) {
+                    return boolean_constant(false);
+                } else if is_true(&left) {
+                    return right;
+                } else if is_true(&right) {
+                    return left;
+                }
+            } else if is_true(&left) || is_true(&right) {
+                return boolean_constant(true);
+            } else if is_false(&left) {
+                return right;
+            } else if is_false(&right) {
+                return left;
+            }
+            FunctionCall {
+                span,
+                func_name,
+                params,
+                arguments: vec![left, right],
+            }
+            .into()
+        }
+        "is_true" if arguments.len() == 1 => {
+            let argument = simplify_boolean_expr(arguments.into_iter().next().unwrap());
+            if is_true(&argument) || is_false(&argument) {
+                return argument;
+            }
+            FunctionCall {
+                span,
+                func_name,
+                params,
+                arguments: vec![argument],
+            }
+            .into()
+        }
+        "not" if arguments.len() == 1 => {
+            let argument = simplify_boolean_expr(arguments.into_iter().next().unwrap());
+            match argument {
+                argument if is_true(&argument) => boolean_constant(false),
+                argument if is_false(&argument) => boolean_constant(true),
+                ScalarExpr::FunctionCall(FunctionCall {
+                    func_name,
+                    mut arguments,
+                    ..
+                }) if func_name == "not" && arguments.len() == 1 => arguments.pop().unwrap(),
+                argument => FunctionCall {
+                    span,
+                    func_name,
+                    params,
+                    arguments: vec![argument],
+                }
+                .into(),
+            }
+        }
+        _ => FunctionCall {
+            span,
+            func_name,
+            params,
+            arguments,
+        }
+        .into(),
+    }
 }
 
 fn remove_true_predicate(predicates: Vec<ScalarExpr>) -> Vec<ScalarExpr> {
@@ -53,6 +168,7 @@ fn normalize_falsy_predicate(predicates: Vec<ScalarExpr>) -> Vec<ScalarExpr> {
 }
 
 /// Rule to normalize a Filter, including:
+/// - Simplify nested boolean operators with constant operands
 /// - Remove true predicates
 /// - If there is a NULL or FALSE conjunction, replace the
 ///   whole filter with FALSE
@@ -88,12 +204,9 @@ impl Rule for RuleNormalizeScalarFilter {
     ) -> Result<()> {
         let mut filter: Filter = s_expr.plan().clone().try_into()?;
 
-        if filter
-            .predicates
-            .iter()
-            .any(|p| is_true(p) || (is_falsy(p) && filter.predicates.len() > 1))
-        {
-            filter.predicates = normalize_predicates(filter.predicates);
+        let predicates = normalize_predicates(filter.predicates.clone());
+        if predicates != filter.predicates {
+            filter.predicates = predicates;
             state.add_result(SExpr::create_unary(
                 Arc::new(filter.into()),
                 Arc::new(s_expr.child(0)?.clone()),
@@ -0,0 +1,314 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use ahash::HashMap;
+use ahash::HashSet;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_functions::aggregates::AggregateFunctionFactory;
+
+use super::rule_push_down_filter_union::replace_column_binding;
+use crate::binder::ColumnBindingBuilder;
+use crate::optimizer::extract::Matcher;
+use crate::optimizer::rule::Rule;
+use crate::optimizer::rule::TransformResult;
+use crate::optimizer::RuleID;
+use crate::optimizer::SExpr;
+use crate::plans::Aggregate;
+use crate::plans::AggregateFunction;
+use crate::plans::AggregateMode;
+use crate::plans::BoundColumnRef;
+use crate::plans::CastExpr;
+use crate::plans::EvalScalar;
+use crate::plans::Operator;
+use crate::plans::RelOp;
+use crate::plans::ScalarExpr;
+use crate::plans::ScalarItem;
+use crate::plans::UnionAll;
+use crate::IndexType;
+use crate::MetadataRef;
+use crate::Visibility;
+
+/// Push down an aggregate through `UNION ALL`, each branch computes a partial result
+/// and the aggregate above the union merges them:
+/// - `SUM`, `MIN`, `MAX` are merged with the same function
+/// - `COUNT` is merged with `SUM`
+/// - `AVG` is covered too, it has been rewritten to `SUM / COUNT` by `AggregateRewriter` before binding
+///
+/// Input:  Aggregate
+///           \
+///          UnionAll (optionally under a pass-through EvalScalar)
+///           /    \
+///          *      *
+///
+/// Output: Aggregate
+///           \
+///          UnionAll
+///           /    \
+///    Aggregate   Aggregate
+///         |         |
+///         *         *
+pub struct RulePushDownAggregateUnion {
+    id: RuleID,
+    metadata: MetadataRef,
+    matchers: Vec<Matcher>,
+}
+
+impl RulePushDownAggregateUnion {
+    pub fn new(metadata: MetadataRef) -> Self {
+        Self {
+            id: RuleID::PushDownAggregateUnion,
+            metadata,
+            matchers: vec![
+                Matcher::MatchOp {
+                    op_type: RelOp::Aggregate,
+                    children: vec![Matcher::MatchOp {
+                        op_type: RelOp::UnionAll,
+                        children: vec![Matcher::Leaf, Matcher::Leaf],
+                    }],
+                },
+                Matcher::MatchOp {
+                    op_type: RelOp::Aggregate,
+                    children: vec![Matcher::MatchOp {
+                        op_type: RelOp::EvalScalar,
+                        children: vec![Matcher::MatchOp {
+                            op_type: RelOp::UnionAll,
+                            children: vec![Matcher::Leaf, Matcher::Leaf],
+                        }],
+                    }],
+                },
+            ],
+        }
+    }
+}
+
+impl Rule for RulePushDownAggregateUnion {
+    fn id(&self) -> RuleID {
+        self.id
+    }
+
+    fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
+        let agg: Aggregate = s_expr.plan().clone().try_into()?;
+        let mut union_s_expr = s_expr.child(0)?;
+        if union_s_expr.plan().rel_op() == RelOp::EvalScalar {
+            // The binder always adds an `EvalScalar` for group items and aggregate arguments,
+            // it can be skipped if it only passes through the columns of union.
+            let eval_scalar: EvalScalar = union_s_expr.plan().clone().try_into()?;
+            if !eval_scalar.items.iter().all(|item| {
+                matches!(&item.scalar, ScalarExpr::BoundColumnRef(column) if column.column.index == item.index)
+            }) {
+                return Ok(());
+            }
+            union_s_expr = union_s_expr.child(0)?;
+        }
+        let union: UnionAll = union_s_expr.plan().clone().try_into()?;
+
+        if agg.mode != AggregateMode::Initial
+            || agg.grouping_sets.is_some()
+            || agg.rank_limit.is_some()
+            || !union.cte_scan_names.is_empty()
+            || union
+                .left_outputs
+                .iter()
+                .chain(union.right_outputs.iter())
+                .any(|(_, cast)| cast.is_some())
+        {
+            return Ok(());
+        }
+
+        let index_pairs: HashMap<IndexType, IndexType> = union
+            .left_outputs
+            .iter()
+            .zip(union.right_outputs.iter())
+            .map(|(left, right)| (left.0, right.0))
+            .collect();
+        let is_union_column = |scalar: &ScalarExpr| matches!(scalar, ScalarExpr::BoundColumnRef(column) if index_pairs.contains_key(&column.column.index));
+
+        let mut group_columns = HashSet::default();
+        for item in agg.group_items.iter() {
+            let ScalarExpr::BoundColumnRef(column) = &item.scalar else {
+                return Ok(());
+            };
+            if !is_union_column(&item.scalar) || !group_columns.insert(column.column.index) {
+                return Ok(());
+            }
+        }
+
+        let function_factory = AggregateFunctionFactory::instance();
+        let mut merge_functions = Vec::with_capacity(agg.aggregate_functions.len());
+        for item in agg.aggregate_functions.iter() {
+            let ScalarExpr::AggregateFunction(func) = &item.scalar else {
+                return Ok(());
+            };
+            let merge_func_name = match func.func_name.as_str() {
+                "sum" | "min" | "max" => func.func_name.as_str(),
+                "count" => "sum",
+                _ => return Ok(()),
+            };
+            if func.distinct || !func.params.is_empty() || !func.args.iter().all(is_union_column) {
+                return Ok(());
+            }
+            let Ok(merge_func) =
+                function_factory.get(merge_func_name, vec![], vec![(*func.return_type).clone()])
+            else {
+                return Ok(());
+            };
+            merge_functions.push((merge_func_name, merge_func.return_type()?));
+        }
+
+        let mut left_agg = Aggregate {
+            from_distinct: agg.from_distinct,
+            ..Default::default()
+        };
+        let mut right_agg = left_agg.clone();
+        let mut final_agg = Aggregate {
+            aggregate_functions: Vec::with_capacity(agg.aggregate_functions.len()),
+            ..agg.clone()
+        };
+        let mut left_outputs = Vec::with_capacity(union.left_outputs.len());
+        let mut right_outputs = Vec::with_capacity(union.right_outputs.len());
+        let mut casts = Vec::new();
+
+        for item in agg.group_items.iter() {
+            let ScalarExpr::BoundColumnRef(column) = &item.scalar else {
+                unreachable!()
+            };
+            let left_index = column.column.index;
+            let right_index = index_pairs[&left_index];
+            left_agg.group_items.push(ScalarItem {
+                scalar: item.scalar.clone(),
+                index: left_index,
+            });
+            right_agg.group_items.push(ScalarItem {
+                scalar: replace_column_binding(&index_pairs, item.scalar.clone())?,
+                index: right_index,
+            });
+            left_outputs.push((left_index, None));
+            right_outputs.push((right_index, None));
+        }
+
+        let mut metadata = self.metadata.write();
+        for (item, (merge_func_name, return_type)) in
+            agg.aggregate_functions.iter().zip(merge_functions)
+        {
+            let ScalarExpr::AggregateFunction(func) = &item.scalar else {
+                unreachable!()
+            };
+            let partial_type = (*func.return_type).clone();
+            let left_index =
+                metadata.add_derived_column(func.display_name.clone(), partial_type.clone(), None);
+            let right_index =
+                metadata.add_derived_column(func.display_name.clone(), partial_type.clone(), None);
+            left_agg.aggregate_functions.push(ScalarItem {
+                scalar: item.scalar.clone(),
+                index: left_index,
+            });
+            right_agg.aggregate_functions.push(ScalarItem {
+                scalar: replace_column_binding(&index_pairs, item.scalar.clone())?,
+                index: right_index,
+            });
+            left_outputs.push((left_index, None));
+            right_outputs.push((right_index, None));
+
+            // The merged result may have a different type, e.g. `SUM` of counts is nullable,
+            // cast it back to the type of the original aggregate function.
+            let index = if return_type == partial_type {
+                item.index
+            } else {
+                let index = metadata.add_derived_column(
+                    func.display_name.clone(),
+                    return_type.clone(),
+                    None,
+                );
+                casts.push(ScalarItem {
+                    scalar: CastExpr {
+                        span: None,
+                        is_try: false,
+                        argument: Box::new(column_ref(
+                            &func.display_name,
+                            index,
+                            return_type.clone(),
+                        )),
+                        target_type: func.return_type.clone(),
+                    }
+                    .into(),
+                    index: item.index,
+                });
+                index
+            };
+            final_agg.aggregate_functions.push(ScalarItem {
+                scalar: AggregateFunction {
+                    span: func.span,
+                    func_name: merge_func_name.to_string(),
+                    distinct: false,
+                    params: vec![],
+                    args: vec![column_ref(&func.display_name, left_index, partial_type)],
+                    return_type: Box::new(return_type),
+                    display_name: func.display_name.clone(),
+                }
+                .into(),
+                index,
+            });
+        }
+        drop(metadata);
+
+        let union = UnionAll {
+            left_outputs,
+            right_outputs,
+            cte_scan_names: vec![],
+        };
+        let union_s_expr = SExpr::create_binary(
+            Arc::new(union.into()),
+            Arc::new(SExpr::create_unary(
+                Arc::new(left_agg.into()),
+                Arc::new(union_s_expr.child(0)?.clone()),
+            )),
+            Arc::new(SExpr::create_unary(
+                Arc::new(right_agg.into()),
+                Arc::new(union_s_expr.child(1)?.clone()),
+            )),
+        );
+        let mut result = SExpr::create_unary(Arc::new(final_agg.into()), Arc::new(union_s_expr));
+        result.set_applied_rule(&self.id);
+        if !casts.is_empty() {
+            result = SExpr::create_unary(
+                Arc::new(EvalScalar { items: casts }.into()),
+                Arc::new(result),
+            );
+        }
+        state.add_result(result);
+
+        Ok(())
+    }
+
+    fn matchers(&self) -> &[Matcher] {
+        &self.matchers
+    }
+}
+
+fn column_ref(name: &str, index: IndexType, data_type: DataType) -> ScalarExpr {
+    BoundColumnRef {
+        span: None,
+        column: ColumnBindingBuilder::new(
+            name.to_string(),
+            index,
+            Box::new(data_type),
+            Visibility::Visible,
+        )
+        .build(),
+    }
+    .into()
+}
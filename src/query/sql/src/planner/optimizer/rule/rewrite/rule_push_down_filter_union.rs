@@ -112,7 +112,7 @@ impl Rule for RulePushDownFilterUnion {
     }
 }
 
-fn replace_column_binding(
+pub(crate) fn replace_column_binding(
     index_pairs: &HashMap<IndexType, IndexType>,
     mut scalar: ScalarExpr,
 ) -> Result<ScalarExpr> {
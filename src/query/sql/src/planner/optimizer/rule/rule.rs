@@ -54,6 +54,7 @@ pub static DEFAULT_REWRITE_RULES: LazyLock<Vec<RuleID>> = LazyLock::new(|| {
         RuleID::PushDownLimitScan,
         RuleID::SemiToInnerJoin,
         RuleID::FoldCountAggregate,
+        RuleID::PushDownAggregateUnion,
         RuleID::TryApplyAggIndex,
         RuleID::PushDownFilterScan,
         RuleID::PushDownPrewhere, /* PushDownPrwhere should be after all rules except PushDownFilterScan */
@@ -86,6 +87,7 @@ pub enum RuleID {
     PushDownFilterEvalScalar,
     FilterNulls,
     PushDownFilterUnion,
+    PushDownAggregateUnion,
     PushDownFilterJoin,
     PushDownFilterScan,
     PushDownFilterSort,
@@ -125,6 +127,7 @@ impl Display for RuleID {
         match self {
             RuleID::FilterNulls => write!(f, "FilterNulls"),
             RuleID::PushDownFilterUnion => write!(f, "PushDownFilterUnion"),
+            RuleID::PushDownAggregateUnion => write!(f, "PushDownAggregateUnion"),
             RuleID::PushDownFilterEvalScalar => write!(f, "PushDownFilterEvalScalar"),
             RuleID::PushDownFilterJoin => write!(f, "PushDownFilterJoin"),
             RuleID::PushDownFilterScan => write!(f, "PushDownFilterScan"),
@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_exception::Result;
+
+use super::RelExpr;
 use super::SExpr;
 use crate::plans::RelOperator;
 use crate::MetadataRef;
@@ -28,3 +31,31 @@ pub fn contains_local_table_scan(s_expr: &SExpr, metadata: &MetadataRef) -> bool
         }
         || matches!(s_expr.plan(), RelOperator::RecursiveCteScan { .. })
 }
+
+/// Check if every table scan of a query is estimated to read at most `max_rows` rows.
+/// Scans without table statistics are never considered small.
+pub fn is_small_query(s_expr: &SExpr, max_rows: u64) -> Result<bool> {
+    if let RelOperator::Scan(scan) = s_expr.plan() {
+        if scan
+            .statistics
+            .table_stats
+            .as_ref()
+            .and_then(|s| s.num_rows)
+            .is_none()
+        {
+            return Ok(false);
+        }
+        let cardinality = RelExpr::with_s_expr(s_expr)
+            .derive_cardinality()?
+            .cardinality;
+        if cardinality > max_rows as f64 {
+            return Ok(false);
+        }
+    }
+    for child in s_expr.children() {
+        if !is_small_query(child, max_rows)? {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
@@ -227,6 +227,11 @@ impl Planner {
         // Step 3: Bind AST with catalog, and generate a pure logical SExpr
         let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
         let mut enable_planner_cache = self.ctx.get_settings().get_enable_planner_cache()?;
+        let query_str = if settings.get_enable_query_text_redaction()? {
+            stmt.to_redacted_sql()
+        } else {
+            stmt.to_mask_sql()
+        };
         let planner_cache_key = if enable_planner_cache {
             Some(Self::planner_cache_key(&stmt.to_string()))
         } else {
@@ -243,7 +248,7 @@ impl Planner {
                 info!("logical plan from cache, time used: {:?}", start.elapsed());
                 // update for clickhouse handler
                 self.ctx
-                    .attach_query_str(get_query_kind(stmt), stmt.to_mask_sql());
+                    .attach_query_str(get_query_kind(stmt), query_str.clone());
                 return Ok(plan.plan);
             }
             enable_planner_cache = c;
@@ -260,11 +265,10 @@ impl Planner {
 
         // Indicate binder there is no need to collect column statistics for the binding table.
         self.ctx
-            .attach_query_str(get_query_kind(stmt), stmt.to_mask_sql());
+            .attach_query_str(get_query_kind(stmt), query_str.clone());
         let plan = binder.bind(stmt).await?;
         // attach again to avoid the query kind is overwritten by the subquery
-        self.ctx
-            .attach_query_str(get_query_kind(stmt), stmt.to_mask_sql());
+        self.ctx.attach_query_str(get_query_kind(stmt), query_str);
 
         // Step 4: Optimize the SExpr with optimizers, and generate optimized physical SExpr
         let opt_ctx = OptimizerContext::new(self.ctx.clone(), metadata.clone())
@@ -31,6 +31,8 @@ pub struct CopyIntoLocationPlan {
     pub path: String,
     pub from: Box<Plan>,
     pub options: CopyIntoLocationOptions,
+    /// The last column of `from` is the partition path of each row, for `PARTITION BY`.
+    pub partitioned: bool,
 }
 
 impl CopyIntoLocationPlan {
@@ -28,6 +28,7 @@ use super::CreateDictionaryPlan;
 use super::DropDictionaryPlan;
 use super::RenameDictionaryPlan;
 use super::ShowCreateDictionaryPlan;
+use crate::binder::ColumnOrigin;
 use crate::binder::ExplainConfig;
 use crate::optimizer::SExpr;
 use crate::plans::copy_into_location::CopyIntoLocationPlan;
@@ -497,6 +498,19 @@ impl Plan {
         }
     }
 
+    /// Base table origin of each column in `schema()`, `None` for computed columns
+    /// and for plans that are not queries.
+    pub fn column_origins(&self) -> Vec<Option<ColumnOrigin>> {
+        match self {
+            Plan::Query {
+                metadata,
+                bind_context,
+                ..
+            } => bind_context.column_origins(metadata),
+            _ => vec![None; self.schema().num_fields()],
+        }
+    }
+
     pub fn has_result_set(&self) -> bool {
         !self.schema().fields().is_empty()
     }
@@ -624,6 +624,8 @@ pub struct LagLeadFunction {
     pub offset: u64,
     pub default: Option<Box<ScalarExpr>>,
     pub return_type: Box<DataType>,
+    /// Skip NULL values when counting the offset rows.
+    pub ignore_null: bool,
 }
 
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
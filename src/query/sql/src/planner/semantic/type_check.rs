@@ -810,7 +810,15 @@ impl<'a> TypeChecker<'a> {
                         .set_span(*span));
                     }
                     let window = window.as_ref().unwrap();
-                    let rank_window = ["first_value", "first", "last_value", "last", "nth_value"];
+                    let rank_window = [
+                        "first_value",
+                        "first",
+                        "last_value",
+                        "last",
+                        "nth_value",
+                        "lag",
+                        "lead",
+                    ];
                     if !rank_window.contains(&func_name) && window.ignore_nulls.is_some() {
                         return Err(ErrorCode::SemanticError(format!(
                             "window function {func_name} not support IGNORE/RESPECT NULLS option"
@@ -1094,6 +1102,13 @@ impl<'a> TypeChecker<'a> {
             Expr::Tuple { span, exprs, .. } => self.resolve_tuple(*span, exprs)?,
 
             Expr::Hole { .. } => unreachable!("hole is impossible in trivial query"),
+
+            Expr::Placeholder { span } => {
+                return Err(ErrorCode::SemanticError(
+                    "placeholder of a redacted literal can not be evaluated",
+                )
+                .set_span(*span));
+            }
         };
 
         Ok(Box::new((scalar, data_type)))
@@ -1335,6 +1350,27 @@ impl<'a> TypeChecker<'a> {
                     end_bound: WindowFuncFrameBound::Following(None),
                 });
             }
+            // With IGNORE NULLS the offset row can be arbitrarily far away, so the frame
+            // covers all the rows before (lag) or after (lead) the current row.
+            WindowFuncType::LagLead(lag_lead) if lag_lead.ignore_null => {
+                let one = Some(Scalar::Number(NumberScalar::UInt64(1)));
+                let (start_bound, end_bound) = if lag_lead.is_lag {
+                    (
+                        WindowFuncFrameBound::Preceding(None),
+                        WindowFuncFrameBound::Preceding(one),
+                    )
+                } else {
+                    (
+                        WindowFuncFrameBound::Following(one),
+                        WindowFuncFrameBound::Following(None),
+                    )
+                };
+                return Ok(WindowFuncFrame {
+                    units: WindowFuncFrameUnits::Rows,
+                    start_bound,
+                    end_bound,
+                });
+            }
             WindowFuncType::LagLead(lag_lead) if lag_lead.is_lag => {
                 return Ok(WindowFuncFrame {
                     units: WindowFuncFrameUnits::Rows,
@@ -1459,9 +1495,12 @@ impl<'a> TypeChecker<'a> {
         };
 
         match func_name {
-            "lag" | "lead" => {
-                self.resolve_lag_lead_window_function(func_name, &arguments, &arg_types)
-            }
+            "lag" | "lead" => self.resolve_lag_lead_window_function(
+                func_name,
+                &arguments,
+                &arg_types,
+                ignore_null,
+            ),
             "first_value" | "first" | "last_value" | "last" | "nth_value" => self
                 .resolve_nth_value_window_function(func_name, &arguments, &arg_types, ignore_null),
             "ntile" => self.resolve_ntile_window_function(&arguments),
@@ -1476,6 +1515,7 @@ impl<'a> TypeChecker<'a> {
         func_name: &str,
         args: &[ScalarExpr],
         arg_types: &[DataType],
+        ignore_null: bool,
     ) -> Result<WindowFuncType> {
         if args.is_empty() || args.len() > 3 {
             return Err(ErrorCode::InvalidArgument(format!(
@@ -1535,6 +1575,8 @@ impl<'a> TypeChecker<'a> {
             offset: offset.unsigned_abs(),
             default: cast_default,
             return_type: Box::new(return_type),
+            // `lag(x, 0)` is the current row itself.
+            ignore_null: ignore_null && offset != 0,
         }))
     }
 
@@ -1742,6 +1784,28 @@ impl<'a> TypeChecker<'a> {
             params
         };
 
+        // Convert the percentile of approx_percentile and approx_percentile_array to params
+        let (params, arguments, arg_types) = if (func_name
+            .eq_ignore_ascii_case("approx_percentile")
+            || func_name.eq_ignore_ascii_case("approx_percentile_array"))
+            && arguments.len() == 2
+            && params.is_empty()
+        {
+            let percentile = ConstantExpr::try_from(arguments[1].clone()).map_err(|_| {
+                ErrorCode::SemanticError(format!(
+                    "The percentile of `{func_name}` must be a constant"
+                ))
+                .set_span(span)
+            })?;
+            let params = match percentile.value {
+                Scalar::Array(column) => column.iter().map(|v| v.to_owned()).collect(),
+                value => vec![value],
+            };
+            (params, arguments[..1].to_vec(), arg_types[..1].to_vec())
+        } else {
+            (params, arguments, arg_types)
+        };
+
         // Rewrite `xxx(distinct)` to `xxx_distinct(...)`
         let (func_name, distinct) = if func_name.eq_ignore_ascii_case("count") && distinct {
             ("count_distinct", false)
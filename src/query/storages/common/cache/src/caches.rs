@@ -33,6 +33,9 @@ use crate::CacheAccessor;
 use crate::InMemoryLruCache;
 
 /// In memory object cache of SegmentInfo
+///
+/// The block metas are kept in their compressed on-disk encoding, and only decoded when
+/// the blocks of the segment are pruned.
 pub type CompactSegmentInfoCache = InMemoryLruCache<CompactSegmentInfo>;
 
 pub type BlockMetaCache = InMemoryLruCache<Vec<Arc<BlockMeta>>>;
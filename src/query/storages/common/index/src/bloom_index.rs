@@ -14,6 +14,7 @@
 
 use std::collections::BTreeMap;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::ops::Deref;
 use std::sync::Arc;
 
@@ -26,6 +27,7 @@ use databend_common_expression::converts::datavalues::scalar_to_datavalue;
 use databend_common_expression::eval_function;
 use databend_common_expression::types::boolean::BooleanDomain;
 use databend_common_expression::types::nullable::NullableDomain;
+use databend_common_expression::types::string::StringColumnBuilder;
 use databend_common_expression::types::AnyType;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::MapType;
@@ -65,6 +67,9 @@ use crate::filters::Xor8Builder;
 use crate::filters::Xor8Filter;
 use crate::Index;
 
+/// Number of characters of the substrings kept by the ngram filter of a string column.
+pub const NGRAM_SIZE: usize = 3;
+
 #[derive(Clone)]
 pub struct BloomIndexMeta {
     pub columns: Vec<(String, SingleColumnMeta)>,
@@ -130,6 +135,9 @@ impl TryFrom<FileMetaData> for BloomIndexMeta {
 ///         |  123456789abcd |  ac2345bcd   |
 ///         +----------------+--------------+
 /// ```
+///
+/// String columns in the ngram columns also get an `Ngram(name)` filter of their
+/// `NGRAM_SIZE` character substrings, which is used to prune `LIKE '%substr%'`.
 pub struct BloomIndex {
     pub func_ctx: FunctionContext,
 
@@ -185,6 +193,7 @@ impl BloomIndex {
         version: u64,
         block: &DataBlock,
         bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+        ngram_columns_map: BTreeMap<FieldIndex, TableField>,
     ) -> Result<Option<Self>> {
         // TODO refactor :
         // if only current version is allowed, just use the current version
@@ -267,6 +276,12 @@ impl BloomIndex {
                 }
             };
 
+            let ngram_filter = if ngram_columns_map.contains_key(&index) {
+                Self::build_ngram_filter(&func_ctx, &column)?
+            } else {
+                None
+            };
+
             let (column, validity) =
                 Self::calculate_nullable_column_digest(&func_ctx, &column, &data_type)?;
 
@@ -297,6 +312,14 @@ impl BloomIndex {
             let filter_name = Self::build_filter_column_name(version, &field)?;
             filter_fields.push(TableField::new(&filter_name, TableDataType::Binary));
             filters.push(Arc::new(filter));
+
+            if let (Some(filter), Some(filter_name)) = (
+                ngram_filter,
+                Self::build_ngram_filter_column_name(version, &field)?,
+            ) {
+                filter_fields.push(TableField::new(&filter_name, TableDataType::Binary));
+                filters.push(Arc::new(filter));
+            }
         }
 
         if filter_fields.is_empty() {
@@ -341,6 +364,42 @@ impl BloomIndex {
         let mut new_col_id = 1;
         let mut domains = ConstantFolder::full_input_domains(&expr);
 
+        // If the column doesn't contain the constant,
+        // we rewrite the expression to a new column with `false` domain.
+        let mut new_false_column = |span: Span, col_name: &str, return_type: &DataType| {
+            let new_col_name = format!("__bloom_column_{}_{}", col_name, new_col_id);
+            new_col_id += 1;
+
+            let bool_domain = Domain::Boolean(BooleanDomain {
+                has_false: true,
+                has_true: false,
+            });
+            let new_domain = if return_type.is_nullable() {
+                // generate `has_null` based on the `null_count` in column statistics.
+                let has_null = match data_schema.column_id_of(col_name) {
+                    Ok(col_id) => match column_stats.get(&col_id) {
+                        Some(stat) => stat.null_count > 0,
+                        None => true,
+                    },
+                    Err(_) => true,
+                };
+                Domain::Nullable(NullableDomain {
+                    has_null,
+                    value: Some(Box::new(bool_domain)),
+                })
+            } else {
+                bool_domain
+            };
+            domains.insert(new_col_name.clone(), new_domain);
+
+            Expr::ColumnRef {
+                span,
+                id: new_col_name.clone(),
+                data_type: return_type.clone(),
+                display_name: new_col_name,
+            }
+        };
+
         visit_expr_column_eq_constant(
             &mut expr,
             &mut |span, col_name, scalar, ty, return_type| {
@@ -349,47 +408,32 @@ impl BloomIndex {
                     data_schema.field_with_name(col_name)?,
                 )?;
 
-                // If the column doesn't contain the constant,
-                // we rewrite the expression to a new column with `false` domain.
                 if self.find(filter_column, scalar, ty, scalar_map)? == FilterEvalResult::MustFalse
                 {
-                    let new_col_name = format!("__bloom_column_{}_{}", col_name, new_col_id);
-                    new_col_id += 1;
-
-                    let bool_domain = Domain::Boolean(BooleanDomain {
-                        has_false: true,
-                        has_true: false,
-                    });
-                    let new_domain = if return_type.is_nullable() {
-                        // generate `has_null` based on the `null_count` in column statistics.
-                        let has_null = match data_schema.column_id_of(col_name) {
-                            Ok(col_id) => match column_stats.get(&col_id) {
-                                Some(stat) => stat.null_count > 0,
-                                None => true,
-                            },
-                            Err(_) => true,
-                        };
-                        Domain::Nullable(NullableDomain {
-                            has_null,
-                            value: Some(Box::new(bool_domain)),
-                        })
-                    } else {
-                        bool_domain
-                    };
-                    domains.insert(new_col_name.clone(), new_domain);
-
-                    Ok(Some(Expr::ColumnRef {
-                        span,
-                        id: new_col_name.clone(),
-                        data_type: return_type.clone(),
-                        display_name: new_col_name,
-                    }))
+                    Ok(Some(new_false_column(span, col_name, return_type)))
                 } else {
                     Ok(None)
                 }
             },
         )?;
 
+        visit_expr_column_like_constant(&mut expr, &mut |span, col_name, pattern, return_type| {
+            let Some(filter_column) = Self::build_ngram_filter_column_name(
+                self.version,
+                data_schema.field_with_name(col_name)?,
+            )?
+            else {
+                return Ok(None);
+            };
+
+            if self.find_ngrams(&filter_column, pattern, scalar_map)? == FilterEvalResult::MustFalse
+            {
+                Ok(Some(new_false_column(span, col_name, return_type)))
+            } else {
+                Ok(None)
+            }
+        })?;
+
         let (new_expr, _) =
             ConstantFolder::fold_with_domain(&expr, &domains, &self.func_ctx, &BUILTIN_FUNCTIONS);
 
@@ -487,6 +531,25 @@ impl BloomIndex {
         Ok(cols)
     }
 
+    /// Find all columns that match the pattern of `col LIKE '<pattern>'` in the expression,
+    /// together with the ngrams that a matching row must contain.
+    pub fn find_like_columns(
+        expr: &Expr<String>,
+        fields: Vec<TableField>,
+    ) -> Result<Vec<(TableField, Vec<String>)>> {
+        let mut cols = Vec::new();
+        visit_expr_column_like_constant(&mut expr.clone(), &mut |_, col_name, pattern, _| {
+            if let Some(v) = fields.iter().find(|f: &&TableField| f.name() == col_name) {
+                let ngrams = like_pattern_ngrams(pattern);
+                if !ngrams.is_empty() {
+                    cols.push((v.clone(), ngrams));
+                }
+            }
+            Ok(None)
+        })?;
+        Ok(cols)
+    }
+
     /// For every applicable column, we will create a filter.
     /// The filter will be stored with field name 'Bloom(column_name)'
     pub fn build_filter_column_name(version: u64, field: &TableField) -> Result<String> {
@@ -502,6 +565,96 @@ impl BloomIndex {
         }
     }
 
+    /// The ngram filter is stored with field name 'Ngram(column_id)',
+    /// index files before version 4 have no ngram filter.
+    pub fn build_ngram_filter_column_name(
+        version: u64,
+        field: &TableField,
+    ) -> Result<Option<String>> {
+        let index_version = BlockBloomFilterIndexVersion::try_from(version)?;
+        match index_version {
+            BlockBloomFilterIndexVersion::V4(_) => {
+                Ok(Some(format!("Ngram({})", field.column_id())))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// The `NGRAM_SIZE` character substrings of `s`.
+    pub fn ngrams(s: &str) -> Vec<&str> {
+        let offsets = s
+            .char_indices()
+            .map(|(i, _)| i)
+            .chain(std::iter::once(s.len()))
+            .collect::<Vec<_>>();
+        (0..offsets.len().saturating_sub(NGRAM_SIZE))
+            .map(|i| &s[offsets[i]..offsets[i + NGRAM_SIZE]])
+            .collect()
+    }
+
+    /// Build a filter of the ngrams of a string column, the null rows are ignored.
+    fn build_ngram_filter(
+        func_ctx: &FunctionContext,
+        column: &Column,
+    ) -> Result<Option<Xor8Filter>> {
+        let Column::String(strings) = column.remove_nullable() else {
+            return Ok(None);
+        };
+
+        let mut ngrams = HashSet::new();
+        for s in strings.iter() {
+            ngrams.extend(Self::ngrams(s));
+        }
+        if ngrams.is_empty() {
+            return Ok(None);
+        }
+
+        let mut builder = StringColumnBuilder::with_capacity(ngrams.len());
+        for ngram in ngrams {
+            builder.put_str(ngram);
+            builder.commit_row();
+        }
+        let digests = Self::calculate_column_digest(
+            func_ctx,
+            &Column::String(builder.build()),
+            &DataType::String,
+            &DataType::Number(NumberDataType::UInt64),
+        )?;
+        let digests = UInt64Type::try_downcast_column(&digests).unwrap();
+
+        let mut filter_builder = Xor8Builder::create();
+        filter_builder.add_digests(digests.deref());
+        Ok(Some(filter_builder.build()?))
+    }
+
+    fn find_ngrams(
+        &self,
+        filter_column: &str,
+        pattern: &str,
+        scalar_map: &HashMap<Scalar, u64>,
+    ) -> Result<FilterEvalResult> {
+        if !self.filter_schema.has_field(filter_column) {
+            // The column doesn't have a ngram filter.
+            return Ok(FilterEvalResult::Uncertain);
+        }
+
+        let idx = self.filter_schema.index_of(filter_column)?;
+        let filter = &self.filters[idx];
+
+        // A row matching the pattern contains every ngram of its literal parts.
+        let missing = like_pattern_ngrams(pattern).into_iter().any(|ngram| {
+            scalar_map
+                .get(&Scalar::String(ngram))
+                .is_some_and(|digest| !filter.contains_digest(*digest))
+        });
+
+        if missing {
+            Ok(FilterEvalResult::MustFalse)
+        } else {
+            Ok(FilterEvalResult::Uncertain)
+        }
+    }
+
     fn find(
         &self,
         filter_column: &str,
@@ -541,6 +694,10 @@ impl BloomIndex {
         Xor8Filter::supported_type(&data_type)
     }
 
+    pub fn supported_ngram_type(data_type: &TableDataType) -> bool {
+        data_type.remove_nullable() == TableDataType::String
+    }
+
     /// Checks if the average length of a string column exceeds 256 bytes.
     /// If it does, the bloom index for the column will not be established.
     fn check_large_string(column: &Column) -> bool {
@@ -700,6 +857,68 @@ fn visit_expr_column_eq_constant(
     Ok(())
 }
 
+/// The distinct ngrams of the literal parts of a `LIKE` pattern.
+fn like_pattern_ngrams(pattern: &str) -> Vec<String> {
+    // The literal parts are ambiguous with escaped wildcards, don't use them.
+    if pattern.contains('\\') {
+        return vec![];
+    }
+    let mut ngrams = pattern
+        .split(|c| c == '%' || c == '_')
+        .flat_map(BloomIndex::ngrams)
+        .map(|ngram| ngram.to_string())
+        .collect::<Vec<_>>();
+    ngrams.sort();
+    ngrams.dedup();
+    ngrams
+}
+
+fn visit_expr_column_like_constant(
+    expr: &mut Expr<String>,
+    visitor: &mut impl FnMut(Span, &str, &str, &DataType) -> Result<Option<Expr<String>>>,
+) -> Result<()> {
+    // Find patterns like `Column LIKE <constant>`.
+    if let Expr::FunctionCall {
+        span,
+        id,
+        args,
+        return_type,
+        ..
+    } = expr
+    {
+        if id.name() == "like" {
+            if let [
+                Expr::ColumnRef { id, .. },
+                Expr::Constant {
+                    scalar: Scalar::String(pattern),
+                    ..
+                },
+            ] = args.as_slice()
+            {
+                if let Some(new_expr) = visitor(*span, id, pattern, return_type)? {
+                    *expr = new_expr;
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    // Otherwise, rewrite sub expressions.
+    match expr {
+        Expr::Cast { expr, .. } => {
+            visit_expr_column_like_constant(expr, visitor)?;
+        }
+        Expr::FunctionCall { args, .. } => {
+            for arg in args.iter_mut() {
+                visit_expr_column_like_constant(arg, visitor)?;
+            }
+        }
+        _ => (),
+    }
+
+    Ok(())
+}
+
 fn visit_map_column(
     span: Span,
     args: &[Expr<String>],
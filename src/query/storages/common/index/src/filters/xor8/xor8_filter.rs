@@ -146,7 +146,11 @@ impl Index for Xor8Filter {
         }
         matches!(
             inner_type,
-            DataType::Number(_) | DataType::String | DataType::Timestamp | DataType::Date
+            DataType::Number(_)
+                | DataType::Decimal(_)
+                | DataType::String
+                | DataType::Timestamp
+                | DataType::Date
         )
     }
 }
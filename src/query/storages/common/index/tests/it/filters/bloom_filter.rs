@@ -20,6 +20,10 @@ use databend_common_arrow::arrow::buffer::Buffer;
 use databend_common_exception::Result;
 use databend_common_expression::type_check::check_function;
 use databend_common_expression::types::array::ArrayColumn;
+use databend_common_expression::types::decimal::Decimal128Type;
+use databend_common_expression::types::decimal::DecimalDataType;
+use databend_common_expression::types::decimal::DecimalScalar;
+use databend_common_expression::types::decimal::DecimalSize;
 use databend_common_expression::types::map::KvColumn;
 use databend_common_expression::types::map::KvPair;
 use databend_common_expression::types::number::NumberScalar;
@@ -154,6 +158,7 @@ fn test_bloom_filter() -> Result<()> {
         LatestBloom::VERSION,
         &block,
         bloom_columns,
+        BTreeMap::new(),
     )?
     .unwrap();
 
@@ -326,6 +331,7 @@ fn test_specify_bloom_filter() -> Result<()> {
         LatestBloom::VERSION,
         &block,
         bloom_columns,
+        BTreeMap::new(),
     )?
     .unwrap();
 
@@ -366,6 +372,7 @@ fn test_string_bloom_filter() -> Result<()> {
         LatestBloom::VERSION,
         &block,
         bloom_columns,
+        BTreeMap::new(),
     )?
     .unwrap();
 
@@ -384,6 +391,159 @@ fn test_string_bloom_filter() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_decimal_bloom_filter() -> Result<()> {
+    let size = DecimalSize {
+        precision: 10,
+        scale: 2,
+    };
+    let schema = Arc::new(TableSchema::new(vec![
+        TableField::new("0", TableDataType::Number(NumberDataType::UInt8)),
+        TableField::new(
+            "1",
+            TableDataType::Decimal(DecimalDataType::Decimal128(size)),
+        ),
+    ]));
+
+    let blocks = [DataBlock::new_from_columns(vec![
+        UInt8Type::from_data(vec![1, 2]),
+        Decimal128Type::from_data_with_size(vec![123i128, 456i128], size),
+    ])];
+    let block = DataBlock::concat(&blocks)?;
+
+    let bloom_columns = bloom_columns_map(schema.clone(), vec![0, 1]);
+    let fields = bloom_columns.values().cloned().collect::<Vec<_>>();
+    let index = BloomIndex::try_create(
+        FunctionContext::default(),
+        LatestBloom::VERSION,
+        &block,
+        bloom_columns,
+        BTreeMap::new(),
+    )?
+    .unwrap();
+
+    let ty = DataType::Decimal(DecimalDataType::Decimal128(size));
+    assert_eq!(
+        FilterEvalResult::Uncertain,
+        eval_index(
+            &index,
+            "1",
+            fields.clone(),
+            schema.clone(),
+            Scalar::Decimal(DecimalScalar::Decimal128(123, size)),
+            ty.clone()
+        )
+    );
+    assert_eq!(
+        FilterEvalResult::MustFalse,
+        eval_index(
+            &index,
+            "1",
+            fields,
+            schema,
+            Scalar::Decimal(DecimalScalar::Decimal128(789, size)),
+            ty
+        )
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_ngram_bloom_filter() -> Result<()> {
+    let schema = Arc::new(TableSchema::new(vec![
+        TableField::new("0", TableDataType::Number(NumberDataType::UInt8)),
+        TableField::new("1", TableDataType::String),
+    ]));
+
+    let blocks = [DataBlock::new_from_columns(vec![
+        UInt8Type::from_data(vec![1, 2]),
+        StringType::from_data(vec!["databend", "ab"]),
+    ])];
+    let block = DataBlock::concat(&blocks)?;
+
+    let bloom_columns = bloom_columns_map(schema.clone(), vec![0, 1]);
+    let ngram_columns = bloom_columns_map(schema.clone(), vec![1]);
+    let fields = ngram_columns.values().cloned().collect::<Vec<_>>();
+    let index = BloomIndex::try_create(
+        FunctionContext::default(),
+        LatestBloom::VERSION,
+        &block,
+        bloom_columns,
+        ngram_columns,
+    )?
+    .unwrap();
+
+    assert!(index.filter_schema.has_field("Ngram(1)"));
+
+    for (pattern, expected) in [
+        ("%tab%", FilterEvalResult::Uncertain),
+        ("data%end", FilterEvalResult::Uncertain),
+        ("%d_tab%", FilterEvalResult::Uncertain),
+        ("%xyz%", FilterEvalResult::MustFalse),
+        ("%data%bendx%", FilterEvalResult::MustFalse),
+        // patterns without a full ngram can't be pruned
+        ("%ab%", FilterEvalResult::Uncertain),
+        ("%x_y%", FilterEvalResult::Uncertain),
+        // escaped wildcards are not used
+        ("%xyz\\%%", FilterEvalResult::Uncertain),
+    ] {
+        assert_eq!(
+            expected,
+            eval_like_index(&index, "1", fields.clone(), schema.clone(), pattern),
+            "pattern: {pattern}"
+        );
+    }
+
+    Ok(())
+}
+
+fn eval_like_index(
+    index: &BloomIndex,
+    col_name: &str,
+    fields: Vec<TableField>,
+    schema: Arc<TableSchema>,
+    pattern: &str,
+) -> FilterEvalResult {
+    let expr = check_function(
+        None,
+        "like",
+        &[],
+        &[
+            Expr::ColumnRef {
+                span: None,
+                id: col_name.to_string(),
+                data_type: DataType::String,
+                display_name: col_name.to_string(),
+            },
+            Expr::Constant {
+                span: None,
+                scalar: Scalar::String(pattern.to_string()),
+                data_type: DataType::String,
+            },
+        ],
+        &BUILTIN_FUNCTIONS,
+    )
+    .unwrap();
+
+    let like_query_cols = BloomIndex::find_like_columns(&expr, fields).unwrap();
+
+    let mut scalar_map = HashMap::<Scalar, u64>::new();
+    let func_ctx = FunctionContext::default();
+    for (_, ngrams) in like_query_cols.iter() {
+        for ngram in ngrams {
+            let scalar = Scalar::String(ngram.clone());
+            let digest =
+                BloomIndex::calculate_scalar_digest(&func_ctx, &scalar, &DataType::String).unwrap();
+            scalar_map.insert(scalar, digest);
+        }
+    }
+    let column_stats = StatisticsOfColumns::new();
+    index
+        .apply(expr, &scalar_map, &column_stats, schema)
+        .unwrap()
+}
+
 fn eval_index(
     index: &BloomIndex,
     col_name: &str,
@@ -21,6 +21,8 @@ use databend_common_exception::Result;
 pub struct ReadSettings {
     pub storage_io_min_bytes_for_seek: u64,
     pub storage_io_max_page_bytes_for_read: u64,
+    /// Whether to read table data from the data cache.
+    pub enable_cache: bool,
 }
 
 impl ReadSettings {
@@ -32,6 +34,7 @@ impl ReadSettings {
             storage_io_max_page_bytes_for_read: ctx
                 .get_settings()
                 .get_storage_io_max_page_bytes_for_read()?,
+            enable_cache: ctx.get_settings().get_enable_table_data_cache()?,
         })
     }
 }
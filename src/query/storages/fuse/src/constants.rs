@@ -19,6 +19,10 @@ pub const FUSE_OPT_KEY_ROW_PER_PAGE: &str = "row_per_page";
 pub const FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD: &str = "row_avg_depth_threshold";
 
 pub const FUSE_OPT_KEY_DATA_RETENTION_PERIOD_IN_HOURS: &str = "data_retention_period_in_hours";
+pub const FUSE_OPT_KEY_MAX_CONCURRENT_WRITES: &str = "max_concurrent_writes";
+pub const FUSE_OPT_KEY_ENABLE_AUTO_COMPACTION: &str = "enable_auto_compaction";
+pub const FUSE_OPT_KEY_AUTO_COMPACTION_BLOCK_COUNT: &str = "auto_compaction_block_count";
+pub const FUSE_OPT_KEY_ENABLE_NGRAM_BLOOM_INDEX: &str = "enable_ngram_bloom_index";
 
 pub const FUSE_TBL_BLOCK_PREFIX: &str = "_b";
 pub const FUSE_TBL_BLOCK_INDEX_PREFIX: &str = "_i";
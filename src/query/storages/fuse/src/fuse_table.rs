@@ -44,7 +44,9 @@ use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
 use databend_common_expression::BlockThresholds;
 use databend_common_expression::ColumnId;
+use databend_common_expression::FieldIndex;
 use databend_common_expression::RemoteExpr;
+use databend_common_expression::TableField;
 use databend_common_expression::ORIGIN_BLOCK_ID_COL_NAME;
 use databend_common_expression::ORIGIN_BLOCK_ROW_NUM_COL_NAME;
 use databend_common_expression::ORIGIN_VERSION_COL_NAME;
@@ -66,6 +68,7 @@ use databend_common_storage::DataOperator;
 use databend_common_storage::StorageMetrics;
 use databend_common_storage::StorageMetricsLayer;
 use databend_storages_common_cache::LoadParams;
+use databend_storages_common_index::BloomIndex;
 use databend_storages_common_table_meta::meta::parse_storage_prefix;
 use databend_storages_common_table_meta::meta::ClusterKey;
 use databend_storages_common_table_meta::meta::CompactSegmentInfo;
@@ -112,6 +115,7 @@ use crate::DEFAULT_ROW_PER_PAGE_FOR_BLOCKING;
 use crate::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
 use crate::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
 use crate::FUSE_OPT_KEY_DATA_RETENTION_PERIOD_IN_HOURS;
+use crate::FUSE_OPT_KEY_ENABLE_NGRAM_BLOOM_INDEX;
 use crate::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use crate::FUSE_OPT_KEY_ROW_PER_PAGE;
 use crate::FUSE_TBL_LAST_SNAPSHOT_HINT;
@@ -440,6 +444,30 @@ impl FuseTable {
         self.bloom_index_cols.clone()
     }
 
+    pub fn enable_ngram_bloom_index(&self) -> bool {
+        self.table_info
+            .options()
+            .get(FUSE_OPT_KEY_ENABLE_NGRAM_BLOOM_INDEX)
+            .and_then(|v| v.to_lowercase().parse::<bool>().ok())
+            .unwrap_or(false)
+    }
+
+    /// The string columns of the bloom index columns that also get a ngram filter,
+    /// empty unless `enable_ngram_bloom_index` is set.
+    pub fn ngram_index_fields(
+        &self,
+        bloom_columns_map: &BTreeMap<FieldIndex, TableField>,
+    ) -> BTreeMap<FieldIndex, TableField> {
+        if !self.enable_ngram_bloom_index() {
+            return BTreeMap::new();
+        }
+        bloom_columns_map
+            .iter()
+            .filter(|(_, field)| BloomIndex::supported_ngram_type(field.data_type()))
+            .map(|(index, field)| (*index, field.clone()))
+            .collect()
+    }
+
     // Check if table is attached.
     pub fn is_table_attached(table_meta_options: &BTreeMap<String, String>) -> bool {
         table_meta_options
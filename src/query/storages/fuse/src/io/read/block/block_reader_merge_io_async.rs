@@ -44,8 +44,14 @@ impl BlockReader {
 
         let mut ranges = vec![];
         // for async read, try using table data cache (if enabled in settings)
-        let column_data_cache = CacheManager::instance().get_table_data_cache();
-        let column_array_cache = CacheManager::instance().get_table_data_array_cache();
+        let (column_data_cache, column_array_cache) = if settings.enable_cache {
+            (
+                CacheManager::instance().get_table_data_cache(),
+                CacheManager::instance().get_table_data_array_cache(),
+            )
+        } else {
+            (None, None)
+        };
         let mut cached_column_data = vec![];
         let mut cached_column_array = vec![];
 
@@ -35,7 +35,11 @@ impl BlockReader {
         ignore_column_ids: &Option<HashSet<ColumnId>>,
     ) -> Result<BlockReadResult> {
         let part = FuseBlockPartInfo::from_part(part)?;
-        let column_array_cache = CacheManager::instance().get_table_data_array_cache();
+        let column_array_cache = if settings.enable_cache {
+            CacheManager::instance().get_table_data_array_cache()
+        } else {
+            None
+        };
 
         let mut ranges = vec![];
         let mut cached_column_array = vec![];
@@ -134,6 +134,7 @@ pub struct BloomIndexBuilder {
     pub table_dal: Operator,
     pub storage_format: FuseStorageFormat,
     pub bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+    pub ngram_columns_map: BTreeMap<FieldIndex, TableField>,
 }
 
 impl BloomIndexBuilder {
@@ -147,6 +148,7 @@ impl BloomIndexBuilder {
             bloom_location.1,
             block,
             self.bloom_columns_map.clone(),
+            self.ngram_columns_map.clone(),
         )?;
 
         match maybe_bloom_index {
@@ -219,6 +221,7 @@ impl BloomIndexState {
         block: &DataBlock,
         location: Location,
         bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+        ngram_columns_map: BTreeMap<FieldIndex, TableField>,
     ) -> Result<Option<Self>> {
         // write index
         let maybe_bloom_index = BloomIndex::try_create(
@@ -226,6 +229,7 @@ impl BloomIndexState {
             location.1,
             block,
             bloom_columns_map,
+            ngram_columns_map,
         )?;
         if let Some(bloom_index) = maybe_bloom_index {
             Ok(Some(Self::from_bloom_index(&bloom_index, location)?))
@@ -338,6 +342,7 @@ pub struct BlockBuilder {
     pub write_settings: WriteSettings,
     pub cluster_stats_gen: ClusterStatsGenerator,
     pub bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+    pub ngram_columns_map: BTreeMap<FieldIndex, TableField>,
     pub inverted_index_builders: Vec<InvertedIndexBuilder>,
 }
 
@@ -354,6 +359,7 @@ impl BlockBuilder {
             &data_block,
             bloom_index_location,
             self.bloom_columns_map.clone(),
+            self.ngram_columns_map.clone(),
         )?;
         let column_distinct_count = bloom_index_state
             .as_ref()
@@ -296,6 +296,7 @@ impl FuseTable {
             cluster_keys,
             bloom_index_cols,
             None,
+            self.enable_ngram_bloom_index(),
             self.get_storage_format(),
         )?;
 
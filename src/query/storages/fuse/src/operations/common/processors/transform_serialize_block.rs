@@ -126,6 +126,7 @@ impl TransformSerializeBlock {
         let bloom_columns_map = table
             .bloom_index_cols
             .bloom_index_fields(source_schema.clone(), BloomIndex::supported_type)?;
+        let ngram_columns_map = table.ngram_index_fields(&bloom_columns_map);
 
         let inverted_index_builders = create_inverted_index_builders(&table.table_info.meta);
 
@@ -136,6 +137,7 @@ impl TransformSerializeBlock {
             write_settings: table.get_write_settings(),
             cluster_stats_gen,
             bloom_columns_map,
+            ngram_columns_map,
             inverted_index_builders,
         };
         Ok(TransformSerializeBlock {
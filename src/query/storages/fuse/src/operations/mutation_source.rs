@@ -236,6 +236,7 @@ impl FuseTable {
             &push_down,
             self.bloom_index_cols(),
             None,
+            self.enable_ngram_bloom_index(),
             self.get_storage_format(),
         )?;
 
@@ -176,6 +176,7 @@ impl FuseTable {
             let bloom_columns_map = self
                 .bloom_index_cols()
                 .bloom_index_fields(table_schema.clone(), BloomIndex::supported_type)?;
+            let ngram_columns_map = self.ngram_index_fields(&bloom_columns_map);
 
             Some(BloomIndexBuilder {
                 table_ctx: ctx.clone(),
@@ -183,6 +184,7 @@ impl FuseTable {
                 table_dal: dal.clone(),
                 storage_format,
                 bloom_columns_map,
+                ngram_columns_map,
             })
         } else {
             None
@@ -196,6 +198,7 @@ impl FuseTable {
                 &push_downs,
                 self.bloom_index_cols(),
                 bloom_index_builder,
+                self.enable_ngram_bloom_index(),
                 self.get_storage_format(),
             )?
         } else {
@@ -210,6 +213,7 @@ impl FuseTable {
                 cluster_keys,
                 self.bloom_index_cols(),
                 bloom_index_builder,
+                self.enable_ngram_bloom_index(),
                 self.get_storage_format(),
             )?
         };
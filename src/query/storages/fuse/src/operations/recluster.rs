@@ -255,6 +255,7 @@ impl FuseTable {
             BloomIndexColumns::None,
             max_concurrency,
             bloom_index_builder,
+            false,
             storage_format,
         )?;
 
@@ -19,6 +19,7 @@ use std::sync::Arc;
 
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
 use databend_common_expression::ColumnId;
 use databend_common_expression::Expr;
 use databend_common_expression::FunctionContext;
@@ -60,6 +61,9 @@ pub struct BloomPrunerCreator {
     /// indices that should be loaded from filter block
     index_fields: Vec<TableField>,
 
+    /// indices whose ngram filter should be loaded from filter block
+    ngram_fields: Vec<TableField>,
+
     /// the expression that would be evaluate
     filter_expression: Expr<String>,
 
@@ -84,14 +88,24 @@ impl BloomPrunerCreator {
         filter_expr: Option<&Expr<String>>,
         bloom_index_cols: BloomIndexColumns,
         bloom_index_builder: Option<BloomIndexBuilder>,
+        ngram_index: bool,
     ) -> Result<Option<Arc<dyn BloomPruner + Send + Sync>>> {
         if let Some(expr) = filter_expr {
             let bloom_columns_map =
                 bloom_index_cols.bloom_index_fields(schema.clone(), BloomIndex::supported_type)?;
             let bloom_column_fields = bloom_columns_map.values().cloned().collect::<Vec<_>>();
-            let point_query_cols = BloomIndex::find_eq_columns(expr, bloom_column_fields)?;
+            let point_query_cols = BloomIndex::find_eq_columns(expr, bloom_column_fields.clone())?;
+            let like_query_cols = if ngram_index {
+                let ngram_column_fields = bloom_column_fields
+                    .into_iter()
+                    .filter(|f| BloomIndex::supported_ngram_type(f.data_type()))
+                    .collect();
+                BloomIndex::find_like_columns(expr, ngram_column_fields)?
+            } else {
+                vec![]
+            };
 
-            if !point_query_cols.is_empty() {
+            if !point_query_cols.is_empty() || !like_query_cols.is_empty() {
                 // convert to filter column names
                 let mut filter_fields = Vec::with_capacity(point_query_cols.len());
                 let mut scalar_map = HashMap::<Scalar, u64>::new();
@@ -103,9 +117,25 @@ impl BloomPrunerCreator {
                     }
                 }
 
+                let mut ngram_fields = Vec::with_capacity(like_query_cols.len());
+                for (field, ngrams) in like_query_cols.into_iter() {
+                    ngram_fields.push(field);
+                    for ngram in ngrams {
+                        if let Entry::Vacant(e) = scalar_map.entry(Scalar::String(ngram)) {
+                            let digest = BloomIndex::calculate_scalar_digest(
+                                &func_ctx,
+                                e.key(),
+                                &DataType::String,
+                            )?;
+                            e.insert(digest);
+                        }
+                    }
+                }
+
                 let creator = BloomPrunerCreator {
                     func_ctx,
                     index_fields: filter_fields,
+                    ngram_fields,
                     filter_expression: expr.clone(),
                     scalar_map,
                     dal,
@@ -131,7 +161,7 @@ impl BloomPrunerCreator {
         let version = index_location.1;
 
         // filter out columns that no longer exist in the indexed block
-        let index_columns = self.index_fields.iter().try_fold(
+        let mut index_columns = self.index_fields.iter().try_fold(
             Vec::with_capacity(self.index_fields.len()),
             |mut acc, field| {
                 if column_ids_of_indexed_block.contains(&field.column_id()) {
@@ -140,6 +170,13 @@ impl BloomPrunerCreator {
                 Ok::<_, ErrorCode>(acc)
             },
         )?;
+        for field in &self.ngram_fields {
+            if column_ids_of_indexed_block.contains(&field.column_id()) {
+                if let Some(name) = BloomIndex::build_ngram_filter_column_name(version, field)? {
+                    index_columns.push(name);
+                }
+            }
+        }
 
         // load the relevant index columns
         let maybe_filter = index_location
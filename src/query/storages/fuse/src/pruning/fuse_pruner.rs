@@ -67,6 +67,8 @@ use crate::pruning::VirtualColumnPruner;
 use crate::FuseStorageFormat;
 
 const SMALL_DATASET_SAMPLE_THRESHOLD: usize = 100;
+/// Max number of segments fetched concurrently by each pruning batch, see `enable_prefetch_segments`.
+const PREFETCH_SEGMENTS_CHUNK_SIZE: usize = 8;
 
 pub struct PruningContext {
     pub ctx: Arc<dyn TableContext>,
@@ -97,6 +99,7 @@ impl PruningContext {
         bloom_index_cols: BloomIndexColumns,
         max_concurrency: usize,
         bloom_index_builder: Option<BloomIndexBuilder>,
+        ngram_index: bool,
         storage_format: FuseStorageFormat,
     ) -> Result<Arc<PruningContext>> {
         let func_ctx = ctx.get_function_context()?;
@@ -150,6 +153,7 @@ impl PruningContext {
             filter_expr.as_ref(),
             bloom_index_cols,
             bloom_index_builder,
+            ngram_index,
         )?;
 
         // Page pruner, used in native format
@@ -221,6 +225,7 @@ impl FusePruner {
         push_down: &Option<PushDownInfo>,
         bloom_index_cols: BloomIndexColumns,
         bloom_index_builder: Option<BloomIndexBuilder>,
+        ngram_index: bool,
         storage_format: FuseStorageFormat,
     ) -> Result<Self> {
         Self::create_with_pages(
@@ -232,6 +237,7 @@ impl FusePruner {
             vec![],
             bloom_index_cols,
             bloom_index_builder,
+            ngram_index,
             storage_format,
         )
     }
@@ -246,6 +252,7 @@ impl FusePruner {
         cluster_keys: Vec<RemoteExpr<String>>,
         bloom_index_cols: BloomIndexColumns,
         bloom_index_builder: Option<BloomIndexBuilder>,
+        ngram_index: bool,
         storage_format: FuseStorageFormat,
     ) -> Result<Self> {
         let max_concurrency = {
@@ -273,6 +280,7 @@ impl FusePruner {
             bloom_index_cols,
             max_concurrency,
             bloom_index_builder,
+            ngram_index,
             storage_format,
         )?;
 
@@ -316,6 +324,12 @@ impl FusePruner {
             SegmentPruner::create(self.pruning_ctx.clone(), self.table_schema.clone())?;
         let block_pruner = Arc::new(BlockPruner::create(self.pruning_ctx.clone())?);
 
+        let prefetch_segments = self
+            .pruning_ctx
+            .ctx
+            .get_settings()
+            .get_enable_prefetch_segments()?;
+
         let mut remain = segment_locs.len() % self.max_concurrency;
         let batch_size = segment_locs.len() / self.max_concurrency;
         let mut works = Vec::with_capacity(self.max_concurrency);
@@ -345,79 +359,99 @@ impl FusePruner {
                             .collect::<Vec<_>>();
                     }
 
+                    // With segment prefetch, the segments of a chunk are fetched concurrently,
+                    // and no more chunk is fetched once the pushed down limit is reached.
+                    let chunks = match prefetch_segments {
+                        true => batch
+                            .chunks(PREFETCH_SEGMENTS_CHUNK_SIZE)
+                            .map(|chunk| chunk.to_vec())
+                            .collect::<Vec<_>>(),
+                        false => vec![batch],
+                    };
+
                     let mut res = vec![];
                     let mut deleted_segments = vec![];
-                    let pruned_segments = segment_pruner.pruning(batch).await?;
-
-                    if delete_pruning {
-                        for (segment_location, compact_segment_info) in &pruned_segments {
-                            if let Some(range_index) = &inverse_range_index {
-                                if !range_index
-                                    .should_keep(&compact_segment_info.summary.col_stats, None)
-                                {
-                                    deleted_segments.push(DeletedSegmentInfo {
-                                        index: segment_location.segment_idx,
-                                        summary: compact_segment_info.summary.clone(),
-                                    });
-                                    continue;
-                                };
-                            }
-                            // do not populate the block meta cache for deletion operations,
-                            // since block metas touched by deletion are not likely to
-                            // be accessed soon.
-                            let populate_block_meta_cache = false;
-                            let block_metas = Self::extract_block_metas(
-                                &segment_location.location.0,
-                                compact_segment_info,
-                                populate_block_meta_cache,
-                            )?;
-                            res.extend(
-                                block_pruner
-                                    .pruning(segment_location.clone(), block_metas)
-                                    .await?,
-                            );
+                    for chunk in chunks {
+                        if prefetch_segments && pruning_ctx.limit_pruner.exceeded() {
+                            break;
                         }
-                    } else {
-                        let sample_probability = table_sample(&push_down)?;
-                        for (location, info) in pruned_segments {
-                            let mut block_metas =
-                                Self::extract_block_metas(&location.location.0, &info, true)?;
-                            if let Some(probability) = sample_probability {
-                                if block_metas.len() <= SMALL_DATASET_SAMPLE_THRESHOLD {
-                                    // Deterministic sampling for small datasets
-                                    // Ensure at least one block is sampled for small datasets
-                                    let sample_size = max(
-                                        1,
-                                        (block_metas.len() as f64 * probability).round() as usize,
-                                    );
-                                    let mut rng = thread_rng();
-                                    block_metas = Arc::new(
-                                        block_metas
-                                            .choose_multiple(&mut rng, sample_size)
-                                            .cloned()
-                                            .collect(),
-                                    );
-                                } else {
-                                    // Random sampling for larger datasets
-                                    let mut sample_block_metas =
-                                        Vec::with_capacity(block_metas.len());
-                                    let mut rng = thread_rng();
-                                    let bernoulli = Bernoulli::new(probability).unwrap();
-                                    for block in block_metas.iter() {
-                                        if bernoulli.sample(&mut rng) {
-                                            sample_block_metas.push(block.clone());
+                        let pruned_segments = segment_pruner.pruning(chunk).await?;
+
+                        if delete_pruning {
+                            for (segment_location, compact_segment_info) in &pruned_segments {
+                                if let Some(range_index) = &inverse_range_index {
+                                    if !range_index
+                                        .should_keep(&compact_segment_info.summary.col_stats, None)
+                                    {
+                                        deleted_segments.push(DeletedSegmentInfo {
+                                            index: segment_location.segment_idx,
+                                            summary: compact_segment_info.summary.clone(),
+                                        });
+                                        continue;
+                                    };
+                                }
+                                // do not populate the block meta cache for deletion operations,
+                                // since block metas touched by deletion are not likely to
+                                // be accessed soon.
+                                let populate_block_meta_cache = false;
+                                let block_metas = Self::extract_block_metas(
+                                    &segment_location.location.0,
+                                    compact_segment_info,
+                                    populate_block_meta_cache,
+                                )?;
+                                res.extend(
+                                    block_pruner
+                                        .pruning(segment_location.clone(), block_metas)
+                                        .await?,
+                                );
+                            }
+                        } else {
+                            let sample_probability = table_sample(&push_down)?;
+                            for (location, info) in pruned_segments {
+                                let mut block_metas =
+                                    Self::extract_block_metas(&location.location.0, &info, true)?;
+                                if let Some(probability) = sample_probability {
+                                    if block_metas.len() <= SMALL_DATASET_SAMPLE_THRESHOLD {
+                                        // Deterministic sampling for small datasets
+                                        // Ensure at least one block is sampled for small datasets
+                                        let sample_size = max(
+                                            1,
+                                            (block_metas.len() as f64 * probability).round()
+                                                as usize,
+                                        );
+                                        let mut rng = thread_rng();
+                                        block_metas = Arc::new(
+                                            block_metas
+                                                .choose_multiple(&mut rng, sample_size)
+                                                .cloned()
+                                                .collect(),
+                                        );
+                                    } else {
+                                        // Random sampling for larger datasets
+                                        let mut sample_block_metas =
+                                            Vec::with_capacity(block_metas.len());
+                                        let mut rng = thread_rng();
+                                        let bernoulli = Bernoulli::new(probability).unwrap();
+                                        for block in block_metas.iter() {
+                                            if bernoulli.sample(&mut rng) {
+                                                sample_block_metas.push(block.clone());
+                                            }
                                         }
+                                        // Ensure at least one block is sampled for large datasets too
+                                        if sample_block_metas.is_empty() && !block_metas.is_empty()
+                                        {
+                                            // Safe to unwrap, because we've checked that block_metas is not empty
+                                            sample_block_metas.push(
+                                                block_metas.choose(&mut rng).unwrap().clone(),
+                                            );
+                                        }
+                                        block_metas = Arc::new(sample_block_metas);
                                     }
-                                    // Ensure at least one block is sampled for large datasets too
-                                    if sample_block_metas.is_empty() && !block_metas.is_empty() {
-                                        // Safe to unwrap, because we've checked that block_metas is not empty
-                                        sample_block_metas
-                                            .push(block_metas.choose(&mut rng).unwrap().clone());
-                                    }
-                                    block_metas = Arc::new(sample_block_metas);
                                 }
+                                res.extend(
+                                    block_pruner.pruning(location.clone(), block_metas).await?,
+                                );
                             }
-                            res.extend(block_pruner.pruning(location.clone(), block_metas).await?);
                         }
                     }
                     Result::<_>::Ok((res, deleted_segments))
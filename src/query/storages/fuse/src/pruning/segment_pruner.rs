@@ -18,6 +18,7 @@ use databend_common_exception::Result;
 use databend_common_expression::TableSchemaRef;
 use databend_common_metrics::storage::*;
 use databend_storages_common_table_meta::meta::CompactSegmentInfo;
+use futures::future::try_join_all;
 
 use crate::io::SegmentsIO;
 use crate::pruning::PruningContext;
@@ -26,6 +27,8 @@ use crate::pruning::SegmentLocation;
 pub struct SegmentPruner {
     pub pruning_ctx: Arc<PruningContext>,
     pub table_schema: TableSchemaRef,
+    // fetch the segments of each call concurrently, see `enable_prefetch_segments`
+    prefetch_segments: bool,
 }
 
 impl SegmentPruner {
@@ -33,9 +36,14 @@ impl SegmentPruner {
         pruning_ctx: Arc<PruningContext>,
         table_schema: TableSchemaRef,
     ) -> Result<Arc<SegmentPruner>> {
+        let prefetch_segments = pruning_ctx
+            .ctx
+            .get_settings()
+            .get_enable_prefetch_segments()?;
         Ok(Arc::new(SegmentPruner {
             pruning_ctx,
             table_schema,
+            prefetch_segments,
         }))
     }
 
@@ -53,14 +61,35 @@ impl SegmentPruner {
         let pruning_stats = self.pruning_ctx.pruning_stats.clone();
         let range_pruner = self.pruning_ctx.range_pruner.clone();
 
+        let mut prefetched = match self.prefetch_segments {
+            true => {
+                try_join_all(segment_locs.iter().map(|segment_location| {
+                    SegmentsIO::read_compact_segment(
+                        self.pruning_ctx.dal.clone(),
+                        segment_location.location.clone(),
+                        self.table_schema.clone(),
+                        true,
+                    )
+                }))
+                .await?
+            }
+            false => vec![],
+        }
+        .into_iter();
+
         for segment_location in segment_locs {
-            let info = SegmentsIO::read_compact_segment(
-                self.pruning_ctx.dal.clone(),
-                segment_location.location.clone(),
-                self.table_schema.clone(),
-                true,
-            )
-            .await?;
+            let info = match prefetched.next() {
+                Some(info) => info,
+                None => {
+                    SegmentsIO::read_compact_segment(
+                        self.pruning_ctx.dal.clone(),
+                        segment_location.location.clone(),
+                        self.table_schema.clone(),
+                        true,
+                    )
+                    .await?
+                }
+            };
 
             let total_bytes = info.summary.uncompressed_byte_size;
             // Perf.
@@ -144,15 +144,15 @@ pub struct ClusteringInformation<'a> {
     pub cluster_key: Option<String>,
 }
 
-struct ClusteringStatistics {
-    cluster_key: String,
-    cluster_type: String,
-    timestamp: i64,
-    total_block_count: u64,
-    constant_block_count: u64,
-    average_overlaps: f64,
-    average_depth: f64,
-    block_depth_histogram: JsonValue,
+pub struct ClusteringStatistics {
+    pub cluster_key: String,
+    pub cluster_type: String,
+    pub timestamp: i64,
+    pub total_block_count: u64,
+    pub constant_block_count: u64,
+    pub average_overlaps: f64,
+    pub average_depth: f64,
+    pub block_depth_histogram: JsonValue,
 }
 
 impl<'a> ClusteringInformation<'a> {
@@ -170,6 +170,12 @@ impl<'a> ClusteringInformation<'a> {
 
     #[async_backtrace::framed]
     pub async fn get_clustering_info(&self) -> Result<DataBlock> {
+        let info = self.get_clustering_statistics().await?;
+        self.build_block(info)
+    }
+
+    #[async_backtrace::framed]
+    pub async fn get_clustering_statistics(&self) -> Result<ClusteringStatistics> {
         let mut default_cluster_key_id = None;
         let (cluster_key, exprs) = match (self.table.cluster_key_str(), &self.cluster_key) {
             (a, Some(b)) => {
@@ -220,7 +226,7 @@ impl<'a> ClusteringInformation<'a> {
             .map_or(now, |s| s.timestamp.unwrap_or(now))
             .timestamp_micros();
         if snapshot.is_none() {
-            return self.build_block(ClusteringStatistics {
+            return Ok(ClusteringStatistics {
                 cluster_key,
                 cluster_type,
                 timestamp,
@@ -364,7 +370,7 @@ impl<'a> ClusteringInformation<'a> {
             block_depth_histogram,
         };
 
-        self.build_block(info)
+        Ok(info)
     }
 
     fn build_block(&self, info: ClusteringStatistics) -> Result<DataBlock> {
@@ -26,6 +26,7 @@ mod fuse_time_travel_size;
 mod fuse_vacuum_temporary_table;
 mod table_args;
 
+pub use clustering_information::ClusteringInformation;
 pub use clustering_information::ClusteringInformationFunc;
 pub use clustering_statistics::ClusteringStatisticsFunc;
 use databend_common_catalog::table_args::TableArgs;
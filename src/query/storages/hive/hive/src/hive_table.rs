@@ -46,6 +46,7 @@ use databend_common_meta_app::schema::UpsertTableCopiedFileReq;
 use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_sinks::AsyncSinker;
 use databend_common_pipeline_sources::SyncSource;
 use databend_common_pipeline_sources::SyncSourcer;
 use databend_common_storage::init_operator;
@@ -64,6 +65,7 @@ use opendal::Operator;
 
 use super::hive_catalog::HiveCatalog;
 use super::hive_table_options::HiveTableOptions;
+use crate::hive_table_sink::HiveTableSink;
 use crate::hive_table_source::HiveTableSource;
 use crate::utils::HiveFetchPartitionScalars;
 use crate::HivePartInfo;
@@ -440,21 +442,51 @@ impl Table for HiveTable {
         self.do_read_data(ctx, plan, pipeline)
     }
 
+    fn append_data(&self, ctx: Arc<dyn TableContext>, pipeline: &mut Pipeline) -> Result<()> {
+        if !self.partition_fields().is_empty() {
+            return Err(ErrorCode::Unimplemented(format!(
+                "insert into partitioned hive table {} is not supported yet",
+                self.name()
+            )));
+        }
+        let path = self.table_options.location.as_ref().ok_or_else(|| {
+            ErrorCode::TableInfoError(format!("{}, table location is empty", self.table_info.name))
+        })?;
+        let dir = convert_hdfs_path(path, true);
+        let schema = self.schema();
+        let query_id = ctx.get_id();
+        pipeline.add_sink(|input| {
+            Ok(ProcessorPtr::create(AsyncSinker::create(
+                input,
+                HiveTableSink::create(
+                    self.dal.clone(),
+                    dir.clone(),
+                    schema.clone(),
+                    query_id.clone(),
+                ),
+            )))
+        })
+    }
+
     fn commit_insertion(
         &self,
         _ctx: Arc<dyn TableContext>,
         _pipeline: &mut Pipeline,
         _copied_files: Option<UpsertTableCopiedFileReq>,
         _update_stream_meta: Vec<UpdateStreamMetaReq>,
-        _overwrite: bool,
+        overwrite: bool,
         _prev_snapshot_id: Option<SnapshotId>,
         _deduplicated_label: Option<String>,
     ) -> Result<()> {
-        Err(ErrorCode::Unimplemented(format!(
-            "commit_insertion operation for table {} is not implemented, table engine is {}",
-            self.name(),
-            self.get_table_info().meta.engine
-        )))
+        if overwrite {
+            return Err(ErrorCode::Unimplemented(format!(
+                "insert overwrite into hive table {} is not supported yet",
+                self.name()
+            )));
+        }
+        // The files written by append_data are already in place, and an unpartitioned
+        // table has nothing to register in the metastore.
+        Ok(())
     }
 
     #[async_backtrace::framed]
@@ -0,0 +1,104 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::uuid::Uuid;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableSchemaRef;
+use databend_common_pipeline_sinks::AsyncSink;
+use opendal::Operator;
+use parquet::arrow::ArrowWriter;
+use parquet::basic::Compression;
+use parquet::file::properties::WriterProperties;
+
+// the in-memory size of the rows buffered before they are written as one file
+const MAX_FILE_BUFFER_SIZE: usize = 64 * 1024 * 1024;
+
+/// Writes the inserted rows as parquet files into the location of an unpartitioned hive
+/// table. Hive picks up new files of such a table without a metastore update.
+///
+/// The files are visible as soon as they are written, a failed INSERT may leave some of
+/// them behind.
+pub struct HiveTableSink {
+    op: Operator,
+    // the table location, with a trailing '/'
+    dir: String,
+    schema: TableSchemaRef,
+    query_id: String,
+    blocks: Vec<DataBlock>,
+    buffered_bytes: usize,
+}
+
+impl HiveTableSink {
+    pub fn create(op: Operator, dir: String, schema: TableSchemaRef, query_id: String) -> Self {
+        HiveTableSink {
+            op,
+            dir,
+            schema,
+            query_id,
+            blocks: vec![],
+            buffered_bytes: 0,
+        }
+    }
+
+    async fn flush(&mut self) -> Result<()> {
+        if self.blocks.is_empty() {
+            return Ok(());
+        }
+        let block = DataBlock::concat(&std::mem::take(&mut self.blocks))?;
+        self.buffered_bytes = 0;
+        if block.is_empty() {
+            return Ok(());
+        }
+
+        let batch = block.to_record_batch(&self.schema)?;
+        let props = WriterProperties::builder()
+            .set_compression(Compression::SNAPPY)
+            .build();
+        let mut buf = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props))?;
+        writer.write(&batch)?;
+        writer.close()?;
+
+        // the readers skip files starting with '_' or '.'
+        let path = format!(
+            "{}{}_{}.parquet",
+            self.dir,
+            self.query_id,
+            Uuid::new_v4().simple()
+        );
+        self.op.write(&path, buf).await?;
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSink for HiveTableSink {
+    const NAME: &'static str = "HiveTableSink";
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self) -> Result<()> {
+        self.flush().await
+    }
+
+    #[async_backtrace::framed]
+    async fn consume(&mut self, data_block: DataBlock) -> Result<bool> {
+        self.buffered_bytes += data_block.memory_size();
+        self.blocks.push(data_block);
+        if self.buffered_bytes >= MAX_FILE_BUFFER_SIZE {
+            self.flush().await?;
+        }
+        Ok(false)
+    }
+}
@@ -74,6 +74,7 @@ impl AccumulatingTransform for StripeDecoder {
                 copy_status.add_chunk(&stripe.path, FileStatus {
                     num_rows_loaded: block.num_rows(),
                     error: None,
+                    rejected_rows: vec![],
                 })
             }
             blocks.push(block);
@@ -99,7 +99,7 @@ impl OrcTable {
     }
 
     #[async_backtrace::framed]
-    async fn prepare_metas(
+    pub async fn prepare_metas(
         file_info: StageFileInfo,
         operator: Operator,
     ) -> Result<Arc<ArrowSchema>> {
@@ -83,6 +83,7 @@ impl ParquetTableForCopy {
             copy_status.add_chunk(meta.location.as_str(), FileStatus {
                 num_rows_loaded: num_rows,
                 error: None,
+                rejected_rows: vec![],
             });
             for rg in meta.meta.row_groups() {
                 let part = ParquetRSRowGroupPart {
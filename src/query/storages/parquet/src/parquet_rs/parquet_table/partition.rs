@@ -433,6 +433,7 @@ fn prune_and_generate_partitions(
             copy_status.add_chunk(location, FileStatus {
                 num_rows_loaded: rows_read,
                 error: None,
+                rejected_rows: vec![],
             });
         }
     }
@@ -171,6 +171,7 @@ impl Processor for ParquetSource {
                         self.copy_status.add_chunk(path.as_str(), FileStatus {
                             num_rows_loaded: num_rows,
                             error: None,
+                            rejected_rows: vec![],
                         });
                         blocks.extend(bs);
                     }
@@ -22,6 +22,7 @@ use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
 
 use super::parquet_file::append_data_to_parquet_files;
 use super::row_based_file::append_data_to_row_based_files;
+use crate::append::iceberg::IcebergMetadataWriter;
 use crate::append::output::SumSummaryTransform;
 use crate::StageTable;
 
@@ -61,7 +62,14 @@ impl StageTable {
                 max_threads,
             )?,
         };
-        if !self.table_info.copy_into_location_options.detailed_output {
+        if self.table_info.copy_into_location_options.iceberg_metadata {
+            let table_info = self.table_info.clone();
+            let op = StageTable::get_op(&self.table_info.stage_info)?;
+            pipeline.try_resize(1)?;
+            pipeline.try_add_async_accumulating_transformer(|| {
+                IcebergMetadataWriter::try_create(&table_info, op.clone())
+            })?;
+        } else if !self.table_info.copy_into_location_options.detailed_output {
             pipeline.try_resize(1)?;
             pipeline.add_accumulating_transformer(SumSummaryTransform::default);
         }
@@ -0,0 +1,204 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use apache_avro::types::Value as AvroValue;
+use apache_avro::Schema as AvroSchema;
+use apache_avro::Writer as AvroWriter;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+/// Avro schema of the entries of an iceberg v2 manifest, for unpartitioned tables.
+///
+/// Only the required fields of `data_file` are written, readers resolve the optional
+/// ones (column sizes, bounds, ...) to null.
+const MANIFEST_ENTRY_SCHEMA: &str = r#"{
+    "type": "record",
+    "name": "manifest_entry",
+    "fields": [
+        {"name": "status", "type": "int", "field-id": 0},
+        {"name": "snapshot_id", "type": ["null", "long"], "default": null, "field-id": 1},
+        {"name": "sequence_number", "type": ["null", "long"], "default": null, "field-id": 3},
+        {"name": "file_sequence_number", "type": ["null", "long"], "default": null, "field-id": 4},
+        {"name": "data_file", "field-id": 2, "type": {
+            "type": "record",
+            "name": "r2",
+            "fields": [
+                {"name": "content", "type": "int", "field-id": 134},
+                {"name": "file_path", "type": "string", "field-id": 100},
+                {"name": "file_format", "type": "string", "field-id": 101},
+                {"name": "partition", "field-id": 102, "type": {
+                    "type": "record",
+                    "name": "r102",
+                    "fields": []
+                }},
+                {"name": "record_count", "type": "long", "field-id": 103},
+                {"name": "file_size_in_bytes", "type": "long", "field-id": 104}
+            ]
+        }}
+    ]
+}"#;
+
+/// Avro schema of the entries of an iceberg v2 manifest list.
+const MANIFEST_FILE_SCHEMA: &str = r#"{
+    "type": "record",
+    "name": "manifest_file",
+    "fields": [
+        {"name": "manifest_path", "type": "string", "field-id": 500},
+        {"name": "manifest_length", "type": "long", "field-id": 501},
+        {"name": "partition_spec_id", "type": "int", "field-id": 502},
+        {"name": "content", "type": "int", "field-id": 517},
+        {"name": "sequence_number", "type": "long", "field-id": 515},
+        {"name": "min_sequence_number", "type": "long", "field-id": 516},
+        {"name": "added_snapshot_id", "type": "long", "field-id": 503},
+        {"name": "added_files_count", "type": "int", "field-id": 504},
+        {"name": "existing_files_count", "type": "int", "field-id": 505},
+        {"name": "deleted_files_count", "type": "int", "field-id": 506},
+        {"name": "added_rows_count", "type": "long", "field-id": 512},
+        {"name": "existing_rows_count", "type": "long", "field-id": 513},
+        {"name": "deleted_rows_count", "type": "long", "field-id": 514}
+    ]
+}"#;
+
+// manifest entry status
+const STATUS_ADDED: i32 = 1;
+// manifest content
+const CONTENT_DATA: i32 = 0;
+
+#[derive(Clone, Debug)]
+pub struct DataFileEntry {
+    /// Absolute location of the data file.
+    pub file_path: String,
+    pub file_size: u64,
+    pub record_count: u64,
+}
+
+/// Serialize the manifest, which registers all the `files` as added by `snapshot_id`.
+pub fn write_manifest(
+    table_schema: &str,
+    snapshot_id: i64,
+    files: &[DataFileEntry],
+) -> Result<Vec<u8>> {
+    let schema = AvroSchema::parse_str(MANIFEST_ENTRY_SCHEMA).map_err(avro_error)?;
+    let mut writer = AvroWriter::new(&schema, Vec::new());
+    for (key, value) in [
+        ("schema", table_schema),
+        ("schema-id", "0"),
+        ("partition-spec", "[]"),
+        ("partition-spec-id", "0"),
+        ("format-version", "2"),
+        ("content", "data"),
+    ] {
+        writer
+            .add_user_metadata(key.to_string(), value)
+            .map_err(avro_error)?;
+    }
+
+    for file in files {
+        let data_file = AvroValue::Record(vec![
+            ("content".to_string(), AvroValue::Int(CONTENT_DATA)),
+            (
+                "file_path".to_string(),
+                AvroValue::String(file.file_path.clone()),
+            ),
+            (
+                "file_format".to_string(),
+                AvroValue::String("PARQUET".to_string()),
+            ),
+            ("partition".to_string(), AvroValue::Record(vec![])),
+            (
+                "record_count".to_string(),
+                AvroValue::Long(file.record_count as i64),
+            ),
+            (
+                "file_size_in_bytes".to_string(),
+                AvroValue::Long(file.file_size as i64),
+            ),
+        ]);
+        // The sequence numbers of added files are inherited from the manifest list.
+        let entry = AvroValue::Record(vec![
+            ("status".to_string(), AvroValue::Int(STATUS_ADDED)),
+            ("snapshot_id".to_string(), Some(snapshot_id).into()),
+            ("sequence_number".to_string(), None::<i64>.into()),
+            ("file_sequence_number".to_string(), None::<i64>.into()),
+            ("data_file".to_string(), data_file),
+        ]);
+        writer.append(entry).map_err(avro_error)?;
+    }
+    writer.into_inner().map_err(avro_error)
+}
+
+/// Serialize the manifest list of `snapshot_id`, which contains the only manifest.
+pub fn write_manifest_list(
+    snapshot_id: i64,
+    sequence_number: i64,
+    manifest_path: &str,
+    manifest_length: usize,
+    files: &[DataFileEntry],
+) -> Result<Vec<u8>> {
+    let schema = AvroSchema::parse_str(MANIFEST_FILE_SCHEMA).map_err(avro_error)?;
+    let mut writer = AvroWriter::new(&schema, Vec::new());
+    let snapshot_id_str = snapshot_id.to_string();
+    let sequence_number_str = sequence_number.to_string();
+    for (key, value) in [
+        ("snapshot-id", snapshot_id_str.as_str()),
+        ("parent-snapshot-id", "null"),
+        ("sequence-number", sequence_number_str.as_str()),
+        ("format-version", "2"),
+    ] {
+        writer
+            .add_user_metadata(key.to_string(), value)
+            .map_err(avro_error)?;
+    }
+
+    let added_rows = files.iter().map(|f| f.record_count as i64).sum::<i64>();
+    let manifest_file = AvroValue::Record(vec![
+        (
+            "manifest_path".to_string(),
+            AvroValue::String(manifest_path.to_string()),
+        ),
+        (
+            "manifest_length".to_string(),
+            AvroValue::Long(manifest_length as i64),
+        ),
+        ("partition_spec_id".to_string(), AvroValue::Int(0)),
+        ("content".to_string(), AvroValue::Int(CONTENT_DATA)),
+        (
+            "sequence_number".to_string(),
+            AvroValue::Long(sequence_number),
+        ),
+        (
+            "min_sequence_number".to_string(),
+            AvroValue::Long(sequence_number),
+        ),
+        (
+            "added_snapshot_id".to_string(),
+            AvroValue::Long(snapshot_id),
+        ),
+        (
+            "added_files_count".to_string(),
+            AvroValue::Int(files.len() as i32),
+        ),
+        ("existing_files_count".to_string(), AvroValue::Int(0)),
+        ("deleted_files_count".to_string(), AvroValue::Int(0)),
+        ("added_rows_count".to_string(), AvroValue::Long(added_rows)),
+        ("existing_rows_count".to_string(), AvroValue::Long(0)),
+        ("deleted_rows_count".to_string(), AvroValue::Long(0)),
+    ]);
+    writer.append(manifest_file).map_err(avro_error)?;
+    writer.into_inner().map_err(avro_error)
+}
+
+fn avro_error(e: apache_avro::Error) -> ErrorCode {
+    ErrorCode::Internal(format!("Failed to write iceberg manifest: {}", e))
+}
@@ -0,0 +1,318 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use async_trait::async_trait;
+use databend_common_catalog::plan::StageTableInfo;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::ArgType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Value;
+use databend_common_meta_app::principal::StageInfo;
+use databend_common_meta_app::principal::StageType;
+use databend_common_meta_app::storage::StorageParams;
+use databend_common_pipeline_transforms::processors::AsyncAccumulatingTransform;
+use databend_common_storage::DataOperator;
+use log::info;
+use opendal::ErrorKind;
+use opendal::Operator;
+use serde_json::json;
+use uuid::Uuid;
+
+use super::manifest::write_manifest;
+use super::manifest::write_manifest_list;
+use super::manifest::DataFileEntry;
+use super::schema::IcebergSchema;
+use super::ICEBERG_METADATA_DIR;
+use crate::append::output::DataSummary;
+
+const SEQUENCE_NUMBER: i64 = 1;
+
+/// Collects the parquet files written by `COPY INTO <location> ... ICEBERG_METADATA = true`,
+/// and commits them as the first snapshot of an iceberg (format v2) table once all the
+/// files are written.
+///
+/// The metadata layout is the one of hadoop catalogs: `metadata/v1.metadata.json` and
+/// `metadata/version-hint.text`, so that the table could be registered by any catalog.
+///
+/// Input blocks are the `UnloadOutput::IcebergFiles` of the writers, the output is the
+/// unload summary, or the per file output if `detailed_output` is set.
+pub struct IcebergMetadataWriter {
+    op: Operator,
+    schema: IcebergSchema,
+    // location of the operator root, ends with '/'
+    base_location: String,
+    // directory of the table, relative to the operator root
+    table_dir: String,
+    files: Vec<DataFileEntry>,
+    detailed_output: bool,
+    summary: DataSummary,
+}
+
+impl IcebergMetadataWriter {
+    pub fn try_create(table_info: &StageTableInfo, op: Operator) -> Result<Self> {
+        let schema = IcebergSchema::try_create(&table_info.schema)?;
+        let base_location = base_location(&table_info.stage_info)?;
+        let table_dir = table_info
+            .files_info
+            .path
+            .trim_start_matches('/')
+            .to_string();
+        Ok(IcebergMetadataWriter {
+            op,
+            schema,
+            base_location,
+            table_dir,
+            files: vec![],
+            detailed_output: table_info.copy_into_location_options.detailed_output,
+            summary: DataSummary::new(),
+        })
+    }
+
+    fn absolute_location(&self, path: &str) -> String {
+        format!("{}{}", self.base_location, path.trim_start_matches('/'))
+    }
+
+    #[async_backtrace::framed]
+    async fn commit(&self) -> Result<()> {
+        let now_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|e| ErrorCode::Internal(e.to_string()))?
+            .as_millis() as i64;
+        let snapshot_id = (Uuid::new_v4().as_u64_pair().0 & i64::MAX as u64) as i64;
+        let commit_id = Uuid::new_v4();
+        let table_schema = serde_json::to_string(&self.schema.schema)?;
+        let metadata_dir = format!("{}{}", self.table_dir, ICEBERG_METADATA_DIR);
+
+        let manifest_path = format!("{metadata_dir}{commit_id}-m0.avro");
+        let manifest = write_manifest(&table_schema, snapshot_id, &self.files)?;
+        let manifest_length = manifest.len();
+        self.op.write(&manifest_path, manifest).await?;
+
+        let manifest_list_path = format!("{metadata_dir}snap-{snapshot_id}-1-{commit_id}.avro");
+        let manifest_list = write_manifest_list(
+            snapshot_id,
+            SEQUENCE_NUMBER,
+            &self.absolute_location(&manifest_path),
+            manifest_length,
+            &self.files,
+        )?;
+        self.op.write(&manifest_list_path, manifest_list).await?;
+
+        let added_records = self.files.iter().map(|f| f.record_count).sum::<u64>();
+        let added_files_size = self.files.iter().map(|f| f.file_size).sum::<u64>();
+        let table_location = self.absolute_location(&self.table_dir);
+        let metadata = json!({
+            "format-version": 2,
+            "table-uuid": Uuid::new_v4().to_string(),
+            "location": table_location.trim_end_matches('/'),
+            "last-sequence-number": SEQUENCE_NUMBER,
+            "last-updated-ms": now_ms,
+            "last-column-id": self.schema.last_column_id,
+            "current-schema-id": 0,
+            "schemas": [self.schema.schema],
+            "default-spec-id": 0,
+            "partition-specs": [{"spec-id": 0, "fields": []}],
+            "last-partition-id": 999,
+            "default-sort-order-id": 0,
+            "sort-orders": [{"order-id": 0, "fields": []}],
+            "properties": {
+                "write.format.default": "parquet",
+                "schema.name-mapping.default": serde_json::to_string(&self.schema.name_mapping)?,
+            },
+            "current-snapshot-id": snapshot_id,
+            "refs": {"main": {"snapshot-id": snapshot_id, "type": "branch"}},
+            "snapshots": [{
+                "snapshot-id": snapshot_id,
+                "sequence-number": SEQUENCE_NUMBER,
+                "timestamp-ms": now_ms,
+                "manifest-list": self.absolute_location(&manifest_list_path),
+                "summary": {
+                    "operation": "append",
+                    "added-data-files": self.files.len().to_string(),
+                    "added-records": added_records.to_string(),
+                    "added-files-size": added_files_size.to_string(),
+                    "total-data-files": self.files.len().to_string(),
+                    "total-records": added_records.to_string(),
+                    "total-files-size": added_files_size.to_string(),
+                },
+                "schema-id": 0,
+            }],
+            "snapshot-log": [{"snapshot-id": snapshot_id, "timestamp-ms": now_ms}],
+            "metadata-log": [],
+        });
+        // The metadata file is the commit point, a concurrent unload to the same location
+        // must fail instead of overwriting it.
+        let metadata_path = format!("{metadata_dir}v1.metadata.json");
+        let metadata = serde_json::to_vec(&metadata)?;
+        let write_result = if self.op.info().full_capability().write_with_if_not_exists {
+            self.op
+                .write_with(&metadata_path, metadata)
+                .if_not_exists(true)
+                .await
+        } else {
+            match self.op.exists(&metadata_path).await? {
+                true => Err(opendal::Error::new(
+                    ErrorKind::ConditionNotMatch,
+                    "metadata file already exists",
+                )),
+                false => self.op.write(&metadata_path, metadata).await,
+            }
+        };
+        match write_result {
+            Ok(_) => {}
+            Err(e) if e.kind() == ErrorKind::ConditionNotMatch => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "iceberg table already exists at '{}'",
+                    table_location
+                )));
+            }
+            Err(e) => return Err(e.into()),
+        }
+        self.op
+            .write(&format!("{metadata_dir}version-hint.text"), b"1".to_vec())
+            .await?;
+
+        info!(
+            "iceberg table committed at {}, snapshot id {}, {} data files",
+            table_location,
+            snapshot_id,
+            self.files.len()
+        );
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl AsyncAccumulatingTransform for IcebergMetadataWriter {
+    const NAME: &'static str = "IcebergMetadataWriter";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, data: DataBlock) -> Result<Option<DataBlock>> {
+        // (file_name, file_size, row_count, input_bytes), see `UnloadOutput::IcebergFiles`
+        if data.num_columns() != 4 {
+            return Err(ErrorCode::Internal(format!(
+                "IcebergMetadataWriter expects 4 columns, but got {}",
+                data.num_columns()
+            )));
+        }
+        let file_names = downcast_column::<StringType>(&data, 0)?;
+        let file_sizes = downcast_column::<UInt64Type>(&data, 1)?;
+        let row_counts = downcast_column::<UInt64Type>(&data, 2)?;
+        let input_bytes = downcast_column::<UInt64Type>(&data, 3)?;
+        for i in 0..data.num_rows() {
+            let (Some(file_name), Some(file_size), Some(record_count), Some(bytes)) = (
+                file_names.index(i),
+                file_sizes.index(i),
+                row_counts.index(i),
+                input_bytes.index(i),
+            ) else {
+                return Err(ErrorCode::Internal(format!(
+                    "IcebergMetadataWriter got a block of {} rows with shorter columns",
+                    data.num_rows()
+                )));
+            };
+            self.files.push(DataFileEntry {
+                file_path: self.absolute_location(file_name),
+                file_size,
+                record_count,
+            });
+            self.summary.add(&DataSummary {
+                row_counts: record_count as usize,
+                input_bytes: bytes as usize,
+                output_bytes: file_size as usize,
+            });
+        }
+
+        match self.detailed_output {
+            true => {
+                let num_rows = data.num_rows();
+                let columns = data.columns()[..3].to_vec();
+                Ok(Some(DataBlock::new(columns, num_rows)))
+            }
+            false => Ok(None),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self, output: bool) -> Result<Option<DataBlock>> {
+        self.commit().await?;
+        match output && !self.detailed_output {
+            true => Ok(Some(self.summary.to_block())),
+            false => Ok(None),
+        }
+    }
+}
+
+fn downcast_column<T: ArgType>(data: &DataBlock, offset: usize) -> Result<Value<T>> {
+    let entry = data.get_by_offset(offset);
+    entry.value.try_downcast::<T>().ok_or_else(|| {
+        ErrorCode::Internal(format!(
+            "IcebergMetadataWriter expects column {} of type {}, but got {}",
+            offset,
+            T::data_type(),
+            entry.data_type
+        ))
+    })
+}
+
+// The location of the stage root, in the form that iceberg readers understand.
+fn base_location(stage_info: &StageInfo) -> Result<String> {
+    let params = if stage_info.stage_type == StageType::External {
+        stage_info.stage_params.storage.clone()
+    } else {
+        let stage_prefix = stage_info.stage_prefix();
+        DataOperator::instance()
+            .params()
+            .map_root(|path| format!("{path}/{stage_prefix}"))
+    };
+
+    let (prefix, root) = match &params {
+        StorageParams::S3(cfg) => (format!("s3://{}", cfg.bucket), cfg.root.clone()),
+        StorageParams::Gcs(cfg) => (format!("gs://{}", cfg.bucket), cfg.root.clone()),
+        StorageParams::Oss(cfg) => (format!("oss://{}", cfg.bucket), cfg.root.clone()),
+        StorageParams::Cos(cfg) => (format!("cosn://{}", cfg.bucket), cfg.root.clone()),
+        StorageParams::Obs(cfg) => (format!("obs://{}", cfg.bucket), cfg.root.clone()),
+        StorageParams::Azblob(cfg) => (format!("azblob://{}", cfg.container), cfg.root.clone()),
+        StorageParams::Hdfs(cfg) => (
+            cfg.name_node.trim_end_matches('/').to_string(),
+            cfg.root.clone(),
+        ),
+        StorageParams::Fs(cfg) => {
+            let root = std::path::absolute(&cfg.root)?;
+            ("file://".to_string(), root.to_string_lossy().to_string())
+        }
+        _ => {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Unloading iceberg table to storage '{}' is not supported",
+                params
+            )));
+        }
+    };
+
+    let root = root
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join("/");
+    match root.is_empty() {
+        true => Ok(format!("{prefix}/")),
+        false => Ok(format!("{prefix}/{root}/")),
+    }
+}
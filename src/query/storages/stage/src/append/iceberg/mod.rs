@@ -0,0 +1,24 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod manifest;
+mod metadata_writer;
+mod schema;
+
+pub(crate) use metadata_writer::IcebergMetadataWriter;
+
+/// Directory of the data files, relative to the location of the unloaded table.
+pub(crate) const ICEBERG_DATA_DIR: &str = "data/";
+/// Directory of the metadata files, relative to the location of the unloaded table.
+pub(crate) const ICEBERG_METADATA_DIR: &str = "metadata/";
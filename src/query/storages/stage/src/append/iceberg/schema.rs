@@ -0,0 +1,193 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::DecimalDataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableSchema;
+use serde_json::json;
+use serde_json::Value as JsonValue;
+
+/// The iceberg form of a table schema.
+///
+/// Field ids are assigned the way iceberg does it, fields of a struct first and their
+/// nested fields afterwards. The unloaded parquet files carry no field ids, so a name
+/// mapping is built along, readers use it to resolve the parquet columns by name.
+pub struct IcebergSchema {
+    pub schema: JsonValue,
+    pub name_mapping: JsonValue,
+    pub last_column_id: i32,
+}
+
+impl IcebergSchema {
+    pub fn try_create(table_schema: &TableSchema) -> Result<Self> {
+        let (names, types): (Vec<_>, Vec<_>) = table_schema
+            .fields()
+            .iter()
+            .map(|f| (f.name().clone(), f.data_type().clone()))
+            .unzip();
+
+        let mut builder = IcebergSchemaBuilder { last_column_id: 0 };
+        let (fields, name_mapping) = builder.struct_fields(&names, &types)?;
+        Ok(IcebergSchema {
+            schema: json!({
+                "type": "struct",
+                "schema-id": 0,
+                "fields": fields,
+            }),
+            name_mapping: JsonValue::Array(name_mapping),
+            last_column_id: builder.last_column_id,
+        })
+    }
+}
+
+struct IcebergSchemaBuilder {
+    last_column_id: i32,
+}
+
+impl IcebergSchemaBuilder {
+    fn next_id(&mut self) -> i32 {
+        self.last_column_id += 1;
+        self.last_column_id
+    }
+
+    fn struct_fields(
+        &mut self,
+        names: &[String],
+        types: &[TableDataType],
+    ) -> Result<(Vec<JsonValue>, Vec<JsonValue>)> {
+        let ids = names.iter().map(|_| self.next_id()).collect::<Vec<_>>();
+
+        let mut fields = Vec::with_capacity(names.len());
+        let mut name_mapping = Vec::with_capacity(names.len());
+        for ((id, name), ty) in ids.into_iter().zip(names).zip(types) {
+            let (field_type, nested_mapping) = self.field_type(ty)?;
+            fields.push(json!({
+                "id": id,
+                "name": name,
+                "required": !ty.is_nullable_or_null(),
+                "type": field_type,
+            }));
+            name_mapping.push(mapped_field(id, &[name.as_str()], nested_mapping));
+        }
+        Ok((fields, name_mapping))
+    }
+
+    fn field_type(&mut self, ty: &TableDataType) -> Result<(JsonValue, Vec<JsonValue>)> {
+        let field_type = match ty.remove_nullable() {
+            TableDataType::Boolean => json!("boolean"),
+            TableDataType::Number(num_ty) => match num_ty {
+                NumberDataType::Int8
+                | NumberDataType::Int16
+                | NumberDataType::Int32
+                | NumberDataType::UInt8
+                | NumberDataType::UInt16 => json!("int"),
+                NumberDataType::Int64 | NumberDataType::UInt32 => json!("long"),
+                // Values above i64::MAX do not fit in a long, and the parquet files keep the
+                // unsigned type, so it can not be declared as decimal(20,0) either.
+                NumberDataType::UInt64 => {
+                    return Err(ErrorCode::Unimplemented(format!(
+                        "Unloading column of type {} to iceberg table is not supported, cast it to Int64 or Decimal(20, 0) first",
+                        ty
+                    )));
+                }
+                NumberDataType::Float32 => json!("float"),
+                NumberDataType::Float64 => json!("double"),
+            },
+            TableDataType::Decimal(DecimalDataType::Decimal128(size)) => {
+                json!(format!("decimal({},{})", size.precision, size.scale))
+            }
+            TableDataType::String => json!("string"),
+            TableDataType::Binary
+            | TableDataType::Variant
+            | TableDataType::Bitmap
+            | TableDataType::Geometry
+            | TableDataType::Geography => json!("binary"),
+            TableDataType::Date => json!("date"),
+            TableDataType::Timestamp => json!("timestamp"),
+            TableDataType::Array(inner) => {
+                let element_id = self.next_id();
+                let (element, nested_mapping) = self.field_type(&inner)?;
+                let field_type = json!({
+                    "type": "list",
+                    "element-id": element_id,
+                    "element": element,
+                    "element-required": !inner.is_nullable(),
+                });
+                // arrow names the list element `_array`.
+                let mapping = mapped_field(element_id, &["element", "_array"], nested_mapping);
+                return Ok((field_type, vec![mapping]));
+            }
+            TableDataType::Map(inner) => match *inner {
+                TableDataType::Tuple { fields_type, .. } if fields_type.len() == 2 => {
+                    let key_id = self.next_id();
+                    let value_id = self.next_id();
+                    let (key, key_mapping) = self.field_type(&fields_type[0])?;
+                    let (value, value_mapping) = self.field_type(&fields_type[1])?;
+                    let field_type = json!({
+                        "type": "map",
+                        "key-id": key_id,
+                        "key": key,
+                        "value-id": value_id,
+                        "value": value,
+                        "value-required": !fields_type[1].is_nullable(),
+                    });
+                    let mapping = vec![
+                        mapped_field(key_id, &["key"], key_mapping),
+                        mapped_field(value_id, &["value"], value_mapping),
+                    ];
+                    return Ok((field_type, mapping));
+                }
+                _ => return Err(unsupported_type(ty)),
+            },
+            TableDataType::Tuple {
+                fields_name,
+                fields_type,
+            } => {
+                let (fields, mapping) = self.struct_fields(&fields_name, &fields_type)?;
+                let field_type = json!({
+                    "type": "struct",
+                    "fields": fields,
+                });
+                return Ok((field_type, mapping));
+            }
+            TableDataType::Decimal(DecimalDataType::Decimal256(_))
+            | TableDataType::Null
+            | TableDataType::EmptyArray
+            | TableDataType::EmptyMap
+            | TableDataType::Nullable(_) => return Err(unsupported_type(ty)),
+        };
+        Ok((field_type, vec![]))
+    }
+}
+
+fn mapped_field(id: i32, names: &[&str], fields: Vec<JsonValue>) -> JsonValue {
+    let mut mapped_field = json!({
+        "field-id": id,
+        "names": names,
+    });
+    if !fields.is_empty() {
+        mapped_field["fields"] = JsonValue::Array(fields);
+    }
+    mapped_field
+}
+
+fn unsupported_type(ty: &TableDataType) -> ErrorCode {
+    ErrorCode::Unimplemented(format!(
+        "Unloading column of type {} to iceberg table is not supported",
+        ty
+    ))
+}
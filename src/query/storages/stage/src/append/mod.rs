@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod do_append;
+mod iceberg;
 mod output;
 mod parquet_file;
 mod path;
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_catalog::plan::StageTableInfo;
 use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::NumberDataType;
@@ -93,6 +94,9 @@ impl DataSummary {
 pub enum UnloadOutput {
     Summary(DataSummary),
     Detail(Vec<OutputFileInfo>),
+    /// Output of the files written for an iceberg table, consumed by the
+    /// `IcebergMetadataWriter` which reports the summary to the client.
+    IcebergFiles(Vec<OutputFileInfo>),
 }
 
 pub struct OutputFileInfo {
@@ -101,8 +105,11 @@ pub struct OutputFileInfo {
 }
 
 impl UnloadOutput {
-    pub fn create(detailed_output: bool) -> Self {
-        if detailed_output {
+    pub fn create(table_info: &StageTableInfo) -> Self {
+        let options = &table_info.copy_into_location_options;
+        if options.iceberg_metadata {
+            UnloadOutput::IcebergFiles(vec![])
+        } else if options.detailed_output {
             UnloadOutput::Detail(vec![])
         } else {
             UnloadOutput::Summary(DataSummary::new())
@@ -112,7 +119,7 @@ impl UnloadOutput {
     pub fn is_empty(&self) -> bool {
         match self {
             UnloadOutput::Summary(s) => s.row_counts == 0,
-            UnloadOutput::Detail(v) => v.is_empty(),
+            UnloadOutput::Detail(v) | UnloadOutput::IcebergFiles(v) => v.is_empty(),
         }
     }
 
@@ -121,7 +128,7 @@ impl UnloadOutput {
             UnloadOutput::Summary(s) => {
                 s.add(&summary);
             }
-            UnloadOutput::Detail(files) => {
+            UnloadOutput::Detail(files) | UnloadOutput::IcebergFiles(files) => {
                 files.push(OutputFileInfo {
                     file_name: file_name.to_string(),
                     summary,
@@ -135,34 +142,44 @@ impl UnloadOutput {
             UnloadOutput::Summary(summary) => {
                 vec![summary.to_block()]
             }
-            UnloadOutput::Detail(files) => {
-                let batch = 1000;
-                let mut blocks = vec![];
-                for i in 0..(files.len() + batch - 1) / batch {
-                    let end = files.len().min((i + 1) * batch);
-                    let chunk = &files[i * batch..end];
-                    blocks.push(file_infos_to_block(chunk));
-                }
-                blocks
-            }
+            UnloadOutput::Detail(files) => file_infos_to_blocks(files, false),
+            UnloadOutput::IcebergFiles(files) => file_infos_to_blocks(files, true),
         }
     }
 }
 
-fn file_infos_to_block(files: &[OutputFileInfo]) -> DataBlock {
+fn file_infos_to_blocks(files: &[OutputFileInfo], with_input_bytes: bool) -> Vec<DataBlock> {
+    let batch = 1000;
+    let mut blocks = vec![];
+    for i in 0..(files.len() + batch - 1) / batch {
+        let end = files.len().min((i + 1) * batch);
+        let chunk = &files[i * batch..end];
+        blocks.push(file_infos_to_block(chunk, with_input_bytes));
+    }
+    blocks
+}
+
+/// Columns are (file_name, file_size, row_count), followed by input_bytes if `with_input_bytes`.
+fn file_infos_to_block(files: &[OutputFileInfo], with_input_bytes: bool) -> DataBlock {
     let mut paths = Vec::with_capacity(files.len());
     let mut rows = Vec::with_capacity(files.len());
     let mut sizes = Vec::with_capacity(files.len());
+    let mut input_bytes = Vec::with_capacity(files.len());
     for file in files {
         paths.push(file.file_name.as_str());
         rows.push(file.summary.row_counts as u64);
         sizes.push(file.summary.output_bytes as u64);
+        input_bytes.push(file.summary.input_bytes as u64);
     }
-    DataBlock::new_from_columns(vec![
+    let mut columns = vec![
         StringType::from_data(paths),
         UInt64Type::from_data(sizes),
         UInt64Type::from_data(rows),
-    ])
+    ];
+    if with_input_bytes {
+        columns.push(UInt64Type::from_data(input_bytes));
+    }
+    DataBlock::new_from_columns(columns)
 }
 
 #[derive(Default)]
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::BTreeMap;
 use std::collections::VecDeque;
 use std::mem;
 use std::sync::Arc;
@@ -25,6 +26,9 @@ use databend_common_exception::Result;
 use databend_common_expression::converts::arrow::table_schema_to_arrow_schema;
 use databend_common_expression::BlockMetaInfoDowncast;
 use databend_common_expression::DataBlock;
+use databend_common_expression::ScalarRef;
+use databend_common_expression::TableSchema;
+use databend_common_expression::TableSchemaRef;
 use databend_common_pipeline_core::processors::Event;
 use databend_common_pipeline_core::processors::InputPort;
 use databend_common_pipeline_core::processors::OutputPort;
@@ -39,7 +43,8 @@ use parquet::file::properties::WriterProperties;
 
 use super::block_batch::BlockBatch;
 use crate::append::output::DataSummary;
-use crate::append::path::unload_path;
+use crate::append::path::unload_partition_dir;
+use crate::append::path::unload_partition_path;
 use crate::append::UnloadOutput;
 
 pub struct ParquetFileWriter {
@@ -47,15 +52,22 @@ pub struct ParquetFileWriter {
     output: Arc<OutputPort>,
 
     table_info: StageTableInfo,
+    /// The schema of the written files, without the partition column.
+    data_schema: TableSchemaRef,
     arrow_schema: Arc<ArrowSchema>,
 
     input_data: Vec<DataBlock>,
 
-    input_bytes: usize,
-    row_counts: usize,
-    writer: ArrowWriter<Vec<u8>>,
+    /// One writer per partition, keyed by the partition path.
+    /// Without `PARTITION BY`, all rows go to the writer of the empty partition.
+    /// At most `MAX_OPEN_PARTITION_WRITERS` are kept, buffering at most
+    /// `MAX_OPEN_PARTITION_WRITERS_BYTES` together, the least recently used ones
+    /// are closed to make room.
+    writers: BTreeMap<String, PartitionWriter>,
+    /// Incremented on every write, used to find the least recently used writer.
+    write_seq: u64,
 
-    file_to_write: Option<(Vec<u8>, DataSummary)>,
+    files_to_write: VecDeque<(String, Vec<u8>, DataSummary)>,
     data_accessor: Operator,
 
     // the result of statement
@@ -69,9 +81,28 @@ pub struct ParquetFileWriter {
     targe_file_size: Option<usize>,
 }
 
+struct PartitionWriter {
+    last_write_seq: u64,
+    input_bytes: usize,
+    row_counts: usize,
+    writer: ArrowWriter<Vec<u8>>,
+}
+
+impl PartitionWriter {
+    /// The finished row groups and the row group in progress, both kept in memory.
+    fn buffered_bytes(&self) -> usize {
+        self.writer.bytes_written() + self.writer.in_progress_size()
+    }
+}
+
 const MAX_BUFFER_SIZE: usize = 64 * 1024 * 1024;
 // this is number of rows, not size
 const MAX_ROW_GROUP_SIZE: usize = 1024 * 1024;
+// the partition path of rows whose `PARTITION BY` expression is NULL
+const NULL_PARTITION: &str = "_NULL_";
+// each open writer buffers its file in memory, so bound them for high-cardinality partitions
+const MAX_OPEN_PARTITION_WRITERS: usize = 32;
+const MAX_OPEN_PARTITION_WRITERS_BYTES: usize = 256 * 1024 * 1024;
 
 fn create_writer(
     arrow_schema: Arc<ArrowSchema>,
@@ -104,48 +135,154 @@ impl ParquetFileWriter {
         group_id: usize,
         targe_file_size: Option<usize>,
     ) -> Result<ProcessorPtr> {
-        let unload_output =
-            UnloadOutput::create(table_info.copy_into_location_options.detailed_output);
+        let unload_output = UnloadOutput::create(&table_info);
 
-        let arrow_schema = Arc::new(table_schema_to_arrow_schema(&table_info.schema));
-        let writer = create_writer(arrow_schema.clone(), targe_file_size)?;
+        let data_schema = if table_info.is_partitioned_unload {
+            let fields = table_info.schema.fields();
+            Arc::new(TableSchema::new(fields[..fields.len() - 1].to_vec()))
+        } else {
+            table_info.schema.clone()
+        };
+        let arrow_schema = Arc::new(table_schema_to_arrow_schema(&data_schema));
 
         Ok(ProcessorPtr::create(Box::new(ParquetFileWriter {
             input,
             output,
             table_info,
+            data_schema,
             arrow_schema,
             unload_output,
             unload_output_blocks: None,
-            writer,
             input_data: Vec::new(),
-            input_bytes: 0,
-            file_to_write: None,
+            writers: BTreeMap::new(),
+            write_seq: 0,
+            files_to_write: VecDeque::new(),
             data_accessor,
             query_id,
             group_id,
             batch_id: 0,
             targe_file_size,
-            row_counts: 0,
         })))
     }
-    pub fn reinit_writer(&mut self) -> Result<()> {
-        self.writer = create_writer(self.arrow_schema.clone(), self.targe_file_size)?;
-        self.row_counts = 0;
-        self.input_bytes = 0;
+
+    fn has_pending_rows(&self) -> bool {
+        self.writers.values().any(|w| w.row_counts > 0)
+    }
+
+    /// Split the block by the trailing partition column.
+    fn split_by_partition(&self, mut block: DataBlock) -> Result<Vec<(String, DataBlock)>> {
+        if !self.table_info.is_partitioned_unload {
+            return Ok(vec![(String::new(), block)]);
+        }
+        let num_rows = block.num_rows();
+        let column = block
+            .get_by_offset(block.num_columns() - 1)
+            .to_column(num_rows);
+        block.pop_columns(1);
+
+        let mut partitions: BTreeMap<String, Vec<u32>> = BTreeMap::new();
+        for row in 0..num_rows {
+            let partition = match column.index(row) {
+                Some(ScalarRef::String(s)) => unload_partition_dir(s),
+                _ => NULL_PARTITION.to_string(),
+            };
+            partitions.entry(partition).or_default().push(row as u32);
+        }
+        if partitions.len() == 1 {
+            let partition = partitions.into_keys().next().unwrap();
+            return Ok(vec![(partition, block)]);
+        }
+        partitions
+            .into_iter()
+            .map(|(partition, rows)| Ok((partition, block.take(rows.as_slice())?)))
+            .collect()
+    }
+
+    fn write(&mut self, partition: String, block: DataBlock) -> Result<()> {
+        if !self.writers.contains_key(&partition) {
+            if self.writers.len() >= MAX_OPEN_PARTITION_WRITERS {
+                self.close_least_recently_used()?;
+            }
+            let writer = PartitionWriter {
+                last_write_seq: 0,
+                input_bytes: 0,
+                row_counts: 0,
+                writer: create_writer(self.arrow_schema.clone(), self.targe_file_size)?,
+            };
+            self.writers.insert(partition.clone(), writer);
+        }
+        self.write_seq += 1;
+        let writer = self.writers.get_mut(&partition).unwrap();
+        writer.last_write_seq = self.write_seq;
+        writer.input_bytes += block.memory_size();
+        writer.row_counts += block.num_rows();
+        let batch = block.to_record_batch(&self.data_schema)?;
+        writer.writer.write(&batch)?;
+
+        if let Some(target) = self.targe_file_size {
+            if writer.row_counts > 0 {
+                // written row groups: compressed, controlled by MAX_ROW_GROUP_SIZE
+                let file_size = writer.writer.bytes_written();
+                // in_progress row group: each column leaf has an at most 1MB uncompressed buffer and multi compressed pages
+                // may result in small file for schema with many columns
+                let in_progress = writer.writer.in_progress_size();
+                if file_size + in_progress >= target {
+                    self.flush(partition, true)?;
+                }
+            }
+        }
+        self.close_writers_over_memory_limit()
+    }
+
+    /// The writer just written to is the most recently used, so it is always kept.
+    fn close_writers_over_memory_limit(&mut self) -> Result<()> {
+        while self.writers.len() > 1 {
+            let buffered = self
+                .writers
+                .values()
+                .map(PartitionWriter::buffered_bytes)
+                .sum::<usize>();
+            if buffered <= MAX_OPEN_PARTITION_WRITERS_BYTES {
+                break;
+            }
+            self.close_least_recently_used()?;
+        }
         Ok(())
     }
 
-    fn flush(&mut self) -> Result<()> {
-        _ = self.writer.finish();
-        let buf = mem::take(self.writer.inner_mut());
+    fn close_least_recently_used(&mut self) -> Result<()> {
+        let partition = self
+            .writers
+            .iter()
+            .min_by_key(|(_, w)| w.last_write_seq)
+            .map(|(partition, _)| partition.clone())
+            .unwrap();
+        if self.writers[&partition].row_counts > 0 {
+            self.flush(partition, false)
+        } else {
+            self.writers.remove(&partition);
+            Ok(())
+        }
+    }
+
+    /// Finish the file of the partition, keep the writer open for more rows if `reopen`.
+    fn flush(&mut self, partition: String, reopen: bool) -> Result<()> {
+        let mut writer = self.writers.remove(&partition).unwrap();
+        _ = writer.writer.finish();
+        let buf = mem::take(writer.writer.inner_mut());
         let output_bytes = buf.len();
-        self.file_to_write = Some((buf, DataSummary {
-            row_counts: self.row_counts,
-            input_bytes: self.input_bytes,
+        let summary = DataSummary {
+            row_counts: writer.row_counts,
+            input_bytes: writer.input_bytes,
             output_bytes,
-        }));
-        self.reinit_writer()?;
+        };
+        if reopen {
+            writer.writer = create_writer(self.arrow_schema.clone(), self.targe_file_size)?;
+            writer.row_counts = 0;
+            writer.input_bytes = 0;
+            self.writers.insert(partition.clone(), writer);
+        }
+        self.files_to_write.push_back((partition, buf, summary));
         Ok(())
     }
 }
@@ -164,14 +301,14 @@ impl Processor for ParquetFileWriter {
         if self.output.is_finished() {
             self.input.finish();
             Ok(Event::Finished)
-        } else if self.file_to_write.is_some() {
+        } else if !self.files_to_write.is_empty() {
             self.input.set_not_need_data();
             Ok(Event::Async)
         } else if !self.input_data.is_empty() {
             self.input.set_not_need_data();
             Ok(Event::Sync)
         } else if self.input.is_finished() {
-            if self.row_counts > 0 {
+            if self.has_pending_rows() {
                 return Ok(Event::Sync);
             }
             if self.unload_output.is_empty() {
@@ -212,45 +349,43 @@ impl Processor for ParquetFileWriter {
 
     fn process(&mut self) -> Result<()> {
         while let Some(b) = self.input_data.pop() {
-            self.input_bytes += b.memory_size();
-            self.row_counts += b.num_rows();
-            let batch = b.to_record_batch(&self.table_info.schema)?;
-            self.writer.write(&batch)?;
-
-            if let Some(target) = self.targe_file_size {
-                if self.row_counts > 0 {
-                    // written row groups: compressed, controlled by MAX_ROW_GROUP_SIZE
-                    let file_size = self.writer.bytes_written();
-                    // in_progress row group: each column leaf has an at most 1MB uncompressed buffer and multi compressed pages
-                    // may result in small file for schema with many columns
-                    let in_progress = self.writer.in_progress_size();
-                    if file_size + in_progress >= target {
-                        self.flush()?;
-                        return Ok(());
-                    }
-                }
+            for (partition, block) in self.split_by_partition(b)? {
+                self.write(partition, block)?;
+            }
+            if !self.files_to_write.is_empty() {
+                return Ok(());
             }
         }
-        if self.input.is_finished() && self.row_counts > 0 {
-            self.flush()?;
+        if self.input.is_finished() {
+            let partitions = self
+                .writers
+                .iter()
+                .filter(|(_, w)| w.row_counts > 0)
+                .map(|(partition, _)| partition.clone())
+                .collect::<Vec<_>>();
+            for partition in partitions {
+                self.flush(partition, false)?;
+            }
         }
         Ok(())
     }
 
     #[async_backtrace::framed]
     async fn async_process(&mut self) -> Result<()> {
-        assert!(self.file_to_write.is_some());
-        let path = unload_path(
-            &self.table_info,
-            &self.query_id,
-            self.group_id,
-            self.batch_id,
-            None,
-        );
-        let (data, summary) = mem::take(&mut self.file_to_write).unwrap();
-        self.unload_output.add_file(&path, summary);
-        self.data_accessor.write(&path, data).await?;
-        self.batch_id += 1;
+        assert!(!self.files_to_write.is_empty());
+        while let Some((partition, data, summary)) = self.files_to_write.pop_front() {
+            let path = unload_partition_path(
+                &self.table_info,
+                &partition,
+                &self.query_id,
+                self.group_id,
+                self.batch_id,
+                None,
+            );
+            self.unload_output.add_file(&path, summary);
+            self.data_accessor.write(&path, data).await?;
+            self.batch_id += 1;
+        }
         Ok(())
     }
 }
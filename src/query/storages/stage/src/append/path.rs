@@ -15,6 +15,8 @@
 use databend_common_catalog::plan::StageTableInfo;
 use databend_common_compress::CompressAlgorithm;
 
+use crate::append::iceberg::ICEBERG_DATA_DIR;
+
 pub fn unload_path(
     stage_table_info: &StageTableInfo,
     query_id: &str,
@@ -33,6 +35,11 @@ pub fn unload_path(
         .unwrap_or_default();
 
     let path = &stage_table_info.files_info.path;
+    // Data files of an iceberg table live in the `data` directory of the table location.
+    let path = &match stage_table_info.copy_into_location_options.iceberg_metadata {
+        true => format!("{}{ICEBERG_DATA_DIR}", path.trim_start_matches('/')),
+        false => path.to_string(),
+    };
     if stage_table_info.copy_into_location_options.use_raw_path {
         path.to_string()
     } else {
@@ -61,3 +68,68 @@ pub fn unload_path(
         }
     }
 }
+
+/// Turn the value of the `PARTITION BY` expression into a relative directory.
+///
+/// `/` separates nested directories, empty segments are dropped. `.` and `..`
+/// segments, `%`, backslashes and control characters are percent-encoded, so the
+/// result can never escape the unload path.
+pub fn unload_partition_dir(value: &str) -> String {
+    let mut dir = String::with_capacity(value.len());
+    for segment in value.split('/').filter(|s| !s.is_empty()) {
+        if !dir.is_empty() {
+            dir.push('/');
+        }
+        if segment == "." || segment == ".." {
+            dir.push_str(&"%2E".repeat(segment.len()));
+            continue;
+        }
+        for c in segment.chars() {
+            if c.is_control() || c == '%' || c == '\\' {
+                let mut buf = [0; 4];
+                for b in c.encode_utf8(&mut buf).bytes() {
+                    dir.push_str(&format!("%{:02X}", b));
+                }
+            } else {
+                dir.push(c);
+            }
+        }
+    }
+    dir
+}
+
+/// Files of a partition are written to the `<partition>` sub directory of the
+/// unload path, the partition is the value of the `PARTITION BY` expression.
+pub fn unload_partition_path(
+    stage_table_info: &StageTableInfo,
+    partition: &str,
+    query_id: &str,
+    group_id: usize,
+    batch_id: usize,
+    compression: Option<CompressAlgorithm>,
+) -> String {
+    let path = unload_path(stage_table_info, query_id, group_id, batch_id, compression);
+    if partition.is_empty() {
+        return path;
+    }
+    match path.rfind('/') {
+        Some(pos) => format!("{}/{partition}/{}", &path[..pos], &path[pos + 1..]),
+        None => format!("{partition}/{path}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::unload_partition_dir;
+
+    #[test]
+    fn test_unload_partition_dir() {
+        assert_eq!(unload_partition_dir("dt=2024-01-01"), "dt=2024-01-01");
+        assert_eq!(unload_partition_dir("/a//b/"), "a/b");
+        assert_eq!(unload_partition_dir("../../etc"), "%2E%2E/%2E%2E/etc");
+        assert_eq!(unload_partition_dir("a/./b"), "a/%2E/b");
+        assert_eq!(unload_partition_dir("a..b"), "a..b");
+        assert_eq!(unload_partition_dir("a\nb%c\\d"), "a%0Ab%25c%5Cd");
+        assert_eq!(unload_partition_dir(""), "");
+    }
+}
@@ -70,8 +70,7 @@ impl RowBasedFileWriter {
         group_id: usize,
         compression: Option<CompressAlgorithm>,
     ) -> Result<ProcessorPtr> {
-        let unload_output =
-            UnloadOutput::create(table_info.copy_into_location_options.detailed_output);
+        let unload_output = UnloadOutput::create(&table_info);
         Ok(ProcessorPtr::create(Box::new(RowBasedFileWriter {
             table_info,
             input,
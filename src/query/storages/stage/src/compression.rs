@@ -34,11 +34,7 @@ pub fn get_compression_alg_copy(
                 "compress type lzo is unimplemented",
             ));
         }
-        StageFileCompression::Snappy => {
-            return Err(ErrorCode::Unimplemented(
-                "compress type snappy is unimplemented",
-            ));
-        }
+        StageFileCompression::Snappy => Some(CompressAlgorithm::Snappy),
         StageFileCompression::None => None,
     };
     Ok(compression_algo)
@@ -24,6 +24,8 @@ use databend_common_storage::FileStatus;
 pub struct ErrorHandler {
     pub on_error_mode: OnErrorMode,
     pub on_error_count: AtomicU64,
+    /// Keep the rejected rows in the file status, set by `REJECTED_ROWS_PATH`.
+    pub keep_rejected_rows: bool,
 }
 
 impl ErrorHandler {
@@ -34,6 +36,7 @@ impl ErrorHandler {
         file_status: &mut FileStatus,
         file_path: &str,
         line: usize,
+        row: &[u8],
     ) -> Result<()> {
         if let Some((columns, num_rows)) = columns {
             columns.iter_mut().for_each(|c| {
@@ -48,6 +51,9 @@ impl ErrorHandler {
 
         match &self.on_error_mode {
             OnErrorMode::Continue => {
+                if self.keep_rejected_rows {
+                    file_status.add_rejected_row(&e, line, row);
+                }
                 file_status.add_error(e, line);
                 Ok(())
             }
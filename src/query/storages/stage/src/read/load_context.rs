@@ -87,6 +87,7 @@ impl LoadContext {
             error_handler: ErrorHandler {
                 on_error_mode,
                 on_error_count: AtomicU64::new(0),
+                keep_rejected_rows: copy_options.rejected_rows_path.is_some(),
             },
         })
     }
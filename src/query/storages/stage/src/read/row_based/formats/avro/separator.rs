@@ -0,0 +1,94 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use apache_avro::Reader;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_storage::FileStatus;
+
+use crate::read::row_based::batch::BytesBatch;
+use crate::read::row_based::batch::NdjsonRowBatch;
+use crate::read::row_based::batch::Position;
+use crate::read::row_based::batch::RowBatch;
+use crate::read::row_based::batch::RowBatchWithPosition;
+use crate::read::row_based::format::SeparatorState;
+
+// the json rows of a file are split into batches of about this size
+const MAX_BATCH_BYTES: usize = 1024 * 1024;
+
+/// The blocks of an avro object container file can only be decoded with the schema in
+/// its header, so the file is kept until eof, then each record is written as a json row.
+pub struct AvroRowSeparator {
+    data: Vec<u8>,
+    pos: Position,
+}
+
+impl SeparatorState for AvroRowSeparator {
+    fn append(&mut self, batch: BytesBatch) -> Result<(Vec<RowBatchWithPosition>, FileStatus)> {
+        self.separate(batch)
+    }
+}
+
+impl AvroRowSeparator {
+    pub fn try_create(path: &str) -> Result<Self> {
+        Ok(Self {
+            data: vec![],
+            pos: Position::new(path.to_string()),
+        })
+    }
+
+    fn separate(
+        &mut self,
+        mut batch: BytesBatch,
+    ) -> Result<(Vec<RowBatchWithPosition>, FileStatus)> {
+        if self.data.is_empty() {
+            self.data = std::mem::take(&mut batch.data);
+        } else {
+            self.data.extend_from_slice(&batch.data);
+        }
+        if !batch.is_eof {
+            return Ok((vec![], FileStatus::default()));
+        }
+
+        let data = std::mem::take(&mut self.data);
+        let reader = Reader::new(data.as_slice()).map_err(|e| self.error(e))?;
+        let mut batches = vec![];
+        let mut rows = NdjsonRowBatch::default();
+        for value in reader {
+            let value = value.map_err(|e| self.error(e))?;
+            let value = serde_json::Value::try_from(value).map_err(|e| self.error(e))?;
+            serde_json::to_writer(&mut rows.data, &value)?;
+            rows.data.push(b'\n');
+            rows.row_ends.push(rows.data.len());
+            if rows.data.len() >= MAX_BATCH_BYTES {
+                batches.push(self.take_batch(&mut rows));
+            }
+        }
+        if rows.rows() > 0 {
+            batches.push(self.take_batch(&mut rows));
+        }
+        Ok((batches, FileStatus::default()))
+    }
+
+    fn take_batch(&mut self, rows: &mut NdjsonRowBatch) -> RowBatchWithPosition {
+        let rows = std::mem::take(rows);
+        let out_pos = self.pos.clone();
+        self.pos.rows += rows.rows();
+        RowBatchWithPosition::new(RowBatch::NDJson(rows), out_pos)
+    }
+
+    fn error(&self, e: impl std::fmt::Display) -> ErrorCode {
+        ErrorCode::BadBytes(format!("invalid avro file {}: {e}", self.pos.path))
+    }
+}
@@ -147,6 +147,24 @@ impl CsvDecoder {
         }
         Ok(())
     }
+
+    /// The decoded fields joined by the field delimiter, quotes and escapes of the
+    /// original record are not kept.
+    fn rejected_row(&self, buf: &[u8], field_ends: &[usize]) -> Vec<u8> {
+        if !self.load_context.error_handler.keep_rejected_rows {
+            return vec![];
+        }
+        let mut row = Vec::with_capacity(buf.len() + field_ends.len());
+        let mut field_start = 0;
+        for (i, field_end) in field_ends.iter().enumerate() {
+            if i > 0 {
+                row.extend_from_slice(self.fmt.params.field_delimiter.as_bytes());
+            }
+            row.extend_from_slice(&buf[field_start..*field_end]);
+            field_start = *field_end;
+        }
+        row
+    }
 }
 
 impl RowDecoder for CsvDecoder {
@@ -162,17 +180,15 @@ impl RowDecoder for CsvDecoder {
         for (i, end) in data.row_ends.iter().enumerate() {
             let num_fields = data.num_fields[i];
             let buf = &data.data[start..*end];
-            if let Err(e) = self.read_row(
-                buf,
-                columns,
-                &data.field_ends[field_end_idx..field_end_idx + num_fields],
-            ) {
+            let field_ends = &data.field_ends[field_end_idx..field_end_idx + num_fields];
+            if let Err(e) = self.read_row(buf, columns, field_ends) {
                 self.load_context.error_handler.on_error(
                     e,
                     Some((columns, state.num_rows)),
                     &mut state.file_status,
                     &batch.start_pos.path,
                     i + batch.start_pos.rows,
+                    &self.rejected_row(buf, field_ends),
                 )?
             } else {
                 state.num_rows += 1;
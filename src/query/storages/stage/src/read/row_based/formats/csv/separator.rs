@@ -169,6 +169,7 @@ impl CsvReader {
                                     file_status,
                                     &self.pos.path,
                                     self.pos.rows,
+                                    &input[..n_in],
                                 )?;
                                 ReadRecordOutput::RecordSkipped
                             } else {
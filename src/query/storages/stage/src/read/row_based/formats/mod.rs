@@ -12,10 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod avro;
 mod csv;
 mod ndjson;
 mod tsv;
 
+pub use avro::AvroInputFormat;
 pub use csv::CsvInputFormat;
 pub use ndjson::NdJsonInputFormat;
 pub use tsv::TsvInputFormat;
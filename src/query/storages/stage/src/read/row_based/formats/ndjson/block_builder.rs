@@ -179,6 +179,7 @@ impl RowDecoder for NdJsonDecoder {
                         &mut state.file_status,
                         &batch.start_pos.path,
                         batch.start_pos.rows + row_id,
+                        row,
                     )?
                 } else {
                     state.num_rows += 1;
@@ -16,4 +16,5 @@ mod block_builder;
 mod format;
 mod separator;
 
+pub(super) use block_builder::NdJsonDecoder;
 pub use format::NdJsonInputFormat;
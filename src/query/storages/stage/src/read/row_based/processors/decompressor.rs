@@ -44,12 +44,13 @@ impl Decompressor {
         })
     }
 
-    fn new_file(&mut self, path: String) {
+    fn new_file(&mut self, path: String, head: &[u8]) {
         assert!(self.decompressor.is_none());
         let algo = if let Some(algo) = &self.algo {
             Some(algo.to_owned())
         } else {
-            CompressAlgorithm::from_path(&path)
+            // COMPRESSION = AUTO: detect by the file extension first, then by the magic bytes
+            CompressAlgorithm::from_path(&path).or_else(|| CompressAlgorithm::from_magic(head))
         };
         self.path = Some(path);
 
@@ -71,10 +72,10 @@ impl AccumulatingTransform for Decompressor {
             .and_then(BytesBatch::downcast_from)
             .unwrap();
         match &self.path {
-            None => self.new_file(batch.path.clone()),
+            None => self.new_file(batch.path.clone(), &batch.data),
             Some(path) => {
                 if path != &batch.path {
-                    self.new_file(batch.path.clone())
+                    self.new_file(batch.path.clone(), &batch.data)
                 }
             }
         }
@@ -34,6 +34,9 @@ pub struct ClusteringHistoryLogElement {
     pub table: String,
     pub byte_size: u64,
     pub row_count: u64,
+    /// NULL unless `enable_clustering_history_depth` is set and the depth is collected.
+    pub average_overlaps: Option<f64>,
+    pub average_depth: Option<f64>,
 }
 
 impl SystemLogElement for ClusteringHistoryLogElement {
@@ -47,6 +50,14 @@ impl SystemLogElement for ClusteringHistoryLogElement {
             TableField::new("table", TableDataType::String),
             TableField::new("byte_size", TableDataType::Number(NumberDataType::UInt64)),
             TableField::new("row_count", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "average_overlaps",
+                TableDataType::Number(NumberDataType::Float64).wrap_nullable(),
+            ),
+            TableField::new(
+                "average_depth",
+                TableDataType::Number(NumberDataType::Float64).wrap_nullable(),
+            ),
         ])
     }
 
@@ -76,6 +87,18 @@ impl SystemLogElement for ClusteringHistoryLogElement {
             .next()
             .unwrap()
             .push(Scalar::Number(NumberScalar::UInt64(self.row_count)).as_ref());
+        columns.next().unwrap().push(
+            self.average_overlaps
+                .map(|v| Scalar::Number(NumberScalar::Float64(v.into())))
+                .unwrap_or(Scalar::Null)
+                .as_ref(),
+        );
+        columns.next().unwrap().push(
+            self.average_depth
+                .map(|v| Scalar::Number(NumberScalar::Float64(v.into())))
+                .unwrap_or(Scalar::Null)
+                .as_ref(),
+        );
         Ok(())
     }
 }
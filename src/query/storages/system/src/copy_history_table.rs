@@ -0,0 +1,146 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRef;
+use databend_common_expression::TableSchemaRefExt;
+
+use crate::SystemLogElement;
+use crate::SystemLogQueue;
+use crate::SystemLogTable;
+
+/// One file of a `COPY INTO <table>` that finished, either loaded or skipped because it
+/// was already loaded into the table.
+#[derive(Clone)]
+pub struct CopyHistoryLogElement {
+    pub event_time: i64,
+    pub query_id: String,
+    pub database: String,
+    pub table: String,
+    pub stage: String,
+    pub file_name: String,
+    /// NULL for skipped files, they are not listed again.
+    pub file_size: Option<u64>,
+    pub etag: Option<String>,
+    /// `LOADED`, `PARTIALLY_LOADED` if some rows are skipped by `ON_ERROR`, or `SKIPPED`.
+    pub status: String,
+    pub rows_loaded: u64,
+    pub errors_seen: u64,
+    pub first_error: Option<String>,
+    pub first_error_line: Option<u64>,
+}
+
+impl SystemLogElement for CopyHistoryLogElement {
+    const TABLE_NAME: &'static str = "copy_history";
+
+    fn schema() -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![
+            TableField::new("event_time", TableDataType::Timestamp),
+            TableField::new("query_id", TableDataType::String),
+            TableField::new("database", TableDataType::String),
+            TableField::new("table", TableDataType::String),
+            TableField::new("stage", TableDataType::String),
+            TableField::new("file_name", TableDataType::String),
+            TableField::new(
+                "file_size",
+                TableDataType::Number(NumberDataType::UInt64).wrap_nullable(),
+            ),
+            TableField::new("etag", TableDataType::String.wrap_nullable()),
+            TableField::new("status", TableDataType::String),
+            TableField::new("rows_loaded", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("errors_seen", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("first_error", TableDataType::String.wrap_nullable()),
+            TableField::new(
+                "first_error_line",
+                TableDataType::Number(NumberDataType::UInt64).wrap_nullable(),
+            ),
+        ])
+    }
+
+    fn fill_to_data_block(&self, columns: &mut Vec<ColumnBuilder>) -> Result<()> {
+        let mut columns = columns.iter_mut();
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Timestamp(self.event_time).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.query_id.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.database.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.table.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.stage.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.file_name.clone()).as_ref());
+        columns.next().unwrap().push(
+            self.file_size
+                .map(|v| Scalar::Number(NumberScalar::UInt64(v)))
+                .unwrap_or(Scalar::Null)
+                .as_ref(),
+        );
+        columns.next().unwrap().push(
+            self.etag
+                .clone()
+                .map(Scalar::String)
+                .unwrap_or(Scalar::Null)
+                .as_ref(),
+        );
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.status.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.rows_loaded)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.errors_seen)).as_ref());
+        columns.next().unwrap().push(
+            self.first_error
+                .clone()
+                .map(Scalar::String)
+                .unwrap_or(Scalar::Null)
+                .as_ref(),
+        );
+        columns.next().unwrap().push(
+            self.first_error_line
+                .map(|v| Scalar::Number(NumberScalar::UInt64(v)))
+                .unwrap_or(Scalar::Null)
+                .as_ref(),
+        );
+        Ok(())
+    }
+}
+
+pub type CopyHistoryQueue = SystemLogQueue<CopyHistoryLogElement>;
+pub type CopyHistoryTable = SystemLogTable<CopyHistoryLogElement>;
@@ -31,6 +31,7 @@ mod clusters_table;
 mod columns_table;
 mod configs_table;
 mod contributors_table;
+mod copy_history_table;
 mod credits_table;
 mod databases_table;
 mod dictionaries_table;
@@ -39,6 +40,7 @@ mod functions_table;
 mod indexes_table;
 mod locks_table;
 mod log_queue;
+mod login_failures_table;
 #[cfg(feature = "jemalloc")]
 mod malloc_stats_table;
 #[cfg(feature = "jemalloc")]
@@ -82,6 +84,9 @@ pub use clusters_table::ClustersTable;
 pub use columns_table::ColumnsTable;
 pub use configs_table::ConfigsTable;
 pub use contributors_table::ContributorsTable;
+pub use copy_history_table::CopyHistoryLogElement;
+pub use copy_history_table::CopyHistoryQueue;
+pub use copy_history_table::CopyHistoryTable;
 pub use credits_table::CreditsTable;
 pub use databases_table::DatabasesTable;
 pub use databases_table::DatabasesTableWithHistory;
@@ -94,6 +99,7 @@ pub use locks_table::LocksTable;
 pub use log_queue::SystemLogElement;
 pub use log_queue::SystemLogQueue;
 pub use log_queue::SystemLogTable;
+pub use login_failures_table::LoginFailuresTable;
 #[cfg(feature = "jemalloc")]
 pub use malloc_stats_table::MallocStatsTable;
 #[cfg(feature = "jemalloc")]
@@ -55,7 +55,7 @@ impl SyncSystemTable for MetricsTable {
         let local_id = ctx.get_cluster().local_id.clone();
 
         let mut samples = GLOBAL_METRICS_REGISTRY.dump_sample()?;
-        samples.extend(self.custom_metric_samples()?);
+        samples.extend(Self::custom_metric_samples()?);
 
         let mut nodes: Vec<String> = Vec::with_capacity(samples.len());
         let mut metrics: Vec<String> = Vec::with_capacity(samples.len());
@@ -136,7 +136,7 @@ impl MetricsTable {
     }
 
     /// Custom metrics that are not collected by prometheus.
-    fn custom_metric_samples(&self) -> Result<Vec<MetricSample>> {
+    pub fn custom_metric_samples() -> Result<Vec<MetricSample>> {
         let samples = vec![
             MetricSample {
                 name: "query_memory_usage_bytes".to_string(),
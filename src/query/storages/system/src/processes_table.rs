@@ -26,6 +26,7 @@ use databend_common_expression::types::number::UInt64Type;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::StringType;
 use databend_common_expression::types::TimestampType;
+use databend_common_expression::types::VariantType;
 use databend_common_expression::utils::FromData;
 use databend_common_expression::DataBlock;
 use databend_common_expression::TableDataType;
@@ -75,10 +76,16 @@ impl SyncSystemTable for ProcessesTable {
         let mut processes_created_time = Vec::with_capacity(processes_info.len());
         let mut processes_status = Vec::with_capacity(processes_info.len());
         let mut processes_current_query_id = Vec::with_capacity(processes_info.len());
+        let mut processes_scan_progress_total_rows = Vec::with_capacity(processes_info.len());
+        let mut processes_scan_progress_total_bytes = Vec::with_capacity(processes_info.len());
+        let mut processes_spill_bytes = Vec::with_capacity(processes_info.len());
+        let mut processes_current_operator = Vec::with_capacity(processes_info.len());
+        let mut processes_scan_progress_by_node = Vec::with_capacity(processes_info.len());
 
         for process_info in &processes_info {
             let data_metrics = &process_info.data_metrics;
             let scan_progress = process_info.scan_progress_value.clone().unwrap_or_default();
+            let total_scan = process_info.total_scan_value.clone().unwrap_or_default();
 
             let created_time: DateTime<Utc> = process_info.created_time.into();
             let created_time = created_time.timestamp_micros();
@@ -121,6 +128,16 @@ impl SyncSystemTable for ProcessesTable {
                     .clone()
                     .unwrap_or("".to_owned()),
             );
+            processes_scan_progress_total_rows.push(total_scan.rows as u64);
+            processes_scan_progress_total_bytes.push(total_scan.bytes as u64);
+            processes_spill_bytes.push(process_info.spill_bytes.unwrap_or(0) as u64);
+            processes_current_operator.push(process_info.running_operators.join(", "));
+            processes_scan_progress_by_node.push(
+                process_info
+                    .node_scan_progress
+                    .as_ref()
+                    .map(|progress| serde_json::to_vec(progress).unwrap()),
+            );
         }
 
         Ok(DataBlock::new_from_columns(vec![
@@ -142,6 +159,11 @@ impl SyncSystemTable for ProcessesTable {
             TimestampType::from_data(processes_created_time),
             StringType::from_data(processes_status),
             StringType::from_data(processes_current_query_id),
+            UInt64Type::from_data(processes_scan_progress_total_rows),
+            UInt64Type::from_data(processes_scan_progress_total_bytes),
+            UInt64Type::from_data(processes_spill_bytes),
+            StringType::from_data(processes_current_operator),
+            VariantType::from_opt_data(processes_scan_progress_by_node),
         ]))
     }
 }
@@ -185,6 +207,20 @@ impl ProcessesTable {
             TableField::new("created_time", TableDataType::Timestamp),
             TableField::new("status", TableDataType::String),
             TableField::new("current_query_id", TableDataType::String),
+            TableField::new(
+                "scan_progress_total_rows",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "scan_progress_total_bytes",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new("spill_bytes", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("current_operator", TableDataType::String),
+            TableField::new(
+                "scan_progress_by_node",
+                TableDataType::Variant.wrap_nullable(),
+            ),
         ]);
 
         let table_info = TableInfo {
@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use jwt_simple::algorithms::ECDSAP256PublicKeyLike;
@@ -19,11 +22,14 @@ use jwt_simple::algorithms::ES256PublicKey;
 use jwt_simple::algorithms::RS256PublicKey;
 use jwt_simple::algorithms::RSAPublicKeyLike;
 use jwt_simple::prelude::JWTClaims;
+use jwt_simple::prelude::VerificationOptions;
 use jwt_simple::token::Token;
 use serde::Deserialize;
 use serde::Serialize;
+use serde_json::Value;
 
 use super::jwk;
+use crate::BUILTIN_ROLE_ACCOUNT_ADMIN;
 
 #[derive(Debug, Clone)]
 pub enum PubKey {
@@ -32,8 +38,14 @@ pub enum PubKey {
 }
 
 pub struct JwtAuthenticator {
-    // Todo(youngsofun): verify settings, like issuer
     key_stores: Vec<jwk::JwkKeyStore>,
+    // None means the `iss` / `aud` claim is not checked
+    allowed_issuers: Option<HashSet<String>>,
+    allowed_audiences: Option<HashSet<String>>,
+    // None means no claim is mapped to roles
+    role_claim: Option<String>,
+    // claim value -> role, claim values not listed are not mapped
+    role_mapping: BTreeMap<String, String>,
 }
 
 #[derive(Debug, Default, Deserialize, Serialize)]
@@ -46,6 +58,9 @@ pub struct CustomClaims {
     pub tenant_id: Option<String>,
     pub role: Option<String>,
     pub ensure_user: Option<EnsureUser>,
+    /// All the other claims, the configured role claim is looked up here.
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
 }
 
 impl CustomClaims {
@@ -54,6 +69,7 @@ impl CustomClaims {
             tenant_id: None,
             role: None,
             ensure_user: None,
+            extra: BTreeMap::new(),
         }
     }
 
@@ -87,7 +103,89 @@ impl JwtAuthenticator {
         for u in jwt_key_files {
             key_stores.push(jwk::JwkKeyStore::new(u))
         }
-        Some(JwtAuthenticator { key_stores })
+        Some(JwtAuthenticator {
+            key_stores,
+            allowed_issuers: None,
+            allowed_audiences: None,
+            role_claim: None,
+            role_mapping: BTreeMap::new(),
+        })
+    }
+
+    /// Only accept tokens issued by one of `issuers`, an empty list accepts any issuer.
+    pub fn with_allowed_issuers(mut self, issuers: Vec<String>) -> Self {
+        if !issuers.is_empty() {
+            self.allowed_issuers = Some(issuers.into_iter().collect());
+        }
+        self
+    }
+
+    /// Only accept tokens for one of `audiences`, an empty list accepts any audience.
+    pub fn with_allowed_audiences(mut self, audiences: Vec<String>) -> Self {
+        if !audiences.is_empty() {
+            self.allowed_audiences = Some(audiences.into_iter().collect());
+        }
+        self
+    }
+
+    /// Map the values of the `claim` claim of a token to roles with the role mapping, an
+    /// empty name disables the mapping.
+    pub fn with_role_claim(mut self, claim: String) -> Self {
+        if !claim.is_empty() {
+            self.role_claim = Some(claim);
+        }
+        self
+    }
+
+    /// Set the allow-list of `<claim value>=<role>` entries, the built-in admin role
+    /// can not be mapped.
+    pub fn with_role_mapping(mut self, mapping: Vec<String>) -> Result<Self> {
+        for entry in mapping {
+            let Some((value, role)) = entry.split_once('=') else {
+                return Err(ErrorCode::InvalidConfig(format!(
+                    "invalid jwt role mapping '{entry}', expect '<claim value>=<role>'"
+                )));
+            };
+            let (value, role) = (value.trim(), role.trim());
+            if value.is_empty() || role.is_empty() {
+                return Err(ErrorCode::InvalidConfig(format!(
+                    "invalid jwt role mapping '{entry}', expect '<claim value>=<role>'"
+                )));
+            }
+            if role.eq_ignore_ascii_case(BUILTIN_ROLE_ACCOUNT_ADMIN) {
+                return Err(ErrorCode::InvalidConfig(format!(
+                    "invalid jwt role mapping '{entry}', built-in role '{BUILTIN_ROLE_ACCOUNT_ADMIN}' can not be mapped"
+                )));
+            }
+            self.role_mapping
+                .insert(value.to_string(), role.to_string());
+        }
+        Ok(self)
+    }
+
+    /// The roles mapped from the values of the role claim, which holds either a single value
+    /// or an array of values, e.g. the `groups` claim of Okta or the `roles` claim of Entra ID.
+    pub fn claim_roles(&self, claims: &CustomClaims) -> Vec<String> {
+        let Some(claim) = &self.role_claim else {
+            return vec![];
+        };
+        let values = match claims.extra.get(claim) {
+            Some(Value::String(value)) => vec![value.as_str()],
+            Some(Value::Array(values)) => values.iter().filter_map(|v| v.as_str()).collect(),
+            _ => vec![],
+        };
+        values
+            .into_iter()
+            .filter_map(|value| self.role_mapping.get(value).cloned())
+            .collect()
+    }
+
+    fn verification_options(&self) -> VerificationOptions {
+        VerificationOptions {
+            allowed_issuers: self.allowed_issuers.clone(),
+            allowed_audiences: self.allowed_audiences.clone(),
+            ..Default::default()
+        }
     }
 
     // parse jwt claims from single source, if custom claim is not matching on desired, claim parsed would be empty
@@ -100,9 +198,10 @@ impl JwtAuthenticator {
         let metadata = Token::decode_metadata(token);
         let key_id = metadata.map_or(None, |e| e.key_id().map(|s| s.to_string()));
         let pub_key = key_store.get_key(key_id).await?;
+        let options = Some(self.verification_options());
         let r = match &pub_key {
-            PubKey::RSA256(pk) => pk.verify_token::<CustomClaims>(token, None),
-            PubKey::ES256(pk) => pk.verify_token::<CustomClaims>(token, None),
+            PubKey::RSA256(pk) => pk.verify_token::<CustomClaims>(token, options),
+            PubKey::ES256(pk) => pk.verify_token::<CustomClaims>(token, options),
         };
         let c = r.map_err(|err| ErrorCode::AuthenticateFailure(err.to_string()))?;
         match c.subject {
@@ -75,6 +75,136 @@ async fn test_parse_non_custom_claim() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_parse_claim_with_allowed_audiences() -> Result<()> {
+    let (pair1, pbkey1) = get_jwks_file_rs256("test_kid");
+    let template1 = ResponseTemplate::new(200).set_body_raw(pbkey1, "application/json");
+    let server = MockServer::start().await;
+    let json_path = "/jwks.json";
+    Mock::given(method("GET"))
+        .and(path(json_path))
+        .respond_with(template1)
+        .expect(1..)
+        .mount(&server)
+        .await;
+    let first_url = format!("http://{}{}", server.address(), json_path);
+    let claims = Claims::create(Duration::from_hours(2))
+        .with_subject("test-user".to_string())
+        .with_issuer("databend-test")
+        .with_audience("databend");
+    let token = pair1.sign(claims)?;
+
+    let auth = JwtAuthenticator::create(first_url.clone(), vec![])
+        .unwrap()
+        .with_allowed_issuers(vec!["databend-test".to_string()])
+        .with_allowed_audiences(vec!["databend".to_string()]);
+    assert!(auth.parse_jwt_claims(token.as_str()).await.is_ok());
+
+    let auth = JwtAuthenticator::create(first_url.clone(), vec![])
+        .unwrap()
+        .with_allowed_audiences(vec!["another-service".to_string()]);
+    assert!(auth.parse_jwt_claims(token.as_str()).await.is_err());
+
+    let auth = JwtAuthenticator::create(first_url, vec![])
+        .unwrap()
+        .with_allowed_issuers(vec!["another-issuer".to_string()]);
+    assert!(auth.parse_jwt_claims(token.as_str()).await.is_err());
+    Ok(())
+}
+
+#[derive(Serialize, Deserialize)]
+struct GroupsData {
+    groups: Vec<String>,
+    department: String,
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_parse_claim_roles() -> Result<()> {
+    let (pair1, pbkey1) = get_jwks_file_rs256("test_kid");
+    let template1 = ResponseTemplate::new(200).set_body_raw(pbkey1, "application/json");
+    let server = MockServer::start().await;
+    let json_path = "/jwks.json";
+    Mock::given(method("GET"))
+        .and(path(json_path))
+        .respond_with(template1)
+        .expect(1..)
+        .mount(&server)
+        .await;
+    let first_url = format!("http://{}{}", server.address(), json_path);
+    let groups_data = GroupsData {
+        groups: vec!["analyst".to_string(), "Everyone".to_string()],
+        department: "finance".to_string(),
+    };
+    let claims = Claims::with_custom_claims(groups_data, Duration::from_hours(2))
+        .with_subject("test-user".to_string());
+    let token = pair1.sign(claims)?;
+
+    let mapping = vec![
+        "analyst=data_analyst".to_string(),
+        "finance=data_finance".to_string(),
+    ];
+
+    // no role claim configured
+    let auth = JwtAuthenticator::create(first_url.clone(), vec![])
+        .unwrap()
+        .with_role_mapping(mapping.clone())?;
+    let res = auth.parse_jwt_claims(token.as_str()).await?;
+    assert!(auth.claim_roles(&res.custom).is_empty());
+
+    // an array claim, values not in the mapping are ignored
+    let auth = JwtAuthenticator::create(first_url.clone(), vec![])
+        .unwrap()
+        .with_role_claim("groups".to_string())
+        .with_role_mapping(mapping.clone())?;
+    let res = auth.parse_jwt_claims(token.as_str()).await?;
+    assert_eq!(auth.claim_roles(&res.custom), vec![
+        "data_analyst".to_string()
+    ]);
+
+    // a string claim
+    let auth = JwtAuthenticator::create(first_url.clone(), vec![])
+        .unwrap()
+        .with_role_claim("department".to_string())
+        .with_role_mapping(mapping.clone())?;
+    let res = auth.parse_jwt_claims(token.as_str()).await?;
+    assert_eq!(auth.claim_roles(&res.custom), vec![
+        "data_finance".to_string()
+    ]);
+
+    // a claim without mapping maps no role
+    let auth = JwtAuthenticator::create(first_url.clone(), vec![])
+        .unwrap()
+        .with_role_claim("groups".to_string());
+    let res = auth.parse_jwt_claims(token.as_str()).await?;
+    assert!(auth.claim_roles(&res.custom).is_empty());
+
+    // a missing claim
+    let auth = JwtAuthenticator::create(first_url.clone(), vec![])
+        .unwrap()
+        .with_role_claim("roles".to_string())
+        .with_role_mapping(mapping)?;
+    let res = auth.parse_jwt_claims(token.as_str()).await?;
+    assert!(auth.claim_roles(&res.custom).is_empty());
+
+    // the built-in admin role can not be mapped
+    for entry in ["analyst=account_admin", "analyst=ACCOUNT_ADMIN"] {
+        let res = JwtAuthenticator::create(first_url.clone(), vec![])
+            .unwrap()
+            .with_role_claim("groups".to_string())
+            .with_role_mapping(vec![entry.to_string()]);
+        assert!(res.is_err());
+    }
+
+    // malformed entries
+    for entry in ["analyst", "=data_analyst", "analyst="] {
+        let res = JwtAuthenticator::create(first_url.clone(), vec![])
+            .unwrap()
+            .with_role_mapping(vec![entry.to_string()]);
+        assert!(res.is_err());
+    }
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_jwk_key_store_retry_on_key_not_found() -> Result<()> {
     let func_calls = Arc::new(AtomicUsize::new(0));
@@ -115,6 +115,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
             span: None,
             hints: None,
             distinct: false,
+            distinct_on: None,
             top_n: None,
             select_list,
             from,
@@ -308,6 +309,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
             // TODO
             hints: None,
             distinct: self.rng.gen_bool(0.7),
+            distinct_on: None,
             top_n: None,
             select_list,
             from,
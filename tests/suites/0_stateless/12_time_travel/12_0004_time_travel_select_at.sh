@@ -31,6 +31,9 @@ TIMEPOINT=$(echo "select timestamp from fuse_snapshot('default', 't12_0004') whe
 echo "counting the data set of first insertion by timestamp, which should contains 2 rows"
 echo "select count(t.c) from t12_0004 at (TIMESTAMP => '$TIMEPOINT'::TIMESTAMP) as t" | $BENDSQL_CLIENT_CONNECT
 
+echo "counting the data set of first insertion by system time, which should contains 2 rows"
+echo "select count(t.c) from t12_0004 for system_time as of '$TIMEPOINT'::TIMESTAMP as t" | $BENDSQL_CLIENT_CONNECT
+
 offset=$(( $(date -u +%s) - $(date -u -d "$TIMEPOINT" +%s) - 1 ))
 echo "counting the data set of first insertion by offset, which should contains 2 rows"
 echo "select count(t.c) from t12_0004 at (OFFSET => -$offset) as t" | $BENDSQL_CLIENT_CONNECT
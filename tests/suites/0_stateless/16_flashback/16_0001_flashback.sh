@@ -52,5 +52,11 @@ echo "flash back to point that does not visible to the current snapshot should r
 echo "alter table t16 flashback to (snapshot => '$SNAPSHOT_ID')" | $BENDSQL_CLIENT_CONNECT
 
 
+# standalone FLASHBACK TABLE statement
+echo "insert into t16 values(4)" | $BENDSQL_CLIENT_CONNECT
+echo "flashback table t16 to (snapshot => '$FST_SNAPSHOT_ID')" | $BENDSQL_CLIENT_CONNECT
+echo "checking that after flashback table to the first snapshot, there should be 2 rows"
+echo "select count(*)=2  from t16" | $BENDSQL_CLIENT_CONNECT
+
 ## Drop table.
 echo "drop table t16" | $BENDSQL_CLIENT_CONNECT
@@ -97,6 +97,19 @@ except mysql.connector.errors.OperationalError:
 except mysql.connector.errors.ProgrammingError:
     print("u1 correct password locked out")
 
+try:
+    mydb = mysql.connector.connect(
+        host="127.0.0.1", user="root", passwd="", port="3307", connection_timeout=6
+    )
+    cursor = mydb.cursor()
+    cursor.execute(
+        "select count(), count(lockout_time) from system.login_failures where name = 'u1';"
+    )
+    res = cursor.fetchone()
+    print("u1 login failures: {}, locked: {}".format(res[0], res[1]))
+except mysql.connector.errors.OperationalError:
+    print("root@127.0.0.1 is timeout")
+
 try:
     mydb = mysql.connector.connect(
         host="127.0.0.1", user="u2", passwd="abc123", port="3307", connection_timeout=3
@@ -0,0 +1,15 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "## no accept-encoding"
+curl -s -u root: -XPOST "http://localhost:8000/v1/query" -H 'Content-Type: application/json' -d '{"sql": "select 1", "pagination": { "wait_time_secs": 5}}' -o /dev/null -D - | grep -ci "^content-encoding"
+
+for encoding in gzip zstd; do
+	echo "## ${encoding}"
+	curl -s -u root: -XPOST "http://localhost:8000/v1/query" -H "Accept-Encoding: ${encoding}" -H 'Content-Type: application/json' -d '{"sql": "select 1", "pagination": { "wait_time_secs": 5}}' -o /dev/null -D - | grep -i "^content-encoding" | tr -d '\r' | tr 'A-Z' 'a-z'
+done
+
+echo "## decoded"
+curl -s -u root: --compressed -XPOST "http://localhost:8000/v1/query" -H 'Content-Type: application/json' -d '{"sql": "select 1", "pagination": { "wait_time_secs": 5}}' | jq -c ".data"